@@ -13,7 +13,7 @@ use bevy::input::mouse::{MouseMotion, MouseWheel};
 use bevy::mesh::{Indices, PrimitiveTopology};
 use bevy::prelude::*;
 use rust_voronoi_planet::{
-    generate_mesh, terrain::sample_perlin_fbm, BasicColorMapper, ColorMapper, MeshData,
+    generate_mesh, terrain::{orient_sample_position, sample_perlin_fbm}, BasicColorMapper, ColorMapper, MeshData,
     PlanetConfigBuilder, PlanetSize, PointDistribution, TerrainColor, TerrainSampler,
     Vec3 as LibVec3, VoronoiPlanet,
 };
@@ -57,7 +57,7 @@ impl TerrainSampler for MarsTerrainSampler {
         }
 
         // Sample elevation
-        let pos = LibVec3::new(-position.x, position.y, -position.z);
+        let pos = orient_sample_position(position);
         let elevation = sample_perlin_fbm(pos * 0.15, self.seed, 4, 0.5, 2.0);
 
         // Mars is mostly land with canyons
@@ -108,7 +108,7 @@ impl TerrainSampler for AlienTerrainSampler {
     type Output = AlienTerrain;
 
     fn sample(&self, position: LibVec3, _radius: f32) -> AlienTerrain {
-        let pos = LibVec3::new(-position.x, position.y, -position.z);
+        let pos = orient_sample_position(position);
 
         // Multiple noise layers for varied terrain
         let elevation = sample_perlin_fbm(pos * 0.12, self.seed, 3, 0.5, 2.0);
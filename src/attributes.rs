@@ -0,0 +1,163 @@
+//! Per-cell attribute storage
+//!
+//! A side-table for gameplay data (owner, resources, temperature, ...) that
+//! lives alongside a planet without being folded into the terrain type.
+
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Result, VoronoiError};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Attribute values stored in parallel with a planet's cells
+///
+/// Indexed by cell ID, one value per cell. Because the table only depends on
+/// the cell count, it stays valid for any operation that keeps the planet's
+/// topology (terrain edits, remeshing, visibility changes, ...).
+///
+/// Create one with [`VoronoiPlanet::with_attributes`](crate::VoronoiPlanet::with_attributes).
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let mut owners = planet.with_attributes::<Option<u32>>();
+/// owners.set(0, Some(7)).unwrap();
+/// assert_eq!(owners.get(0), Some(&Some(7)));
+/// assert_eq!(owners.len(), planet.cell_count());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellAttributes<A> {
+    values: Vec<A>,
+}
+
+impl<A: Default + Clone> CellAttributes<A> {
+    /// Create a table with `cell_count` default values
+    pub fn new(cell_count: usize) -> Self {
+        Self {
+            values: vec![A::default(); cell_count],
+        }
+    }
+}
+
+impl<A> CellAttributes<A> {
+    /// Get the attribute for a cell
+    ///
+    /// Returns `None` if the cell ID is out of bounds.
+    #[inline]
+    pub fn get(&self, cell_id: usize) -> Option<&A> {
+        self.values.get(cell_id)
+    }
+
+    /// Get a mutable reference to the attribute for a cell
+    ///
+    /// Returns `None` if the cell ID is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, cell_id: usize) -> Option<&mut A> {
+        self.values.get_mut(cell_id)
+    }
+
+    /// Set the attribute for a cell
+    ///
+    /// # Errors
+    ///
+    /// Returns `CellNotFound` if the cell ID is out of bounds
+    pub fn set(&mut self, cell_id: usize, value: A) -> Result<()> {
+        let slot = self
+            .values
+            .get_mut(cell_id)
+            .ok_or(VoronoiError::CellNotFound(cell_id))?;
+        *slot = value;
+        Ok(())
+    }
+
+    /// Iterate over `(cell_id, value)` pairs in cell ID order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &A)> {
+        self.values.iter().enumerate()
+    }
+
+    /// Get all values as a slice, indexed by cell ID
+    #[inline]
+    pub fn as_slice(&self) -> &[A] {
+        &self.values
+    }
+
+    /// Get the number of cells in the table
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if the table is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<A> Index<usize> for CellAttributes<A> {
+    type Output = A;
+
+    fn index(&self, cell_id: usize) -> &A {
+        &self.values[cell_id]
+    }
+}
+
+impl<A> IndexMut<usize> for CellAttributes<A> {
+    fn index_mut(&mut self, cell_id: usize) -> &mut A {
+        &mut self.values[cell_id]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlanetConfigBuilder, PlanetSize, VoronoiPlanet};
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Resources {
+        iron: u32,
+        food: u32,
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut attrs: CellAttributes<Resources> = CellAttributes::new(10);
+
+        assert_eq!(attrs.get(3), Some(&Resources::default()));
+        attrs.set(3, Resources { iron: 5, food: 2 }).unwrap();
+        assert_eq!(attrs.get(3).unwrap().iron, 5);
+
+        attrs[4].food = 9;
+        assert_eq!(attrs.get(4).unwrap().food, 9);
+
+        assert!(attrs.get(10).is_none());
+        assert!(attrs.set(10, Resources::default()).is_err());
+
+        let changed: Vec<usize> = attrs
+            .iter()
+            .filter(|(_, r)| **r != Resources::default())
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(changed, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_planet_attributes_length() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let attrs = planet.with_attributes::<f32>();
+
+        assert_eq!(attrs.len(), planet.cell_count());
+        assert!(attrs.iter().all(|(_, &v)| v == 0.0));
+    }
+}
@@ -0,0 +1,101 @@
+//! Batch planet generation with reused buffers
+//!
+//! Tools that build many planets in a row (seed galleries, previews) spend a
+//! noticeable share of generation time allocating the same adjacency lists
+//! over and over. [`PlanetGenerator`] keeps those buffers alive between calls.
+
+use crate::config::PlanetConfig;
+use crate::error::Result;
+use crate::generation::{generate_raw_cells_with_scratch, GenerationScratch};
+use crate::planet::VoronoiPlanet;
+use crate::terrain::{BasicTerrainType, PerlinTerrainSampler, TerrainSampler};
+
+/// Generator that reuses its scratch buffers across planets
+///
+/// Produces exactly the same planets as [`VoronoiPlanet::generate`] and
+/// [`VoronoiPlanet::generate_with_sampler`]; only the temporary allocations
+/// (vertex-triangle adjacency, Lloyd point buffers, neighbor candidates) are
+/// kept between calls. The returned planets own their data as usual.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// let mut generator = PlanetGenerator::new();
+/// for seed in 0..3 {
+///     let config = PlanetConfigBuilder::new()
+///         .seed(seed)
+///         .planet_size(PlanetSize::Custom { cell_count: 500, radius: 5.0 })
+///         .build()
+///         .unwrap();
+///     let planet = generator.generate(config).unwrap();
+///     println!("Seed {}: {} cells", seed, planet.cell_count());
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct PlanetGenerator {
+    scratch: GenerationScratch,
+}
+
+impl PlanetGenerator {
+    /// Create a generator with empty buffers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate a planet with default Perlin terrain sampling
+    ///
+    /// Same result as [`VoronoiPlanet::generate`].
+    pub fn generate(&mut self, config: PlanetConfig) -> Result<VoronoiPlanet<BasicTerrainType>> {
+        let sampler = PerlinTerrainSampler::from_planet_config(&config);
+        self.generate_with_sampler(config, &sampler)
+    }
+
+    /// Generate a planet with a custom terrain sampler
+    ///
+    /// Same result as [`VoronoiPlanet::generate_with_sampler`].
+    pub fn generate_with_sampler<T, S>(&mut self, config: PlanetConfig, sampler: &S) -> Result<VoronoiPlanet<T>>
+    where
+        T: Clone,
+        S: TerrainSampler<Output = T>,
+    {
+        let raw_cells = generate_raw_cells_with_scratch(&config, &mut self.scratch)?;
+        Ok(VoronoiPlanet::from_raw_cells(config, raw_cells, sampler))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{PlanetConfigBuilder, PlanetSize, PointDistribution};
+
+    #[test]
+    fn test_generator_matches_independent_generation() {
+        let mut generator = PlanetGenerator::new();
+
+        for seed in 0..10 {
+            // Alternate sizes so buffers both grow and shrink between calls
+            let cell_count = if seed % 2 == 0 { 400 } else { 250 };
+            let config = PlanetConfigBuilder::new()
+                .seed(seed)
+                .planet_size(PlanetSize::Custom { cell_count, radius: 5.0 })
+                .point_distribution(if seed % 3 == 0 { PointDistribution::Fibonacci } else { PointDistribution::Random })
+                .lloyd_iterations(2)
+                .unwrap()
+                .build()
+                .unwrap();
+
+            let batched = generator.generate(config).unwrap();
+            let independent = VoronoiPlanet::generate(config).unwrap();
+
+            assert_eq!(batched.cell_count(), independent.cell_count(), "seed {}", seed);
+            for (a, b) in batched.cells().iter().zip(independent.cells()) {
+                assert_eq!(a.center, b.center, "seed {}", seed);
+                assert_eq!(a.neighbors, b.neighbors, "seed {}", seed);
+                assert_eq!(a.vertices, b.vertices, "seed {}", seed);
+                assert_eq!(a.terrain, b.terrain, "seed {}", seed);
+            }
+        }
+    }
+}
@@ -4,9 +4,98 @@
 
 use glam::Vec3;
 
+use crate::geometry::{longitude, longitude_east};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Latitude/longitude extent of a cell or region, in degrees
+///
+/// Latitude is measured from the equator towards +Y (north, 90°), longitude
+/// is [`geometry::longitude`](crate::geometry::longitude) in (-180°, 180°]. When `wraps` is set the longitude range
+/// crosses the antimeridian: it runs east from `min_lon` through ±180° to
+/// `max_lon`, so `min_lon > max_lon`. Regions containing a pole span the full
+/// longitude range.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatLonBounds {
+    /// Southernmost latitude
+    pub min_lat: f32,
+    /// Northernmost latitude
+    pub max_lat: f32,
+    /// Western edge of the longitude range
+    pub min_lon: f32,
+    /// Eastern edge of the longitude range
+    pub max_lon: f32,
+    /// Whether the longitude range crosses the antimeridian
+    pub wraps: bool,
+}
+
+impl LatLonBounds {
+    /// Compute bounds over boundary points, widening to the poles if enclosed
+    ///
+    /// Returns `None` for an empty point set.
+    pub(crate) fn from_points(
+        points: impl IntoIterator<Item = Vec3>,
+        contains_north: bool,
+        contains_south: bool,
+    ) -> Option<Self> {
+        let mut min_lat = f32::INFINITY;
+        let mut max_lat = f32::NEG_INFINITY;
+        let mut lons = Vec::new();
+
+        for point in points {
+            let dir = point.normalize();
+            let lat = dir.y.clamp(-1.0, 1.0).asin().to_degrees();
+            min_lat = min_lat.min(lat);
+            max_lat = max_lat.max(lat);
+            lons.push(longitude(dir));
+        }
+        if lons.is_empty() {
+            return None;
+        }
+
+        if contains_north {
+            max_lat = 90.0;
+        }
+        if contains_south {
+            min_lat = -90.0;
+        }
+        if contains_north || contains_south {
+            return Some(Self { min_lat, max_lat, min_lon: -180.0, max_lon: 180.0, wraps: false });
+        }
+
+        // The covered range is the complement of the widest eastward gap
+        // between consecutive longitudes, the last one wrapping to the first
+        lons.sort_by(f32::total_cmp);
+        let n = lons.len();
+        let gap = |i: usize| longitude_east(lons[i], lons[(i + 1) % n]);
+        let mut gap_after = n - 1;
+        for i in 0..n - 1 {
+            if gap(i) > gap(gap_after) {
+                gap_after = i;
+            }
+        }
+
+        let (min_lon, max_lon) = if gap_after == n - 1 {
+            (lons[0].to_degrees(), lons[n - 1].to_degrees())
+        } else {
+            (lons[gap_after + 1].to_degrees(), lons[gap_after].to_degrees())
+        };
+
+        Some(Self { min_lat, max_lat, min_lon, max_lon, wraps: min_lon > max_lon })
+    }
+
+    /// Width of the longitude range in degrees (accounts for wrapping)
+    pub fn lon_span(&self) -> f32 {
+        if self.wraps {
+            longitude_east(self.min_lon.to_radians(), self.max_lon.to_radians()).to_degrees()
+        } else {
+            self.max_lon - self.min_lon
+        }
+    }
+}
+
 /// A single Voronoi cell on the planet surface
 ///
 /// Each cell represents a discrete region of the planet with:
@@ -32,7 +121,19 @@ use serde::{Deserialize, Serialize};
 /// - **Total: ~140 bytes + sizeof(T) per cell**
 ///
 /// For 26,000 cells (Large planet): ~3.6 MB in RAM (+ terrain data)
+///
+/// # Serialization
+///
+/// With the `serde` feature, `VoronoiCell<T>` implements `Serialize` only if
+/// `T: Serialize` and `Deserialize` only if `T: Deserialize`. For terrain
+/// types without serde support, save the configuration instead via
+/// [`VoronoiPlanet::to_config_only`](crate::VoronoiPlanet::to_config_only),
+/// which is serializable for every `T`.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
 #[derive(Debug, Clone)]
 pub struct VoronoiCell<T> {
     /// Unique identifier for this cell (0 to cell_count-1)
@@ -142,6 +243,81 @@ impl<T> VoronoiCell<T> {
         area
     }
 
+    /// Calculate the exact spherical surface area of this cell
+    ///
+    /// Sums the spherical excess of the triangles fanned from the center to
+    /// each boundary edge. Unlike [`approximate_area`](Self::approximate_area),
+    /// the areas of all cells add up to the sphere's surface area `4πr²`.
+    pub fn spherical_area(&self, sphere_radius: f32) -> f32 {
+        if self.vertices.len() < 3 {
+            return 0.0;
+        }
+
+        let a = self.center.normalize();
+        let mut excess = 0.0;
+        for i in 0..self.vertices.len() {
+            let b = self.vertices[i].normalize();
+            let c = self.vertices[(i + 1) % self.vertices.len()].normalize();
+
+            // Van Oosterom & Strackee: tan(E/2) = |a·(b×c)| / (1 + a·b + b·c + c·a)
+            let numerator = a.dot(b.cross(c)).abs();
+            let denominator = 1.0 + a.dot(b) + b.dot(c) + c.dot(a);
+            excess += 2.0 * numerator.atan2(denominator);
+        }
+
+        excess * sphere_radius * sphere_radius
+    }
+
+    /// Check whether a direction from the planet center falls inside this cell
+    ///
+    /// Treats the boundary as a convex spherical polygon wound counter-clockwise.
+    pub(crate) fn contains_direction(&self, direction: Vec3) -> bool {
+        let n = self.vertices.len();
+        n >= 3
+            && (0..n).all(|i| {
+                let edge_normal = self.vertices[i].cross(self.vertices[(i + 1) % n]);
+                edge_normal.dot(direction) >= 0.0
+            })
+    }
+
+    /// Get the latitude/longitude extent of this cell's vertices (degrees)
+    ///
+    /// Cells enclosing a pole report the full longitude range and a latitude
+    /// reaching ±90°. `sphere_radius` is the radius the vertices lie on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let bounds = planet.get_cell(0).unwrap().lat_lon_bounds(planet.radius());
+    /// println!("Lat {:.1}..{:.1}, lon span {:.1}", bounds.min_lat, bounds.max_lat, bounds.lon_span());
+    /// ```
+    pub fn lat_lon_bounds(&self, sphere_radius: f32) -> LatLonBounds {
+        let points = self.vertices.iter().map(|v| *v / sphere_radius);
+        LatLonBounds::from_points(points, self.contains_direction(Vec3::Y), self.contains_direction(Vec3::NEG_Y))
+            .unwrap_or_else(|| {
+                // No boundary: collapse to the center
+                LatLonBounds::from_points([self.center], false, false).unwrap()
+            })
+    }
+
+    /// Calculate the boundary length of this cell along the sphere surface
+    ///
+    /// Sums the great-circle arc lengths between consecutive boundary vertices.
+    pub fn perimeter(&self, sphere_radius: f32) -> f32 {
+        if self.vertices.len() < 2 {
+            return 0.0;
+        }
+
+        (0..self.vertices.len())
+            .map(|i| {
+                let next = (i + 1) % self.vertices.len();
+                arc_length(self.vertices[i], self.vertices[next], sphere_radius)
+            })
+            .sum()
+    }
+
     /// Get distance to another cell (great circle distance between centers)
     ///
     /// Returns the arc distance along the sphere surface, not Euclidean distance.
@@ -157,6 +333,13 @@ impl<T> VoronoiCell<T> {
     }
 }
 
+/// Great-circle arc length between two points, measured on a sphere of `sphere_radius`
+pub(crate) fn arc_length(a: Vec3, b: Vec3, sphere_radius: f32) -> f32 {
+    // atan2 form stays accurate for both tiny and near-antipodal angles
+    let angle = a.cross(b).length().atan2(a.dot(b));
+    sphere_radius * angle
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,6 +392,70 @@ mod tests {
         assert!(area < 10.0); // Should be reasonable
     }
 
+    #[test]
+    fn test_spherical_area_octant() {
+        // One octant of the unit sphere has area 4π/8
+        let cell = VoronoiCell::new(
+            0,
+            Vec3::new(1.0, 1.0, 1.0).normalize(),
+            TestTerrain::Ocean,
+            vec![],
+            vec![Vec3::X, Vec3::Y, Vec3::Z],
+        );
+
+        let expected = std::f32::consts::PI / 2.0;
+        assert!((cell.spherical_area(1.0) - expected).abs() < 1e-4);
+        assert!((cell.spherical_area(2.0) - expected * 4.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_lat_lon_bounds() {
+        let ring = |center: Vec3, radius: f32| -> Vec<Vec3> {
+            let (u, v) = crate::generation::tangent_basis(center.normalize());
+            (0..6)
+                .map(|k| {
+                    let angle = k as f32 * std::f32::consts::TAU / 6.0;
+                    (center.normalize() + (u * angle.cos() + v * angle.sin()) * radius).normalize()
+                })
+                .collect()
+        };
+
+        // Small equatorial cell straddling the antimeridian (longitude ±180°)
+        let center = Vec3::NEG_X;
+        let cell = VoronoiCell::new(0, center, TestTerrain::Ocean, vec![], ring(center, 0.05));
+        let bounds = cell.lat_lon_bounds(1.0);
+        assert!(bounds.max_lat - bounds.min_lat < 6.0);
+        assert!(bounds.min_lat < 0.0 && bounds.max_lat > 0.0);
+        assert!(bounds.wraps && bounds.min_lon > 0.0 && bounds.max_lon < 0.0);
+        assert!(bounds.lon_span() < 6.0);
+
+        // Equatorial cell away from the antimeridian doesn't wrap
+        let bounds = VoronoiCell::new(1, Vec3::Z, TestTerrain::Ocean, vec![], ring(Vec3::Z, 0.05)).lat_lon_bounds(1.0);
+        assert!(!bounds.wraps && (bounds.min_lon..=bounds.max_lon).contains(&90.0));
+
+        // Polar cell spans every longitude
+        let polar = VoronoiCell::new(2, Vec3::Y, TestTerrain::Grassland, vec![], ring(Vec3::Y, 0.1));
+        let bounds = polar.lat_lon_bounds(1.0);
+        assert_eq!(bounds.max_lat, 90.0);
+        assert!(bounds.min_lat > 80.0);
+        assert_eq!(bounds.lon_span(), 360.0);
+    }
+
+    #[test]
+    fn test_perimeter_octant() {
+        // Three quarter great circles bound one octant
+        let cell = VoronoiCell::new(
+            0,
+            Vec3::new(1.0, 1.0, 1.0).normalize(),
+            TestTerrain::Ocean,
+            vec![],
+            vec![Vec3::X, Vec3::Y, Vec3::Z],
+        );
+
+        let expected = 3.0 * std::f32::consts::FRAC_PI_2 * 2.0;
+        assert!((cell.perimeter(2.0) - expected).abs() < 1e-4);
+    }
+
     #[test]
     fn test_distance_to() {
         let cell1 = VoronoiCell::new(
@@ -17,6 +17,8 @@ pub enum PointDistribution {
     ///
     /// Points are placed randomly and require Lloyd's relaxation for uniformity.
     /// This is the traditional approach but slower due to multiple relaxation iterations.
+    /// Relaxation couples every point to the cell count, so changing `cell_count`
+    /// reshuffles the whole planet.
     #[default]
     Random,
     /// Fibonacci lattice (golden spiral) distribution
@@ -25,13 +27,36 @@ pub enum PointDistribution {
     /// Produces excellent uniformity without requiring Lloyd's relaxation iterations.
     /// Recommended for best performance: use with `lloyd_iterations: 0`.
     Fibonacci,
+    /// Seeded Halton low-discrepancy sequence
+    ///
+    /// Point `i` depends only on `i` and the seed, so growing `cell_count` keeps
+    /// every existing point and only adds new ones. Use with `lloyd_iterations: 0`
+    /// to add detail to a planet without reshuffling its terrain.
+    Halton,
+}
+
+/// High-level continent layout for the default terrain sampler
+///
+/// Adjusts the continent noise frequency, domain-warp strength and sea level
+/// of [`PerlinTerrainSampler`](crate::PerlinTerrainSampler), see
+/// [`PerlinTerrainSampler::with_continent_style`](crate::PerlinTerrainSampler::with_continent_style).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContinentStyle {
+    /// One large supercontinent: low continent frequency, strong warping
+    Pangaea,
+    /// Many scattered islands: high continent frequency, mild warping, more ocean
+    Fragmented,
+    /// A handful of continents (the original look)
+    #[default]
+    Balanced,
 }
 
 /// Planet size presets matching the existing game's size system
 ///
 /// Each size maps to a specific cell count and sphere radius for consistent gameplay scaling.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum PlanetSize {
     /// Tiny planet: ~5,000 cells, radius ~11.3 units (Size 1)
     Tiny,
@@ -40,6 +65,7 @@ pub enum PlanetSize {
     /// Medium planet: ~17,000 cells, radius ~20.9 units (Size 5)
     Medium,
     /// Large planet: ~26,000 cells, radius ~25.8 units (Size 8, default)
+    #[default]
     Large,
     /// Custom planet size with specific cell count and radius
     Custom {
@@ -90,12 +116,6 @@ impl PlanetSize {
     }
 }
 
-impl Default for PlanetSize {
-    fn default() -> Self {
-        PlanetSize::Large // Match existing game default (size 8)
-    }
-}
-
 /// Configuration for deterministic Voronoi planet generation
 ///
 /// This configuration is serializable and can be shared between client and server.
@@ -106,6 +126,20 @@ impl Default for PlanetSize {
 /// Only the configuration is serialized (~20 bytes), not the generated cells.
 /// The planet is regenerated from the configuration when loading a save file.
 ///
+/// # Versioning
+///
+/// Every serialized config carries a [`version`](Self::version). The policy:
+///
+/// - [`CONFIG_VERSION`] is bumped whenever a field is added or changes meaning.
+/// - New fields get a default that reproduces what older versions generated,
+///   so saves written before the field existed load unchanged. Only `seed` and
+///   `planet_size` are required.
+/// - Loading migrates to the current version: missing fields are filled in,
+///   `version` is set to [`CONFIG_VERSION`], and unknown fields are ignored.
+///   Configs without a `version` are treated as version 0.
+/// - Configs from a newer crate (`version > CONFIG_VERSION`) are rejected
+///   rather than silently misread.
+///
 /// # Example
 ///
 /// ```rust
@@ -126,8 +160,14 @@ impl Default for PlanetSize {
 /// # }
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SavedPlanetConfig"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PlanetConfig {
+    /// Format version of this configuration, see [Versioning](Self#versioning)
+    ///
+    /// Always [`CONFIG_VERSION`] for configs built or loaded by this crate.
+    pub version: u32,
+
     /// Random seed for deterministic planet generation
     ///
     /// The same seed (with same planet_size and lloyd_iterations) will always
@@ -160,6 +200,12 @@ pub struct PlanetConfig {
     /// This allows the same cell layout with different terrain distributions.
     pub terrain_seed: u32,
 
+    /// Random seed for seed point placement, or `None` to use [`seed`](Self::seed)
+    ///
+    /// Lets tools reroll the cell layout while keeping `seed` (and any values
+    /// derived from it) fixed. See [`layout_seed`](Self::layout_seed).
+    pub point_seed: Option<u32>,
+
     /// Override the sphere radius from the planet_size preset
     ///
     /// If set, this radius will be used instead of the preset radius.
@@ -171,6 +217,97 @@ pub struct PlanetConfig {
     /// - `Random`: Traditional random placement, requires Lloyd relaxation
     /// - `Fibonacci`: Golden spiral, near-uniform without relaxation (faster)
     pub point_distribution: PointDistribution,
+
+    /// Merge (nearly) coincident seed points before building the hull
+    ///
+    /// Enabled by default. Points closer than `DEDUP_EPSILON * radius` are
+    /// merged and replaced by fresh points, so the planet has exactly
+    /// `cell_count()` cells. When disabled, the hull drops duplicates silently
+    /// and the cell count may end up slightly below `cell_count()`.
+    pub dedup_points: bool,
+
+    /// Continent layout used by the default terrain sampler
+    ///
+    /// Only affects planets generated with [`VoronoiPlanet::generate`](crate::VoronoiPlanet::generate)
+    /// (or a sampler built with [`PerlinTerrainSampler::from_planet_config`](crate::PerlinTerrainSampler::from_planet_config)).
+    pub continent_style: ContinentStyle,
+}
+
+/// Current serialization version of [`PlanetConfig`]
+///
+/// Version 1 introduced the `version` field itself; configs saved before it
+/// are version 0. Version 2 added `point_seed`.
+pub const CONFIG_VERSION: u32 = 2;
+
+/// First version whose saves always carry `dedup_points`
+///
+/// Version 0 saves without the field were written before deduplication
+/// existed, so they load with it disabled to reproduce the same geometry.
+#[cfg(feature = "serde")]
+const DEDUP_POINTS_VERSION: u32 = 1;
+
+/// Serialized form of [`PlanetConfig`] as read from any supported version
+///
+/// Everything except the seed and size is optional and falls back to the
+/// builder defaults, which is how older saves are migrated.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct SavedPlanetConfig {
+    #[serde(default)]
+    version: u32,
+    seed: u32,
+    planet_size: PlanetSize,
+    #[serde(default)]
+    lloyd_iterations: Option<usize>,
+    #[serde(default)]
+    lloyd_convergence: Option<f32>,
+    #[serde(default)]
+    terrain_seed: Option<u32>,
+    #[serde(default)]
+    point_seed: Option<u32>,
+    #[serde(default)]
+    radius_override: Option<f32>,
+    #[serde(default)]
+    point_distribution: Option<PointDistribution>,
+    #[serde(default)]
+    dedup_points: Option<bool>,
+    #[serde(default)]
+    continent_style: Option<ContinentStyle>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SavedPlanetConfig> for PlanetConfig {
+    type Error = String;
+
+    fn try_from(saved: SavedPlanetConfig) -> std::result::Result<Self, String> {
+        if saved.version > CONFIG_VERSION {
+            return Err(format!(
+                "config version {} is newer than supported version {}",
+                saved.version, CONFIG_VERSION
+            ));
+        }
+
+        let defaults = PlanetConfigBuilder::new()
+            .seed(saved.seed)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        Ok(PlanetConfig {
+            version: CONFIG_VERSION,
+            seed: saved.seed,
+            planet_size: saved.planet_size,
+            lloyd_iterations: saved.lloyd_iterations.unwrap_or(defaults.lloyd_iterations),
+            lloyd_convergence: saved.lloyd_convergence.unwrap_or(defaults.lloyd_convergence),
+            terrain_seed: saved.terrain_seed.unwrap_or(defaults.terrain_seed),
+            point_seed: saved.point_seed,
+            radius_override: saved.radius_override,
+            point_distribution: saved.point_distribution.unwrap_or(defaults.point_distribution),
+            dedup_points: saved
+                .dedup_points
+                .unwrap_or(saved.version >= DEDUP_POINTS_VERSION && defaults.dedup_points),
+            continent_style: saved.continent_style.unwrap_or(defaults.continent_style),
+        })
+    }
 }
 
 impl PlanetConfig {
@@ -180,6 +317,14 @@ impl PlanetConfig {
         self.planet_size.cell_count()
     }
 
+    /// Seed used to place the initial points
+    ///
+    /// Returns [`point_seed`](Self::point_seed) if set, otherwise [`seed`](Self::seed).
+    #[inline]
+    pub fn layout_seed(&self) -> u32 {
+        self.point_seed.unwrap_or(self.seed)
+    }
+
     /// Get the sphere radius for this configuration
     ///
     /// Returns the radius_override if set, otherwise the planet_size preset radius.
@@ -205,6 +350,279 @@ impl Default for PlanetConfig {
     }
 }
 
+/// Format version written as the first byte of a planet code
+const CODE_VERSION: u8 = 1;
+
+/// Crockford base32 alphabet (no I, L, O, U to avoid misreads)
+const CODE_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+impl PlanetConfig {
+    /// Encode the configuration as a short shareable "planet code"
+    ///
+    /// The fields are packed into a versioned little-endian byte layout with a
+    /// checksum byte and written as Crockford base32, giving ~30 characters for
+    /// a preset size. Works without the `serde` feature. Decode with
+    /// [`from_code`](Self::from_code).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidConfig` if `lloyd_iterations` doesn't fit in a byte or a
+    /// custom `cell_count` doesn't fit in 32 bits. The builder never produces
+    /// such configs, but struct literals can.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+    /// let code = config.to_code().unwrap();
+    /// assert_eq!(PlanetConfig::from_code(&code).unwrap(), config);
+    /// ```
+    pub fn to_code(&self) -> Result<String> {
+        let too_large = |field: &str, value: usize| {
+            VoronoiError::InvalidConfig(format!("{} {} does not fit in a planet code", field, value))
+        };
+        let lloyd_iterations =
+            u8::try_from(self.lloyd_iterations).map_err(|_| too_large("lloyd_iterations", self.lloyd_iterations))?;
+
+        let mut bytes = vec![CODE_VERSION];
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        bytes.extend_from_slice(&self.terrain_seed.to_le_bytes());
+
+        match self.planet_size {
+            PlanetSize::Tiny => bytes.push(0),
+            PlanetSize::Small => bytes.push(1),
+            PlanetSize::Medium => bytes.push(2),
+            PlanetSize::Large => bytes.push(3),
+            PlanetSize::Custom { cell_count, radius } => {
+                let cell_count = u32::try_from(cell_count).map_err(|_| too_large("cell_count", cell_count))?;
+                bytes.push(4);
+                bytes.extend_from_slice(&cell_count.to_le_bytes());
+                bytes.extend_from_slice(&radius.to_bits().to_le_bytes());
+            }
+        }
+
+        bytes.push(lloyd_iterations);
+        bytes.extend_from_slice(&self.lloyd_convergence.to_bits().to_le_bytes());
+        bytes.push(match self.point_distribution {
+            PointDistribution::Random => 0,
+            PointDistribution::Fibonacci => 1,
+            PointDistribution::Halton => 2,
+        });
+
+        let style = match self.continent_style {
+            ContinentStyle::Balanced => 0,
+            ContinentStyle::Pangaea => 1,
+            ContinentStyle::Fragmented => 2,
+        };
+        let flags = self.dedup_points as u8
+            | (self.radius_override.is_some() as u8) << 1
+            | style << 2
+            | (self.point_seed.is_some() as u8) << 4;
+        bytes.push(flags);
+        if let Some(radius) = self.radius_override {
+            bytes.extend_from_slice(&radius.to_bits().to_le_bytes());
+        }
+        if let Some(point_seed) = self.point_seed {
+            bytes.extend_from_slice(&point_seed.to_le_bytes());
+        }
+
+        bytes.push(code_checksum(&bytes));
+        Ok(base32_encode(&bytes))
+    }
+
+    /// Decode a configuration from a planet code produced by [`to_code`](Self::to_code)
+    ///
+    /// Decoding is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidConfig` if the code is malformed, fails its checksum,
+    /// has an unknown version, or describes an invalid configuration.
+    pub fn from_code(code: &str) -> Result<PlanetConfig> {
+        let invalid = |reason: &str| VoronoiError::InvalidConfig(format!("invalid planet code: {}", reason));
+
+        let bytes = base32_decode(code).ok_or_else(|| invalid("unexpected character"))?;
+        let (&checksum, body) = bytes.split_last().ok_or_else(|| invalid("empty code"))?;
+        if code_checksum(body) != checksum {
+            return Err(invalid("checksum mismatch"));
+        }
+
+        let mut reader = CodeReader { bytes: body };
+        let version = reader.u8().ok_or_else(|| invalid("truncated"))?;
+        if version != CODE_VERSION {
+            return Err(invalid(&format!("unsupported version {}", version)));
+        }
+
+        let fields = (|| {
+            let seed = reader.u32()?;
+            let terrain_seed = reader.u32()?;
+            let planet_size = match reader.u8()? {
+                0 => PlanetSize::Tiny,
+                1 => PlanetSize::Small,
+                2 => PlanetSize::Medium,
+                3 => PlanetSize::Large,
+                4 => PlanetSize::Custom { cell_count: reader.u32()? as usize, radius: reader.f32()? },
+                _ => return None,
+            };
+            let lloyd_iterations = reader.u8()? as usize;
+            let lloyd_convergence = reader.f32()?;
+            let point_distribution = match reader.u8()? {
+                0 => PointDistribution::Random,
+                1 => PointDistribution::Fibonacci,
+                2 => PointDistribution::Halton,
+                _ => return None,
+            };
+            let flags = reader.u8()?;
+            let radius_override = if flags & 2 != 0 { Some(reader.f32()?) } else { None };
+            let point_seed = if flags & 16 != 0 { Some(reader.u32()?) } else { None };
+            let continent_style = match (flags >> 2) & 3 {
+                0 => ContinentStyle::Balanced,
+                1 => ContinentStyle::Pangaea,
+                2 => ContinentStyle::Fragmented,
+                _ => return None,
+            };
+            if flags >> 5 != 0 || !reader.bytes.is_empty() {
+                return None;
+            }
+
+            Some(PlanetConfig {
+                version: CONFIG_VERSION,
+                seed,
+                planet_size,
+                lloyd_iterations,
+                lloyd_convergence,
+                terrain_seed,
+                point_seed,
+                radius_override,
+                point_distribution,
+                dedup_points: flags & 1 != 0,
+                continent_style,
+            })
+        })();
+        let config = fields.ok_or_else(|| invalid("malformed fields"))?;
+
+        // Apply the same limits as the builder
+        let builder = PlanetConfigBuilder::new()
+            .lloyd_iterations(config.lloyd_iterations)?
+            .lloyd_convergence(config.lloyd_convergence)?;
+        if let Some(radius) = config.radius_override {
+            builder.radius_override(radius)?;
+        }
+        if let PlanetSize::Custom { cell_count, radius } = config.planet_size {
+            if cell_count == 0 || radius.is_nan() || radius <= 0.0 {
+                return Err(invalid("custom size must have cells and a positive radius"));
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// One-byte checksum over a planet code body (FNV-1a folded to 8 bits)
+fn code_checksum(bytes: &[u8]) -> u8 {
+    let hash = bytes
+        .iter()
+        .fold(0x811C_9DC5u32, |h, &b| (h ^ b as u32).wrapping_mul(0x0100_0193));
+    (hash ^ hash >> 8 ^ hash >> 16 ^ hash >> 24) as u8
+}
+
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for &byte in bytes {
+        buffer = buffer << 8 | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(CODE_ALPHABET[(buffer >> bits & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(CODE_ALPHABET[(buffer << (5 - bits) & 31) as usize] as char);
+    }
+    out
+}
+
+fn base32_decode(code: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(code.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in code.bytes() {
+        let value = CODE_ALPHABET.iter().position(|&a| a == c.to_ascii_uppercase())? as u32;
+        buffer = buffer << 5 | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    // Leftover bits are padding: fewer than one character's worth, all zero
+    (bits < 5 && buffer & ((1 << bits) - 1) == 0).then_some(out)
+}
+
+/// Little-endian cursor over a decoded planet code
+struct CodeReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl CodeReader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (head, rest) = self.bytes.split_first_chunk::<N>()?;
+        self.bytes = rest;
+        Some(*head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take::<1>().map(|[b]| b)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        self.u32().map(f32::from_bits)
+    }
+}
+
+/// Seed stream for cell layout, see [`derive_seed`]
+pub const SEED_STREAM_LAYOUT: u32 = 0;
+/// Seed stream for terrain sampling, see [`derive_seed`]
+pub const SEED_STREAM_TERRAIN: u32 = 1;
+/// Seed stream for points replacing merged duplicates, see [`derive_seed`]
+pub const SEED_STREAM_REPLACEMENT: u32 = 2;
+/// Seed stream for per-cell art variants, see [`derive_seed`]
+pub const SEED_STREAM_VARIANT: u32 = 3;
+
+/// Derive a sub-seed from a master seed
+///
+/// Mixes `master` and `stream` with the SplitMix64 finalizer and keeps the
+/// upper 32 bits, so neighboring master seeds or streams give unrelated
+/// results. The mapping is part of the save format and will not change.
+/// Streams 0 and 1 are used by [`PlanetConfigBuilder::master_seed`] for the
+/// cell layout and terrain, stream 2 for replacement points during generation
+/// and stream 3 for [`VoronoiPlanet::variant_index`](crate::VoronoiPlanet::variant_index);
+/// other subsystems should pick their own stream IDs.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::config::{derive_seed, SEED_STREAM_TERRAIN};
+///
+/// let terrain = derive_seed(2024, SEED_STREAM_TERRAIN);
+/// assert_eq!(terrain, derive_seed(2024, SEED_STREAM_TERRAIN));
+/// ```
+pub fn derive_seed(master: u32, stream: u32) -> u32 {
+    let mut z = ((stream as u64) << 32 | master as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 32) as u32
+}
+
 /// Builder for creating PlanetConfig with validation
 ///
 /// Uses the builder pattern to create configurations with sensible defaults
@@ -231,12 +649,16 @@ impl Default for PlanetConfig {
 #[derive(Debug, Clone)]
 pub struct PlanetConfigBuilder {
     seed: Option<u32>,
+    master_seed: Option<u32>,
     planet_size: PlanetSize,
     lloyd_iterations: usize,
     lloyd_convergence: f32,
     terrain_seed: Option<u32>,
+    point_seed: Option<u32>,
     radius_override: Option<f32>,
     point_distribution: PointDistribution,
+    dedup_points: bool,
+    continent_style: ContinentStyle,
 }
 
 impl PlanetConfigBuilder {
@@ -248,16 +670,23 @@ impl PlanetConfigBuilder {
     /// - lloyd_iterations: 5 (good uniformity)
     /// - lloyd_convergence: 0.01 (stop when points move < 1% of radius)
     /// - terrain_seed: Same as seed
+    /// - point_seed: Same as seed
     /// - radius_override: None
+    /// - dedup_points: true
+    /// - continent_style: Balanced
     pub fn new() -> Self {
         Self {
             seed: None,
+            master_seed: None,
             planet_size: PlanetSize::default(),
             lloyd_iterations: 5,
             lloyd_convergence: 0.01,
             terrain_seed: None,
+            point_seed: None,
             radius_override: None,
             point_distribution: PointDistribution::default(),
+            dedup_points: true,
+            continent_style: ContinentStyle::default(),
         }
     }
 
@@ -321,6 +750,18 @@ impl PlanetConfigBuilder {
         Ok(self)
     }
 
+    /// Derive all sub-seeds from one master seed
+    ///
+    /// `seed` and `terrain_seed` are computed with [`derive_seed`] (streams
+    /// [`SEED_STREAM_LAYOUT`] and [`SEED_STREAM_TERRAIN`]), so they are
+    /// decorrelated but reproducible from this one value. Explicit
+    /// [`seed`](Self::seed) and [`terrain_seed`](Self::terrain_seed) calls
+    /// still take precedence.
+    pub fn master_seed(mut self, seed: u32) -> Self {
+        self.master_seed = Some(seed);
+        self
+    }
+
     /// Set a separate terrain seed
     ///
     /// If not set, the terrain seed will match the planet seed.
@@ -331,6 +772,16 @@ impl PlanetConfigBuilder {
         self
     }
 
+    /// Set a separate seed for the initial point placement
+    ///
+    /// If not set, points are placed with the planet seed. Changing only the
+    /// point seed rerolls the cell layout while `seed` and `terrain_seed`
+    /// stay fixed.
+    pub fn point_seed(mut self, seed: u32) -> Self {
+        self.point_seed = Some(seed);
+        self
+    }
+
     /// Override the sphere radius
     ///
     /// If set, this radius will be used instead of the planet_size preset radius.
@@ -361,21 +812,47 @@ impl PlanetConfigBuilder {
         self
     }
 
+    /// Enable or disable merging of coincident seed points
+    ///
+    /// When enabled (default), points closer than a tiny fraction of the radius
+    /// are merged and replaced before the convex hull is computed. Disable it
+    /// only if you need the raw hull behaviour, which drops duplicates silently.
+    pub fn dedup_points(mut self, enabled: bool) -> Self {
+        self.dedup_points = enabled;
+        self
+    }
+
+    /// Set the continent layout of the default terrain sampler
+    ///
+    /// `Pangaea` favors one supercontinent, `Fragmented` scattered islands.
+    pub fn continent_style(mut self, style: ContinentStyle) -> Self {
+        self.continent_style = style;
+        self
+    }
+
     /// Build the configuration
     ///
     /// If no seed was provided, generates a random seed using thread_rng.
     pub fn build(self) -> Result<PlanetConfig> {
-        let seed = self.seed.unwrap_or_else(|| rand::random());
-        let terrain_seed = self.terrain_seed.unwrap_or(seed);
+        let derived = |stream| self.master_seed.map(|master| derive_seed(master, stream));
+        let seed = self.seed.or_else(|| derived(SEED_STREAM_LAYOUT)).unwrap_or_else(rand::random);
+        let terrain_seed = self
+            .terrain_seed
+            .or_else(|| derived(SEED_STREAM_TERRAIN))
+            .unwrap_or(seed);
 
         Ok(PlanetConfig {
+            version: CONFIG_VERSION,
             seed,
             planet_size: self.planet_size,
             lloyd_iterations: self.lloyd_iterations,
             lloyd_convergence: self.lloyd_convergence,
             terrain_seed,
+            point_seed: self.point_seed,
             radius_override: self.radius_override,
             point_distribution: self.point_distribution,
+            dedup_points: self.dedup_points,
+            continent_style: self.continent_style,
         })
     }
 }
@@ -423,6 +900,7 @@ mod tests {
         assert_eq!(config.planet_size, PlanetSize::Large);
         assert_eq!(config.lloyd_iterations, 5);
         assert_eq!(config.radius_override, None);
+        assert!(config.dedup_points);
         // seed and terrain_seed are random, so just verify they were set
         let _seed = config.seed; // Just verify seed exists
     }
@@ -485,6 +963,60 @@ mod tests {
         assert_eq!(config.planet_size, restored.planet_size);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_deserialization_defaults_dedup() {
+        let config = PlanetConfigBuilder::new().seed(7).dedup_points(false).build().unwrap();
+
+        // Old saves without the field predate deduplication and keep it off
+        let mut value = serde_json::to_value(config).unwrap();
+        value.as_object_mut().unwrap().remove("dedup_points");
+        value["version"] = serde_json::json!(0);
+        let restored: PlanetConfig = serde_json::from_value(value.clone()).unwrap();
+        assert!(!restored.dedup_points);
+
+        // A current-version config missing the field gets the default
+        value["version"] = serde_json::json!(CONFIG_VERSION);
+        let restored: PlanetConfig = serde_json::from_value(value).unwrap();
+        assert!(restored.dedup_points);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_migrates_old_saves() {
+        // Written before `version`, `point_distribution` and the later fields existed
+        let json = r#"{
+            "seed": 42,
+            "planet_size": "Small",
+            "lloyd_iterations": 3,
+            "lloyd_convergence": 0.01,
+            "terrain_seed": 7,
+            "radius_override": null
+        }"#;
+        let config: PlanetConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!((config.seed, config.terrain_seed, config.lloyd_iterations), (42, 7, 3));
+        assert_eq!(config.planet_size, PlanetSize::Small);
+        assert_eq!(config.point_distribution, PointDistribution::default());
+        assert!(!config.dedup_points);
+        assert_eq!(config.continent_style, ContinentStyle::Balanced);
+
+        // Only seed and size are required; the terrain seed follows the seed
+        // and a version 0 save predates deduplication
+        let minimal: PlanetConfig = serde_json::from_str(r#"{"seed": 5, "planet_size": "Tiny"}"#).unwrap();
+        let built = PlanetConfigBuilder::new().seed(5).planet_size(PlanetSize::Tiny).dedup_points(false).build().unwrap();
+        assert_eq!(minimal, built);
+        assert!(serde_json::from_str::<PlanetConfig>(r#"{"planet_size": "Tiny"}"#).is_err());
+
+        // Current configs round-trip, configs from the future are rejected
+        let json = serde_json::to_string(&built).unwrap();
+        assert!(json.contains(&format!("\"version\":{}", CONFIG_VERSION)));
+        assert_eq!(serde_json::from_str::<PlanetConfig>(&json).unwrap(), built);
+        let future = format!(r#"{{"version": {}, "seed": 5, "planet_size": "Tiny"}}"#, CONFIG_VERSION + 1);
+        let err = serde_json::from_str::<PlanetConfig>(&future).unwrap_err();
+        assert!(err.to_string().contains("newer"));
+    }
+
     #[test]
     fn test_builder_too_many_iterations() {
         let result = PlanetConfigBuilder::new().lloyd_iterations(21);
@@ -516,4 +1048,95 @@ mod tests {
         assert_eq!(config.seed, 42);
         assert_eq!(config.terrain_seed, 99);
     }
+
+    #[test]
+    fn test_point_seed_defaults_to_planet_seed() {
+        let config = PlanetConfigBuilder::new().seed(42).build().unwrap();
+        assert_eq!(config.point_seed, None);
+        assert_eq!(config.layout_seed(), 42);
+
+        let config = PlanetConfigBuilder::new().seed(42).point_seed(7).build().unwrap();
+        assert_eq!((config.seed, config.terrain_seed, config.layout_seed()), (42, 42, 7));
+    }
+
+    #[test]
+    fn test_master_seed_derivation() {
+        let a = PlanetConfigBuilder::new().master_seed(7).build().unwrap();
+        let b = PlanetConfigBuilder::new().master_seed(7).build().unwrap();
+        assert_eq!((a.seed, a.terrain_seed), (b.seed, b.terrain_seed));
+        assert_eq!(a.seed, derive_seed(7, SEED_STREAM_LAYOUT));
+        assert_eq!(a.terrain_seed, derive_seed(7, SEED_STREAM_TERRAIN));
+        assert_ne!(a.seed, a.terrain_seed);
+
+        // Explicit seeds override the derived ones
+        let c = PlanetConfigBuilder::new().master_seed(7).terrain_seed(5).build().unwrap();
+        assert_eq!((c.seed, c.terrain_seed), (a.seed, 5));
+
+        // Adjacent master seeds flip about half of the terrain seed bits
+        let flipped: u32 = (0..1000)
+            .map(|m| (derive_seed(m, SEED_STREAM_TERRAIN) ^ derive_seed(m + 1, SEED_STREAM_TERRAIN)).count_ones())
+            .sum();
+        let mean = flipped as f32 / 1000.0;
+        assert!((14.0..18.0).contains(&mean), "mean flipped bits {}", mean);
+    }
+
+    #[test]
+    fn test_planet_code_round_trip() {
+        let configs = [
+            PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap(),
+            PlanetConfigBuilder::new()
+                .seed(u32::MAX)
+                .terrain_seed(7)
+                .planet_size(PlanetSize::Custom { cell_count: 1234, radius: 3.5 })
+                .lloyd_iterations(0)
+                .unwrap()
+                .lloyd_convergence(0.0)
+                .unwrap()
+                .radius_override(9.25)
+                .unwrap()
+                .point_distribution(PointDistribution::Halton)
+                .dedup_points(false)
+                .build()
+                .unwrap(),
+            PlanetConfigBuilder::new().seed(3).point_seed(11).continent_style(ContinentStyle::Fragmented).build().unwrap(),
+        ];
+
+        for config in configs {
+            let code = config.to_code().unwrap();
+            assert!(code.len() <= 48, "code too long: {}", code);
+            assert_eq!(PlanetConfig::from_code(&code).unwrap(), config);
+            assert_eq!(PlanetConfig::from_code(&code.to_lowercase()).unwrap(), config);
+        }
+    }
+
+    #[test]
+    fn test_planet_code_invalid() {
+        let config = PlanetConfigBuilder::new().seed(42).build().unwrap();
+        let code = config.to_code().unwrap();
+
+        // A typo in any character is caught by the checksum or field checks
+        let mut typo = code.clone().into_bytes();
+        typo[3] = if typo[3] == b'A' { b'B' } else { b'A' };
+        let typo = String::from_utf8(typo).unwrap();
+
+        // Extra zero padding would give the same config a second code
+        let padded = format!("{}0", code);
+
+        for bad in ["", "hello world", "U", &code[..code.len() - 2], &typo, &padded] {
+            assert!(
+                matches!(PlanetConfig::from_code(bad), Err(VoronoiError::InvalidConfig(_))),
+                "accepted {:?}",
+                bad
+            );
+        }
+
+        // Struct literals can bypass the builder limits; those fields must not be truncated
+        let many_iterations = PlanetConfig { lloyd_iterations: 300, ..config };
+        assert!(matches!(many_iterations.to_code(), Err(VoronoiError::InvalidConfig(_))));
+        #[cfg(target_pointer_width = "64")]
+        {
+            let huge = PlanetConfig { planet_size: PlanetSize::Custom { cell_count: 1 << 33, radius: 1.0 }, ..config };
+            assert!(matches!(huge.to_code(), Err(VoronoiError::InvalidConfig(_))));
+        }
+    }
 }
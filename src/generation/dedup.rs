@@ -0,0 +1,128 @@
+//! Merging of coincident seed points before hull computation
+//!
+//! The convex hull silently drops (nearly) duplicate points, so the cell
+//! count would quietly fall below the number of input points and cell IDs
+//! would no longer line up with them. Merging them up front makes this
+//! explicit and avoids sliver cells from points just outside the hull's
+//! own tolerance.
+
+use parry3d::math::Point;
+use std::collections::HashMap;
+
+/// Default merge distance as a fraction of the sphere radius
+pub const DEDUP_EPSILON: f32 = 1e-5;
+
+/// Remove points that lie within `epsilon` of an earlier point
+///
+/// Keeps the first occurrence of each cluster and preserves input order, so
+/// the result is deterministic. Uses a uniform grid with `epsilon`-sized
+/// buckets, making this O(n).
+///
+/// # Arguments
+///
+/// * `points` - Input points
+/// * `epsilon` - Merge distance in world units
+///
+/// # Returns
+///
+/// Points with near-duplicates removed, or a copy of the input unchanged if
+/// `epsilon` is not positive (there is no sensible bucket size for it)
+///
+/// # Example
+///
+/// ```rust
+/// use parry3d::math::Point;
+/// use rust_voronoi_planet::generation::dedup_points;
+///
+/// let points = vec![Point::new(1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)];
+/// assert_eq!(dedup_points(&points, 1e-4).len(), 2);
+/// ```
+pub fn dedup_points(points: &[Point<f32>], epsilon: f32) -> Vec<Point<f32>> {
+    if epsilon.is_nan() || epsilon <= 0.0 {
+        return points.to_vec();
+    }
+
+    let epsilon_sq = epsilon * epsilon;
+    let bucket = |p: &Point<f32>| {
+        (
+            (p.x / epsilon).floor() as i64,
+            (p.y / epsilon).floor() as i64,
+            (p.z / epsilon).floor() as i64,
+        )
+    };
+
+    let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+    let mut kept: Vec<Point<f32>> = Vec::with_capacity(points.len());
+
+    for point in points {
+        let (bx, by, bz) = bucket(point);
+
+        let mut duplicate = false;
+        'search: for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    if let Some(indices) = grid.get(&(bx + dx, by + dy, bz + dz)) {
+                        for &idx in indices {
+                            let other = &kept[idx];
+                            let d = (point.x - other.x).powi(2)
+                                + (point.y - other.y).powi(2)
+                                + (point.z - other.z).powi(2);
+                            if d <= epsilon_sq {
+                                duplicate = true;
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if !duplicate {
+            grid.entry((bx, by, bz)).or_default().push(kept.len());
+            kept.push(*point);
+        }
+    }
+
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::points::generate_sphere_points;
+    use crate::generation::voronoi::generate_cells;
+
+    #[test]
+    fn test_dedup_removes_duplicates() {
+        let mut points = generate_sphere_points(100, 10.0, 42);
+        let copies: Vec<Point<f32>> = points[..10].to_vec();
+        points.extend(copies);
+        points.push(Point::new(points[20].x + 1e-6, points[20].y, points[20].z));
+
+        let deduped = dedup_points(&points, 10.0 * DEDUP_EPSILON);
+        assert_eq!(deduped.len(), 100);
+        assert_eq!(deduped[..], generate_sphere_points(100, 10.0, 42)[..]);
+
+        // Non-positive epsilon leaves the input alone
+        for epsilon in [0.0, -1.0, f32::NAN] {
+            assert_eq!(dedup_points(&points, epsilon)[..], points[..]);
+        }
+    }
+
+    #[test]
+    fn test_duplicates_produce_valid_cells() {
+        let mut points = generate_sphere_points(100, 10.0, 7);
+        let copies: Vec<Point<f32>> = points.iter().step_by(3).copied().collect();
+        points.extend(copies);
+
+        let deduped = dedup_points(&points, 10.0 * DEDUP_EPSILON);
+        let cells = generate_cells(&deduped, 10.0).unwrap();
+
+        assert_eq!(cells.len(), 100);
+        assert_eq!(cells.len(), deduped.len());
+        for cell in &cells {
+            assert!(cell.vertices.len() >= 3);
+            assert!(cell.neighbors.len() >= 3);
+        }
+    }
+}
@@ -1,62 +1,450 @@
-//! Delaunay triangulation via convex hull
+//! Delaunay triangulation of points on a sphere
 //!
 //! For points on a sphere, the Delaunay triangulation is equivalent to the
-//! 3D convex hull of those points. This module provides a thin wrapper around
-//! parry3d's convex hull algorithm.
-//!
-//! Note: This module is currently unused but kept for potential future use.
+//! 3D convex hull of those points. Generation uses parry3d's convex hull by
+//! default. [`spherical_delaunay`] is an incremental alternative built for
+//! very large planets: points are inserted in space-filling-curve order, so
+//! locating each new point is a short walk from the previous one, and the
+//! triangle buffers can be reused between Lloyd iterations. The
+//! `incremental-delaunay` feature makes generation use it.
 
+use glam::DVec3;
 use parry3d::math::Point;
 use parry3d::transformation;
 
-/// Result of Delaunay triangulation
+/// Sentinel for "no triangle" / "no vertex"
+const NONE: u32 = u32::MAX;
+
+/// Triangulate points on a sphere with the configured backend
+///
+/// Returns the hull vertices (inputs the hull kept, possibly reordered) and
+/// triangles indexing into them, like `parry3d::transformation::convex_hull`.
+#[cfg(not(feature = "incremental-delaunay"))]
+pub(crate) fn triangulate(points: &[Point<f32>], _scratch: &mut IncrementalDelaunay) -> (Vec<Point<f32>>, Vec<[u32; 3]>) {
+    transformation::convex_hull(points)
+}
+
+/// Triangulate points on a sphere with the configured backend
 ///
-/// Contains the vertices (which may be reordered from input) and triangle indices.
-#[allow(dead_code)]
-pub struct DelaunayResult {
-    /// Vertices (may be reordered from input)
-    pub vertices: Vec<Point<f32>>,
-    /// Triangle indices (each [u32; 3] is a triangle)
-    pub triangles: Vec<[u32; 3]>,
+/// Returns the hull vertices (inputs the hull kept, possibly reordered) and
+/// triangles indexing into them, like `parry3d::transformation::convex_hull`.
+#[cfg(feature = "incremental-delaunay")]
+pub(crate) fn triangulate(points: &[Point<f32>], scratch: &mut IncrementalDelaunay) -> (Vec<Point<f32>>, Vec<[u32; 3]>) {
+    scratch.triangulate(points)
 }
 
-/// Compute Delaunay triangulation of points on sphere via convex hull
+/// Compute the Delaunay triangulation of points on a sphere incrementally
+///
+/// Produces the same `(vertices, triangles)` shape as
+/// `parry3d::transformation::convex_hull` and therefore the same cells and
+/// neighbor graph, but runs in roughly `O(n log n)`: points are sorted along a
+/// Morton curve and each one is located by walking from the last inserted
+/// triangle. Vertices keep their input order and points that coincide with an
+/// earlier point are left out. If rounding ever makes a point's insertion
+/// ambiguous, the whole set is handed to the convex hull instead, so no point
+/// is lost either way. Triangles come out in a different order than the hull's.
 ///
-/// For points on a sphere, the Delaunay triangulation is the same as the
-/// convex hull projected onto the sphere surface. This is computationally
-/// efficient and numerically stable.
+/// Points should be centered on the origin and (nearly) on a common sphere.
+/// Fewer than four non-coplanar points give no triangles.
 ///
-/// # Arguments
+/// # Example
 ///
-/// * `points` - Points on the sphere surface
+/// ```
+/// use rust_voronoi_planet::generation::{generate_sphere_points, spherical_delaunay};
 ///
-/// # Returns
+/// let points = generate_sphere_points(1000, 10.0, 42);
+/// let (vertices, triangles) = spherical_delaunay(&points);
+/// assert_eq!(vertices.len(), 1000);
+/// assert_eq!(triangles.len(), 2 * 1000 - 4);
+/// ```
+pub fn spherical_delaunay(points: &[Point<f32>]) -> (Vec<Point<f32>>, Vec<[u32; 3]>) {
+    IncrementalDelaunay::default().triangulate(points)
+}
+
+/// Reusable state of the incremental spherical Delaunay triangulator
 ///
-/// DelaunayResult containing reordered vertices and triangle connectivity
-#[allow(dead_code)]
-pub fn compute_delaunay(points: &[Point<f32>]) -> DelaunayResult {
-    let (vertices, triangles) = transformation::convex_hull(points);
-    DelaunayResult { vertices, triangles }
+/// Triangles are stored with their three vertices in counter-clockwise order
+/// seen from outside, and `adjacent[t][i]` is the triangle across the edge
+/// from corner `i` to corner `i + 1`.
+#[derive(Debug, Default)]
+pub(crate) struct IncrementalDelaunay {
+    positions: Vec<DVec3>,
+    triangles: Vec<[u32; 3]>,
+    adjacent: Vec<[u32; 3]>,
+    alive: Vec<bool>,
+    free: Vec<u32>,
+    /// Per-triangle stamp marking the faces visible from the current point
+    visited: Vec<u32>,
+    stamp: u32,
+    stack: Vec<u32>,
+    visible: Vec<u32>,
+    /// Horizon edges as (from, to, outside triangle, edge index in it)
+    horizon: Vec<(u32, u32, u32, usize)>,
+    /// New triangle whose horizon edge starts at a vertex, indexed by vertex
+    fan_start: Vec<u32>,
+    order: Vec<u32>,
+    /// Set when a point could not be inserted consistently
+    ambiguous: bool,
+}
+
+impl IncrementalDelaunay {
+    /// Triangulate `points`, reusing this instance's buffers
+    pub(crate) fn triangulate(&mut self, points: &[Point<f32>]) -> (Vec<Point<f32>>, Vec<[u32; 3]>) {
+        self.reset(points);
+
+        let Some(seed) = self.initial_tetrahedron() else {
+            return (Vec::new(), Vec::new());
+        };
+
+        self.insertion_order(seed);
+        let mut hint = 0;
+        for i in 0..self.order.len() {
+            let vertex = self.order[i];
+            if let Some(triangle) = self.insert(vertex, hint) {
+                hint = triangle;
+            } else if self.ambiguous {
+                // The exact hull keeps the point the fan would have dropped
+                return transformation::convex_hull(points);
+            }
+        }
+
+        self.output(points)
+    }
+
+    fn reset(&mut self, points: &[Point<f32>]) {
+        self.positions.clear();
+        self.positions
+            .extend(points.iter().map(|p| DVec3::new(p.x as f64, p.y as f64, p.z as f64)));
+        self.triangles.clear();
+        self.adjacent.clear();
+        self.alive.clear();
+        self.free.clear();
+        self.visited.clear();
+        self.stamp = 0;
+        self.ambiguous = false;
+        self.fan_start.clear();
+        self.fan_start.resize(points.len(), NONE);
+    }
+
+    /// Build a well-spread starting tetrahedron, returning its vertices
+    fn initial_tetrahedron(&mut self) -> Option<[u32; 4]> {
+        let p = &self.positions;
+        if p.len() < 4 {
+            return None;
+        }
+
+        let farthest = |score: &dyn Fn(DVec3) -> f64| {
+            (0..p.len()).max_by(|&a, &b| score(p[a]).total_cmp(&score(p[b]))).unwrap()
+        };
+        let a = farthest(&|v| v.x);
+        let b = farthest(&|v| v.distance_squared(p[a]));
+        let c = farthest(&|v| (v - p[a]).cross(p[b] - p[a]).length_squared());
+        let d = farthest(&|v| orient(p[a], p[b], p[c], v).abs());
+        if orient(p[a], p[b], p[c], p[d]) == 0.0 {
+            return None;
+        }
+
+        // Orient the base so the fourth vertex lies behind it
+        let (b, c) = if orient(p[a], p[b], p[c], p[d]) > 0.0 { (c, b) } else { (b, c) };
+        let [a, b, c, d] = [a, b, c, d].map(|v| v as u32);
+
+        // Faces: base (a, b, c) and the three sides, each seen CCW from outside
+        let faces = [[a, b, c], [a, d, b], [b, d, c], [c, d, a]];
+        for face in faces {
+            self.add_triangle(face, [NONE; 3]);
+        }
+        self.adjacent[0] = [1, 2, 3];
+        self.adjacent[1] = [3, 2, 0];
+        self.adjacent[2] = [1, 3, 0];
+        self.adjacent[3] = [2, 1, 0];
+
+        Some([a, b, c, d])
+    }
+
+    /// Sort the remaining vertices along a Morton curve for short location walks
+    fn insertion_order(&mut self, seed: [u32; 4]) {
+        let extent = self.positions.iter().fold(0.0f64, |m, v| m.max(v.abs().max_element())).max(f64::MIN_POSITIVE);
+        let key = |v: DVec3| {
+            let cell = |x: f64| (((x / extent + 1.0) * 0.5 * 1023.0).round().clamp(0.0, 1023.0)) as u32;
+            spread_bits(cell(v.x)) | spread_bits(cell(v.y)) << 1 | spread_bits(cell(v.z)) << 2
+        };
+
+        self.order.clear();
+        self.order.extend((0..self.positions.len() as u32).filter(|v| !seed.contains(v)));
+        let positions = &self.positions;
+        self.order.sort_by_cached_key(|&v| key(positions[v as usize]));
+    }
+
+    /// Insert one vertex; returns a new triangle to start the next walk from
+    ///
+    /// Returns `None` without changes for points hidden behind the hull
+    /// (duplicates), and also sets `ambiguous` if the visible region is not a
+    /// disc bounded by one simple loop.
+    fn insert(&mut self, vertex: u32, hint: u32) -> Option<u32> {
+        let p = self.positions[vertex as usize];
+        let start = self.locate(p, hint)?;
+
+        // Collect the connected region of faces visible from the point
+        self.stamp = self.stamp.wrapping_add(1);
+        if self.stamp == 0 {
+            self.visited.iter_mut().for_each(|s| *s = 0);
+            self.stamp = 1;
+        }
+        self.visible.clear();
+        self.stack.clear();
+        self.stack.push(start);
+        self.visited[start as usize] = self.stamp;
+        while let Some(t) = self.stack.pop() {
+            self.visible.push(t);
+            for &n in &self.adjacent[t as usize] {
+                if self.visited[n as usize] != self.stamp && self.is_visible(n, p) {
+                    self.visited[n as usize] = self.stamp;
+                    self.stack.push(n);
+                }
+            }
+        }
+
+        // The region's border must be one simple loop for the fan to close up
+        self.horizon.clear();
+        for &t in &self.visible {
+            for i in 0..3 {
+                let n = self.adjacent[t as usize][i];
+                if self.visited[n as usize] != self.stamp {
+                    let tri = self.triangles[t as usize];
+                    let Some(k) = self.adjacent[n as usize].iter().position(|&x| x == t) else {
+                        self.ambiguous = true;
+                        return None;
+                    };
+                    self.horizon.push((tri[i], tri[(i + 1) % 3], n, k));
+                }
+            }
+        }
+        let mut simple = true;
+        for &(from, ..) in &self.horizon {
+            if self.fan_start[from as usize] == 0 {
+                simple = false;
+            }
+            self.fan_start[from as usize] = 0;
+        }
+        if !simple {
+            for &(from, ..) in &self.horizon {
+                self.fan_start[from as usize] = NONE;
+            }
+            self.ambiguous = true;
+            return None;
+        }
+
+        for i in 0..self.visible.len() {
+            let t = self.visible[i];
+            self.alive[t as usize] = false;
+            self.free.push(t);
+        }
+
+        // Fan the horizon to the new vertex
+        for i in 0..self.horizon.len() {
+            let (from, to, outside, k) = self.horizon[i];
+            let t = self.add_triangle([from, to, vertex], [outside, NONE, NONE]);
+            self.adjacent[outside as usize][k] = t;
+            self.fan_start[from as usize] = t;
+        }
+        for i in 0..self.horizon.len() {
+            let (from, to, ..) = self.horizon[i];
+            let t = self.fan_start[from as usize];
+            let next = self.fan_start[to as usize];
+            self.adjacent[t as usize][1] = next;
+            self.adjacent[next as usize][2] = t;
+        }
+        let last = self.fan_start[self.horizon[0].0 as usize];
+        for i in 0..self.horizon.len() {
+            let from = self.horizon[i].0;
+            self.fan_start[from as usize] = NONE;
+        }
+
+        Some(last)
+    }
+
+    /// Find a face visible from `p`, walking across edges toward it
+    fn locate(&self, p: DVec3, hint: u32) -> Option<u32> {
+        let mut t = if self.alive.get(hint as usize) == Some(&true) {
+            hint
+        } else {
+            self.alive.iter().position(|&a| a)? as u32
+        };
+
+        'walk: for _ in 0..self.triangles.len() {
+            let tri = self.triangles[t as usize];
+            for i in 0..3 {
+                let a = self.positions[tri[i] as usize];
+                let b = self.positions[tri[(i + 1) % 3] as usize];
+                // `p` lies beyond the great circle through this edge
+                if a.cross(b).dot(p) < 0.0 {
+                    t = self.adjacent[t as usize][i];
+                    continue 'walk;
+                }
+            }
+            if self.is_visible(t, p) {
+                return Some(t);
+            }
+            break;
+        }
+
+        // The walk failed (only possible before the hull encloses the origin)
+        (0..self.triangles.len() as u32).find(|&t| self.alive[t as usize] && self.is_visible(t, p))
+    }
+
+    fn is_visible(&self, t: u32, p: DVec3) -> bool {
+        let [a, b, c] = self.triangles[t as usize].map(|v| self.positions[v as usize]);
+        // Rounding can put a duplicate of a corner slightly in front of the face
+        p != a && p != b && p != c && orient(a, b, c, p) > 0.0
+    }
+
+    fn add_triangle(&mut self, vertices: [u32; 3], adjacent: [u32; 3]) -> u32 {
+        if let Some(t) = self.free.pop() {
+            self.triangles[t as usize] = vertices;
+            self.adjacent[t as usize] = adjacent;
+            self.alive[t as usize] = true;
+            self.visited[t as usize] = 0;
+            t
+        } else {
+            self.triangles.push(vertices);
+            self.adjacent.push(adjacent);
+            self.alive.push(true);
+            self.visited.push(0);
+            (self.triangles.len() - 1) as u32
+        }
+    }
+
+    /// Compact to the inserted vertices (in input order) and live triangles
+    fn output(&mut self, points: &[Point<f32>]) -> (Vec<Point<f32>>, Vec<[u32; 3]>) {
+        let remap = &mut self.fan_start;
+        remap.iter_mut().for_each(|r| *r = NONE);
+        for (tri, _) in self.triangles.iter().zip(&self.alive).filter(|(_, &alive)| alive) {
+            for &v in tri {
+                remap[v as usize] = 0;
+            }
+        }
+
+        let mut vertices = Vec::new();
+        for (i, r) in remap.iter_mut().enumerate() {
+            if *r != NONE {
+                *r = vertices.len() as u32;
+                vertices.push(points[i]);
+            }
+        }
+
+        let triangles = self
+            .triangles
+            .iter()
+            .zip(&self.alive)
+            .filter(|(_, &alive)| alive)
+            .map(|(tri, _)| tri.map(|v| remap[v as usize]))
+            .collect();
+        remap.iter_mut().for_each(|r| *r = NONE);
+
+        (vertices, triangles)
+    }
+}
+
+/// Signed volume test: positive if `p` lies in front of the CCW face `(a, b, c)`
+#[inline]
+fn orient(a: DVec3, b: DVec3, c: DVec3, p: DVec3) -> f64 {
+    (b - a).cross(c - a).dot(p - a)
+}
+
+/// Interleave the low 10 bits of `x` with two zero bits each (3D Morton code)
+fn spread_bits(x: u32) -> u32 {
+    let mut x = x & 0x3FF;
+    x = (x | x << 16) & 0x030000FF;
+    x = (x | x << 8) & 0x0300F00F;
+    x = (x | x << 4) & 0x030C30C3;
+    x = (x | x << 2) & 0x09249249;
+    x
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generation::points::generate_sphere_points;
+    use std::collections::{BTreeSet, HashMap};
+
+    /// Undirected edges of a triangulation, as pairs of input point indices
+    fn edges(points: &[Point<f32>], vertices: &[Point<f32>], triangles: &[[u32; 3]]) -> BTreeSet<(usize, usize)> {
+        let input: HashMap<[u32; 3], usize> =
+            points.iter().enumerate().map(|(i, p)| ([p.x, p.y, p.z].map(f32::to_bits), i)).collect();
+        let ids: Vec<usize> = vertices.iter().map(|v| input[&[v.x, v.y, v.z].map(f32::to_bits)]).collect();
+
+        triangles
+            .iter()
+            .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+            .map(|(a, b)| {
+                let (a, b) = (ids[a as usize], ids[b as usize]);
+                (a.min(b), a.max(b))
+            })
+            .collect()
+    }
 
     #[test]
-    fn test_compute_delaunay() {
-        // Create a simple tetrahedron (4 points)
-        let points = vec![
-            Point::new(1.0, 0.0, 0.0),
-            Point::new(0.0, 1.0, 0.0),
-            Point::new(0.0, 0.0, 1.0),
-            Point::new(-1.0, -1.0, -1.0),
-        ];
-
-        let result = compute_delaunay(&points);
-
-        // Should have 4 vertices and 4 triangular faces (tetrahedron)
-        assert_eq!(result.vertices.len(), 4);
-        assert!(result.triangles.len() >= 4);
+    fn test_spherical_delaunay_matches_convex_hull() {
+        let points = generate_sphere_points(2000, 10.0, 42);
+        let (vertices, triangles) = spherical_delaunay(&points);
+
+        // Closed, consistently oriented surface: every directed edge appears
+        // once and its reverse once, and Euler's formula holds
+        assert_eq!(vertices.len(), points.len());
+        assert_eq!(triangles.len(), 2 * points.len() - 4);
+        let mut directed = std::collections::HashSet::new();
+        for t in &triangles {
+            for (a, b) in [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])] {
+                assert!(directed.insert((a, b)), "edge {}-{} used twice", a, b);
+            }
+        }
+        assert!(directed.iter().all(|&(a, b)| directed.contains(&(b, a))));
+
+        // Faces point outward
+        for t in &triangles {
+            let [a, b, c] = t.map(|i| { let v = vertices[i as usize]; glam::Vec3::new(v.x, v.y, v.z) });
+            assert!((b - a).cross(c - a).dot(a + b + c) > 0.0);
+        }
+
+        // Same neighbor graph as the hull backend
+        let (hull_vertices, hull_triangles) = transformation::convex_hull(&points);
+        assert_eq!(edges(&points, &vertices, &triangles), edges(&points, &hull_vertices, &hull_triangles));
+    }
+
+    #[test]
+    fn test_spherical_delaunay_degenerate_input() {
+        assert!(spherical_delaunay(&[]).1.is_empty());
+        let flat = [Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0), Point::new(-1.0, 0.0, 0.0), Point::new(0.0, -1.0, 0.0)];
+        assert!(spherical_delaunay(&flat).1.is_empty());
+
+        // Duplicates are left out, and buffers can be reused
+        let mut points = generate_sphere_points(100, 1.0, 7);
+        points.extend_from_within(..5);
+        let mut delaunay = IncrementalDelaunay::default();
+        let (vertices, triangles) = delaunay.triangulate(&points);
+        assert_eq!(vertices.len(), 100);
+        assert_eq!(triangles.len(), 196);
+        assert_eq!(delaunay.triangulate(&points[..100]).1.len(), 196);
+    }
+
+    #[test]
+    fn test_spherical_delaunay_keeps_every_point() {
+        for seed in 0..10 {
+            let points = generate_sphere_points(3000, 10.0, seed);
+            assert_eq!(spherical_delaunay(&points).0.len(), points.len(), "seed {}", seed);
+        }
+
+        // A lat/lon grid puts every ring of four neighbors on a common circle
+        let mut grid = vec![Point::new(0.0, 10.0, 0.0), Point::new(0.0, -10.0, 0.0)];
+        for lat in 1..60 {
+            let theta = lat as f32 / 60.0 * std::f32::consts::PI;
+            for lon in 0..120 {
+                let phi = lon as f32 / 120.0 * std::f32::consts::TAU;
+                grid.push(Point::new(theta.sin() * phi.cos(), theta.cos(), theta.sin() * phi.sin()) * 10.0);
+            }
+        }
+        let (vertices, triangles) = spherical_delaunay(&grid);
+        assert_eq!(vertices.len(), grid.len());
+        assert_eq!(triangles.len(), 2 * grid.len() - 4);
     }
 }
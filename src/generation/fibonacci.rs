@@ -25,7 +25,7 @@ use rand::Rng;
 use std::f32::consts::PI;
 
 /// Golden ratio φ = (1 + √5) / 2
-const PHI: f32 = 1.618033988749895;
+const PHI: f32 = 1.618_034;
 
 /// Jitter strength as fraction of average cell spacing
 /// 0.0 = no jitter (pure Fibonacci spiral)
@@ -0,0 +1,106 @@
+//! Prefix-Stable Halton Point Distribution
+//!
+//! Generates points from a Halton low-discrepancy sequence (bases 2 and 3)
+//! mapped onto the sphere with an area-preserving projection.
+//!
+//! Unlike the Fibonacci lattice, where every point's position depends on the
+//! total count, point `i` of a Halton sequence depends only on `i` and the seed.
+//! The first N points of a larger planet are therefore exactly the points of a
+//! smaller one, so increasing the cell count adds detail without moving the
+//! existing cells (as long as Lloyd's relaxation is disabled).
+//!
+//! The seed applies a Cranley-Patterson rotation (a random offset modulo 1 on
+//! both sequence axes), which keeps the prefix property intact.
+
+use parry3d::math::Point;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::f64::consts::TAU;
+
+/// Radical inverse of `index` in the given base (van der Corput sequence)
+fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    let inv_base = 1.0 / base as f64;
+    let mut factor = inv_base;
+    let mut result = 0.0;
+
+    while index > 0 {
+        result += (index % base) as f64 * factor;
+        index /= base;
+        factor *= inv_base;
+    }
+
+    result
+}
+
+/// Generate points on a sphere from a seeded Halton sequence
+///
+/// Point `i` depends only on `i` and `seed`, never on `count`, so
+/// `generate_halton_sphere_points(n, r, s)` is a prefix of
+/// `generate_halton_sphere_points(m, r, s)` for any `m >= n`.
+///
+/// # Arguments
+///
+/// * `count` - Number of points to generate
+/// * `radius` - Sphere radius
+/// * `seed` - Random seed for the sequence rotation
+///
+/// # Returns
+///
+/// Vector of points on the sphere surface, suitable for Delaunay triangulation
+pub fn generate_halton_sphere_points(count: usize, radius: f32, seed: u32) -> Vec<Point<f32>> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+    let offset_u: f64 = rng.gen_range(0.0..1.0);
+    let offset_v: f64 = rng.gen_range(0.0..1.0);
+    let radius = radius as f64;
+
+    (0..count as u64)
+        .map(|i| {
+            // Index 0 maps to (0, 0) in both bases, start at 1 to skip it
+            let u = (radical_inverse(i + 1, 2) + offset_u).fract();
+            let v = (radical_inverse(i + 1, 3) + offset_v).fract();
+
+            // Uniform in z and longitude is area-preserving on the sphere
+            let z = 1.0 - 2.0 * u;
+            let ring = (1.0 - z * z).max(0.0).sqrt();
+            let theta = TAU * v;
+
+            Point::new(
+                (ring * theta.cos() * radius) as f32,
+                (ring * theta.sin() * radius) as f32,
+                (z * radius) as f32,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points_on_sphere() {
+        let points = generate_halton_sphere_points(500, 10.0, 42);
+
+        assert_eq!(points.len(), 500);
+        for point in &points {
+            let distance = (point.x * point.x + point.y * point.y + point.z * point.z).sqrt();
+            assert!((distance - 10.0).abs() < 0.01, "Point not on sphere surface: distance={}", distance);
+        }
+    }
+
+    #[test]
+    fn test_prefix_stable() {
+        let small = generate_halton_sphere_points(300, 10.0, 7);
+        let large = generate_halton_sphere_points(400, 10.0, 7);
+
+        assert_eq!(&large[..300], &small[..]);
+        assert_ne!(generate_halton_sphere_points(10, 10.0, 8), small[..10]);
+    }
+
+    #[test]
+    fn test_radical_inverse() {
+        assert_eq!(radical_inverse(1, 2), 0.5);
+        assert_eq!(radical_inverse(3, 2), 0.75);
+        assert!((radical_inverse(5, 3) - 7.0 / 9.0).abs() < 1e-12);
+    }
+}
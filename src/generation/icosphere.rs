@@ -0,0 +1,216 @@
+//! Icosphere (Goldberg polyhedron) cell generation
+//!
+//! Builds perfectly regular cells from the dual of a subdivided icosahedron.
+//! Every cell is a hexagon except for exactly 12 pentagons sitting on the
+//! original icosahedron vertices. Useful as a uniform-topology alternative
+//! and as a reference when benchmarking the Voronoi output.
+
+use glam::Vec3;
+use std::collections::HashMap;
+
+use super::voronoi::{order_voronoi_vertices, RawCell};
+use crate::error::{Result, VoronoiError};
+
+/// Maximum supported subdivision level (655,362 cells)
+const MAX_SUBDIVISIONS: u32 = 8;
+
+/// Generate hexagon/pentagon cells from a subdivided icosahedron's dual
+///
+/// Each subdivision splits every triangle into four, so the result has
+/// `10 * 4^subdivisions + 2` cells. Cell centers are the icosphere vertices
+/// and cell vertices are the (normalized) centroids of the surrounding faces.
+///
+/// # Arguments
+///
+/// * `subdivisions` - Number of midpoint subdivision passes (0 = dodecahedron)
+/// * `radius` - Sphere radius
+///
+/// # Returns
+///
+/// Vector of raw cells in the same format as the Voronoi pipeline
+///
+/// # Errors
+///
+/// Returns `InvalidConfig` if `subdivisions` exceeds 8 or `radius` is not positive
+///
+/// # Example
+///
+/// ```rust
+/// use rust_voronoi_planet::generation::icosphere::generate_icosphere_cells;
+///
+/// let cells = generate_icosphere_cells(2, 10.0).unwrap();
+/// assert_eq!(cells.len(), 162);
+/// ```
+pub fn generate_icosphere_cells(subdivisions: u32, radius: f32) -> Result<Vec<RawCell>> {
+    if subdivisions > MAX_SUBDIVISIONS {
+        return Err(VoronoiError::InvalidConfig(format!(
+            "Icosphere subdivisions must be <= {} (got {})",
+            MAX_SUBDIVISIONS, subdivisions
+        )));
+    }
+    if radius <= 0.0 {
+        return Err(VoronoiError::InvalidConfig(format!(
+            "Icosphere radius must be positive (got {})",
+            radius
+        )));
+    }
+
+    let (vertices, triangles) = unit_icosphere(subdivisions);
+
+    // Build vertex -> triangle and vertex -> neighbor adjacency
+    let mut vertex_triangles: Vec<Vec<usize>> = vec![Vec::new(); vertices.len()];
+    let mut vertex_neighbors: Vec<Vec<usize>> = vec![Vec::new(); vertices.len()];
+    for (tri_idx, tri) in triangles.iter().enumerate() {
+        for i in 0..3 {
+            let a = tri[i];
+            let b = tri[(i + 1) % 3];
+            vertex_triangles[a].push(tri_idx);
+            vertex_neighbors[a].push(b);
+            vertex_neighbors[b].push(a);
+        }
+    }
+
+    let cells = vertices
+        .iter()
+        .enumerate()
+        .map(|(vertex_idx, &unit)| {
+            let center = unit * radius;
+
+            // Face centroids of the surrounding triangles form the dual polygon
+            let face_centers: Vec<Vec3> = vertex_triangles[vertex_idx]
+                .iter()
+                .map(|&tri_idx| {
+                    let [a, b, c] = triangles[tri_idx];
+                    (vertices[a] + vertices[b] + vertices[c]).normalize() * radius
+                })
+                .collect();
+
+            let mut neighbors = std::mem::take(&mut vertex_neighbors[vertex_idx]);
+            neighbors.sort();
+            neighbors.dedup();
+
+            RawCell {
+                id: vertex_idx,
+                center,
+                neighbors,
+                vertices: order_voronoi_vertices(face_centers, center, radius),
+            }
+        })
+        .collect();
+
+    Ok(cells)
+}
+
+/// Subdivided unit icosphere vertices and faces (counter-clockwise seen from outside)
+pub(crate) fn unit_icosphere(subdivisions: u32) -> (Vec<Vec3>, Vec<[usize; 3]>) {
+    let (mut vertices, mut triangles) = icosahedron();
+    for _ in 0..subdivisions {
+        triangles = subdivide(&mut vertices, &triangles);
+    }
+    (vertices, triangles)
+}
+
+/// Unit icosahedron vertices and faces
+fn icosahedron() -> (Vec<Vec3>, Vec<[usize; 3]>) {
+    let t = (1.0 + 5.0_f32.sqrt()) / 2.0;
+
+    let vertices = [
+        Vec3::new(-1.0, t, 0.0),
+        Vec3::new(1.0, t, 0.0),
+        Vec3::new(-1.0, -t, 0.0),
+        Vec3::new(1.0, -t, 0.0),
+        Vec3::new(0.0, -1.0, t),
+        Vec3::new(0.0, 1.0, t),
+        Vec3::new(0.0, -1.0, -t),
+        Vec3::new(0.0, 1.0, -t),
+        Vec3::new(t, 0.0, -1.0),
+        Vec3::new(t, 0.0, 1.0),
+        Vec3::new(-t, 0.0, -1.0),
+        Vec3::new(-t, 0.0, 1.0),
+    ]
+    .iter()
+    .map(|v| v.normalize())
+    .collect();
+
+    let triangles = vec![
+        [0, 11, 5], [0, 5, 1], [0, 1, 7], [0, 7, 10], [0, 10, 11],
+        [1, 5, 9], [5, 11, 4], [11, 10, 2], [10, 7, 6], [7, 1, 8],
+        [3, 9, 4], [3, 4, 2], [3, 2, 6], [3, 6, 8], [3, 8, 9],
+        [4, 9, 5], [2, 4, 11], [6, 2, 10], [8, 6, 7], [9, 8, 1],
+    ];
+
+    (vertices, triangles)
+}
+
+/// Split every triangle into four, projecting new edge midpoints onto the unit sphere
+fn subdivide(vertices: &mut Vec<Vec3>, triangles: &[[usize; 3]]) -> Vec<[usize; 3]> {
+    let mut midpoint_cache: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut midpoint = |a: usize, b: usize, vertices: &mut Vec<Vec3>| -> usize {
+        let key = (a.min(b), a.max(b));
+        *midpoint_cache.entry(key).or_insert_with(|| {
+            vertices.push(((vertices[a] + vertices[b]) * 0.5).normalize());
+            vertices.len() - 1
+        })
+    };
+
+    let mut result = Vec::with_capacity(triangles.len() * 4);
+    for &[a, b, c] in triangles {
+        let ab = midpoint(a, b, vertices);
+        let bc = midpoint(b, c, vertices);
+        let ca = midpoint(c, a, vertices);
+
+        result.push([a, ab, ca]);
+        result.push([b, bc, ab]);
+        result.push([c, ca, bc]);
+        result.push([ab, bc, ca]);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icosphere_cell_count() {
+        for subdivisions in 0..4 {
+            let cells = generate_icosphere_cells(subdivisions, 10.0).unwrap();
+            assert_eq!(cells.len(), 10 * 4usize.pow(subdivisions) + 2);
+        }
+    }
+
+    #[test]
+    fn test_icosphere_twelve_pentagons() {
+        let cells = generate_icosphere_cells(3, 10.0).unwrap();
+
+        let pentagons = cells.iter().filter(|c| c.vertices.len() == 5).count();
+        let hexagons = cells.iter().filter(|c| c.vertices.len() == 6).count();
+
+        assert_eq!(pentagons, 12);
+        assert_eq!(hexagons, cells.len() - 12);
+        for cell in &cells {
+            assert_eq!(cell.neighbors.len(), cell.vertices.len());
+        }
+    }
+
+    #[test]
+    fn test_icosphere_neighbor_symmetry() {
+        let cells = generate_icosphere_cells(2, 10.0).unwrap();
+
+        for cell in &cells {
+            for &neighbor_id in &cell.neighbors {
+                assert!(
+                    cells[neighbor_id].neighbors.contains(&cell.id),
+                    "Neighbor relationship should be symmetric"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_icosphere_invalid_input() {
+        assert!(generate_icosphere_cells(MAX_SUBDIVISIONS + 1, 10.0).is_err());
+        assert!(generate_icosphere_cells(1, 0.0).is_err());
+    }
+}
@@ -5,21 +5,37 @@
 
 use glam::Vec3;
 use parry3d::math::Point;
-use parry3d::transformation;
-use std::collections::HashMap;
 use std::time::Instant;
 
-/// Type alias for vertex-triangle adjacency map
-type VertexTriangleMap = HashMap<usize, Vec<usize>>;
+use super::delaunay::triangulate;
+use super::voronoi::fill_vertex_triangles;
+use super::GenerationScratch;
+use crate::logging::{log_debug, log_info};
+
+/// Which per-iteration displacement statistic is compared to the convergence threshold
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ConvergenceMode {
+    /// Largest movement of any point (strictest, one stubborn point keeps it running)
+    #[default]
+    MaxDisplacement,
+    /// Average movement over all points
+    MeanDisplacement,
+    /// Movement at the given percentile (0-100, e.g. `95.0`), ignoring the few
+    /// slowest points above it
+    Percentile(f32),
+}
 
 /// Options for Lloyd's relaxation algorithm
 #[derive(Debug, Clone, Copy)]
 pub struct LloydOptions {
     /// Maximum number of iterations to run
     pub max_iterations: usize,
-    /// Convergence threshold - stop when max displacement < this value
+    /// Convergence threshold - stop when the displacement statistic chosen by
+    /// `convergence_mode` is < this value (relative to the radius)
     /// Set to 0.0 to disable early termination
     pub convergence_threshold: f32,
+    /// Displacement statistic checked against the threshold (default: max)
+    pub convergence_mode: ConvergenceMode,
 }
 
 impl Default for LloydOptions {
@@ -30,10 +46,46 @@ impl Default for LloydOptions {
             // stop when max displacement < 0.113 units. This typically triggers after
             // 3-4 iterations, providing good uniformity with ~40% speedup.
             convergence_threshold: 0.01,
+            convergence_mode: ConvergenceMode::MaxDisplacement,
+        }
+    }
+}
+
+impl ConvergenceMode {
+    /// Reduce the displacements of one iteration to the value checked for convergence
+    ///
+    /// Reorders `displacements`. Returns 0 for an empty slice.
+    fn measure(self, displacements: &mut [f32]) -> f32 {
+        if displacements.is_empty() {
+            return 0.0;
+        }
+        match self {
+            ConvergenceMode::MaxDisplacement => displacements.iter().copied().fold(0.0, f32::max),
+            ConvergenceMode::MeanDisplacement => {
+                displacements.iter().map(|&d| d as f64).sum::<f64>() as f32 / displacements.len() as f32
+            }
+            ConvergenceMode::Percentile(percentile) => {
+                let fraction = if percentile.is_nan() { 1.0 } else { (percentile / 100.0).clamp(0.0, 1.0) };
+                let rank = ((displacements.len() - 1) as f32 * fraction).round() as usize;
+                *displacements.select_nth_unstable_by(rank, f32::total_cmp).1
+            }
         }
     }
 }
 
+/// Outcome of a Lloyd's relaxation run
+///
+/// Part of [`GenerationReport`](crate::stats::GenerationReport).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LloydReport {
+    /// Iterations actually run (0 if relaxation was disabled)
+    pub iterations: usize,
+    /// Whether the run stopped early because the convergence threshold was met
+    pub converged: bool,
+    /// Largest point movement in the last iteration, in world units
+    pub max_displacement: f32,
+}
+
 /// Apply Lloyd's Relaxation to improve point distribution uniformity
 ///
 /// Lloyd's Relaxation iteratively moves each seed point to the centroid of its
@@ -83,50 +135,74 @@ pub fn lloyd_relaxation(
 ///
 /// Relaxed points with improved uniformity
 pub fn lloyd_relaxation_with_options(
-    mut points: Vec<Point<f32>>,
+    points: Vec<Point<f32>>,
     radius: f32,
     options: LloydOptions,
 ) -> Vec<Point<f32>> {
+    lloyd_relaxation_with_scratch(points, radius, options, &[], &mut GenerationScratch::default()).0
+}
+
+/// Apply Lloyd's Relaxation, reusing the adjacency and point buffers in `scratch`
+///
+/// Points whose entry in `fixed` is `true` keep their position every
+/// iteration; missing entries count as `false`.
+pub(crate) fn lloyd_relaxation_with_scratch(
+    mut points: Vec<Point<f32>>,
+    radius: f32,
+    options: LloydOptions,
+    fixed: &[bool],
+    scratch: &mut GenerationScratch,
+) -> (Vec<Point<f32>>, LloydReport) {
     let convergence_threshold = options.convergence_threshold * radius;
     let total_start = Instant::now();
     let num_points = points.len();
 
-    eprintln!(
-        "[Lloyd] Starting: {} points, max {} iterations, threshold {:.4} (abs: {:.4})",
-        num_points, options.max_iterations, options.convergence_threshold, convergence_threshold
+    log_debug!(
+        "Lloyd starting: {} points, max {} iterations, threshold {:.4} (abs: {:.4}, {:?})",
+        num_points, options.max_iterations, options.convergence_threshold, convergence_threshold, options.convergence_mode
     );
 
     let mut iterations_run = 0;
     let mut converged = false;
+    let mut last_displacement = 0.0;
 
     for iteration in 0..options.max_iterations {
         let iter_start = Instant::now();
 
         // Compute convex hull from current points (this is the bottleneck - ~97% of time)
         let hull_start = Instant::now();
-        let (vertices, triangle_indices) = transformation::convex_hull(&points);
+        let (vertices, triangle_indices) = triangulate(&points, &mut scratch.delaunay);
         let hull_time = hull_start.elapsed();
 
         // Build vertex-triangle adjacency map
         let map_start = Instant::now();
-        let vertex_triangle_map = build_vertex_triangle_map(&triangle_indices);
+        fill_vertex_triangles(&triangle_indices, vertices.len(), &mut scratch.vertex_triangles);
         let map_time = map_start.elapsed();
 
-        // Calculate new positions with displacement tracking
+        // Calculate new positions with displacement tracking; the previous
+        // iteration's buffer is recycled for the output
         let points_start = Instant::now();
-        let (new_points, max_displacement) = compute_new_points(
+        let max_displacement = compute_new_points(
             &vertices,
-            &vertex_triangle_map,
+            &scratch.vertex_triangles,
             &triangle_indices,
             radius,
+            fixed,
+            &mut scratch.next_points,
+            &mut scratch.displacements,
         );
+        let measured = match options.convergence_mode {
+            ConvergenceMode::MaxDisplacement => max_displacement,
+            mode => mode.measure(&mut scratch.displacements),
+        };
         let points_time = points_start.elapsed();
 
-        points = new_points;
+        std::mem::swap(&mut points, &mut scratch.next_points);
         iterations_run = iteration + 1;
+        last_displacement = max_displacement;
 
-        eprintln!(
-            "[Lloyd] Iter {}: hull={:?}, map={:?}, points={:?}, total={:?}, max_disp={:.4}",
+        log_debug!(
+            "Lloyd iteration {}: hull={:?}, map={:?}, points={:?}, total={:?}, max_disp={:.4}",
             iteration + 1,
             hull_time,
             map_time,
@@ -136,12 +212,13 @@ pub fn lloyd_relaxation_with_options(
         );
 
         // Early exit if converged
-        if convergence_threshold > 0.0 && max_displacement < convergence_threshold {
+        if convergence_threshold > 0.0 && measured < convergence_threshold {
             converged = true;
-            eprintln!(
-                "[Lloyd] Converged at iteration {} (max_disp {:.4} < threshold {:.4})",
+            log_debug!(
+                "Lloyd converged at iteration {} ({:?} {:.4} < threshold {:.4})",
                 iteration + 1,
-                max_displacement,
+                options.convergence_mode,
+                measured,
                 convergence_threshold
             );
             break;
@@ -149,77 +226,71 @@ pub fn lloyd_relaxation_with_options(
     }
 
     let total_time = total_start.elapsed();
-    eprintln!(
-        "[Lloyd] Finished: {} iterations (of max {}), converged={}, total={:?}",
+    log_info!(
+        "Lloyd finished: {} iterations (of max {}), converged={}, total={:?}",
         iterations_run, options.max_iterations, converged, total_time
     );
 
-    points
+    let report = LloydReport {
+        iterations: iterations_run,
+        converged,
+        max_displacement: last_displacement,
+    };
+    (points, report)
 }
 
-/// Compute new point positions and track maximum displacement
+/// Compute new point positions into `out` and return the maximum displacement
+///
+/// Points marked in `fixed` are copied unchanged. The displacement of every
+/// other point is written to `displacements`.
 fn compute_new_points(
     vertices: &[Point<f32>],
-    vertex_triangle_map: &VertexTriangleMap,
+    vertex_triangles: &[Vec<usize>],
     triangle_indices: &[[u32; 3]],
     radius: f32,
-) -> (Vec<Point<f32>>, f32) {
+    fixed: &[bool],
+    out: &mut Vec<Point<f32>>,
+    displacements: &mut Vec<f32>,
+) -> f32 {
     let mut max_displacement: f32 = 0.0;
 
-    let new_points: Vec<Point<f32>> = (0..vertices.len())
-        .map(|vertex_idx| {
-            let old_pos = &vertices[vertex_idx];
-
-            // Get all triangles adjacent to this vertex
-            let adjacent_triangles = &vertex_triangle_map[&vertex_idx];
-
-            // Compute circumcenters of adjacent triangles
-            let circumcenters: Vec<Vec3> = adjacent_triangles
-                .iter()
-                .map(|&tri_idx| {
-                    compute_spherical_circumcenter(tri_idx, vertices, triangle_indices, radius)
-                })
-                .collect();
-
-            // Calculate centroid (average position)
-            let sum: Vec3 = circumcenters.iter().copied().sum();
-            let centroid = sum / circumcenters.len() as f32;
-
-            // Normalize back to sphere surface
-            let normalized = centroid.normalize() * radius;
-            let new_point = Point::new(normalized.x, normalized.y, normalized.z);
-
-            // Track displacement
-            let dx = new_point.x - old_pos.x;
-            let dy = new_point.y - old_pos.y;
-            let dz = new_point.z - old_pos.z;
-            let displacement = (dx * dx + dy * dy + dz * dz).sqrt();
-            if displacement > max_displacement {
-                max_displacement = displacement;
-            }
-
-            new_point
-        })
-        .collect();
+    out.clear();
+    displacements.clear();
+    out.extend((0..vertices.len()).map(|vertex_idx| {
+        let old_pos = &vertices[vertex_idx];
+        if fixed.get(vertex_idx).copied().unwrap_or(false) {
+            return *old_pos;
+        }
 
-    (new_points, max_displacement)
-}
+        // Get all triangles adjacent to this vertex
+        let adjacent_triangles = &vertex_triangles[vertex_idx];
 
-/// Build map from vertex index to all triangles that include it
-///
-/// This adjacency map is essential for finding all triangles adjacent to each seed point.
-fn build_vertex_triangle_map(triangle_indices: &[[u32; 3]]) -> VertexTriangleMap {
-    let mut map: VertexTriangleMap = HashMap::new();
-
-    for (tri_idx, triangle) in triangle_indices.iter().enumerate() {
-        for &vertex_idx in triangle.iter() {
-            map.entry(vertex_idx as usize)
-                .or_insert_with(Vec::new)
-                .push(tri_idx);
+        // Calculate centroid (average of circumcenters). Accumulate sequentially
+        // in triangle order so the result never depends on summation strategy.
+        let mut sum = Vec3::ZERO;
+        for &tri_idx in adjacent_triangles {
+            sum += compute_spherical_circumcenter(tri_idx, vertices, triangle_indices, radius);
         }
-    }
+        let centroid = sum / adjacent_triangles.len() as f32;
+
+        // Normalize back to sphere surface
+        let normalized = centroid.normalize() * radius;
+        let new_point = Point::new(normalized.x, normalized.y, normalized.z);
+
+        // Track displacement
+        let dx = new_point.x - old_pos.x;
+        let dy = new_point.y - old_pos.y;
+        let dz = new_point.z - old_pos.z;
+        let displacement = (dx * dx + dy * dy + dz * dz).sqrt();
+        displacements.push(displacement);
+        if displacement > max_displacement {
+            max_displacement = displacement;
+        }
+
+        new_point
+    }));
 
-    map
+    max_displacement
 }
 
 /// Compute the circumcenter of a spherical triangle
@@ -278,6 +349,37 @@ mod tests {
     use super::*;
     use crate::generation::points::generate_sphere_points;
 
+    #[cfg(feature = "logging")]
+    #[test]
+    fn test_lloyd_logs_progress() {
+        use std::sync::Mutex;
+
+        static RECORDS: Mutex<Vec<(log::Level, String)>> = Mutex::new(Vec::new());
+
+        struct CapturingLogger;
+        impl log::Log for CapturingLogger {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+            fn log(&self, record: &log::Record) {
+                if record.target().ends_with("lloyd") {
+                    RECORDS.lock().unwrap().push((record.level(), record.args().to_string()));
+                }
+            }
+            fn flush(&self) {}
+        }
+
+        // Other tests may log concurrently, so only look for this run's records
+        let _ = log::set_logger(&CapturingLogger);
+        log::set_max_level(log::LevelFilter::Debug);
+        lloyd_relaxation(generate_sphere_points(97, 10.0, 42), 10.0, 2);
+
+        let records = RECORDS.lock().unwrap();
+        assert!(records.iter().any(|(level, msg)| *level == log::Level::Debug && msg.contains("97 points")));
+        assert!(records.iter().any(|(level, msg)| *level == log::Level::Debug && msg.starts_with("Lloyd iteration")));
+        assert!(records.iter().any(|(level, msg)| *level == log::Level::Info && msg.starts_with("Lloyd finished")));
+    }
+
     #[test]
     fn test_lloyd_relaxation() {
         let points = generate_sphere_points(100, 10.0, 42);
@@ -315,6 +417,7 @@ mod tests {
         let options = LloydOptions {
             max_iterations: 10,
             convergence_threshold: 0.0001,
+            ..Default::default()
         };
         let relaxed = lloyd_relaxation_with_options(points, 10.0, options);
 
@@ -332,6 +435,45 @@ mod tests {
         let options = LloydOptions::default();
         assert_eq!(options.max_iterations, 5);
         assert!((options.convergence_threshold - 0.01).abs() < 0.001);
+        assert_eq!(options.convergence_mode, ConvergenceMode::MaxDisplacement);
+    }
+
+    #[test]
+    fn test_convergence_modes() {
+        // A relaxed lattice with one point pulled far out of place: the bulk is
+        // settled from the start while the outlier takes several iterations
+        let mut points = super::super::generate_fibonacci_sphere_points(400, 10.0, 1);
+        let first = Vec3::new(points[0].x, points[0].y, points[0].z);
+        let second = Vec3::new(points[1].x, points[1].y, points[1].z);
+        let pulled = (first * 0.55 + second * 0.45).normalize() * 10.0;
+        points[0] = Point::new(pulled.x, pulled.y, pulled.z);
+
+        let run = |mode| {
+            let options = LloydOptions {
+                max_iterations: 20,
+                convergence_threshold: 0.002,
+                convergence_mode: mode,
+            };
+            lloyd_relaxation_with_scratch(points.clone(), 10.0, options, &[], &mut GenerationScratch::default()).1
+        };
+
+        let max = run(ConvergenceMode::MaxDisplacement);
+        let mean = run(ConvergenceMode::MeanDisplacement);
+        let percentile = run(ConvergenceMode::Percentile(95.0));
+        assert!(mean.converged && percentile.converged);
+        assert!(mean.iterations < max.iterations, "mean {} vs max {}", mean.iterations, max.iterations);
+        assert!(percentile.iterations < max.iterations);
+    }
+
+    #[test]
+    fn test_convergence_mode_measure() {
+        let mut values = [4.0, 1.0, 3.0, 2.0, 100.0];
+        assert_eq!(ConvergenceMode::MaxDisplacement.measure(&mut values), 100.0);
+        assert_eq!(ConvergenceMode::MeanDisplacement.measure(&mut values), 22.0);
+        assert_eq!(ConvergenceMode::Percentile(50.0).measure(&mut values), 3.0);
+        assert_eq!(ConvergenceMode::Percentile(75.0).measure(&mut values), 4.0);
+        assert_eq!(ConvergenceMode::Percentile(100.0).measure(&mut values), 100.0);
+        assert_eq!(ConvergenceMode::MeanDisplacement.measure(&mut []), 0.0);
     }
 
     #[test]
@@ -341,6 +483,7 @@ mod tests {
         let options = LloydOptions {
             max_iterations: 3,
             convergence_threshold: 0.0,
+            ..Default::default()
         };
         let relaxed = lloyd_relaxation_with_options(points, 10.0, options);
 
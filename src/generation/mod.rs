@@ -1,49 +1,424 @@
 //! Core Voronoi generation algorithm
 //!
 //! Generates Voronoi cells on a sphere surface using Lloyd's relaxation
-//! and Delaunay triangulation via convex hull.
+//! and Delaunay triangulation via convex hull (or, with the
+//! `incremental-delaunay` feature, [`spherical_delaunay`]).
+//!
+//! # Determinism
+//!
+//! The same [`PlanetConfig`] produces bit-identical cells on every run:
+//! random numbers come from seeded `ChaCha8Rng`, vector sums are accumulated
+//! sequentially in cell/triangle order, and neighbor lists are sorted. Hash maps
+//! are only used for lookups, never for iteration order that reaches the output.
+//!
+//! Across platforms, `PointDistribution::Random` only relies on IEEE-exact
+//! operations (`+`, `*`, `/`, `sqrt`) and is expected to match exactly.
+//! `PointDistribution::Fibonacci` and `PointDistribution::Halton` use `sin`/`cos`, whose last bit may differ
+//! between math libraries, so tiny drift there is possible. A golden-value test
+//! pins the output for a known seed so regressions are caught.
+//!
+//! # Stability across cell counts
+//!
+//! Changing `cell_count` generally changes every cell. Random points share a
+//! prefix (point `i` is the same for any count), but Lloyd's relaxation moves
+//! every point depending on its neighbors, and the Fibonacci lattice spaces its
+//! points by the total count. Terrain sampled at cell centers therefore shifts
+//! everywhere. `PointDistribution::Halton` with `lloyd_iterations: 0` keeps the
+//! existing cells in place and only inserts new ones.
 
+mod dedup;
 mod delaunay;
 mod fibonacci;
+mod halton;
+pub mod icosphere;
 mod lloyd;
 mod points;
 mod voronoi;
 
+pub use dedup::{dedup_points, DEDUP_EPSILON};
+pub use delaunay::spherical_delaunay;
 pub use fibonacci::generate_fibonacci_sphere_points;
-pub use lloyd::{lloyd_relaxation, lloyd_relaxation_with_options, LloydOptions};
+pub use halton::generate_halton_sphere_points;
+pub use icosphere::generate_icosphere_cells;
+pub use lloyd::{lloyd_relaxation, lloyd_relaxation_with_options, ConvergenceMode, LloydOptions, LloydReport};
 pub use points::generate_sphere_points;
 pub use voronoi::{generate_cells, RawCell};
+pub(crate) use voronoi::tangent_basis;
+
+use glam::Vec3;
+use parry3d::math::Point;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 
-use crate::config::{PlanetConfig, PointDistribution};
-use crate::error::Result;
+use crate::config::{derive_seed, PlanetConfig, PointDistribution, SEED_STREAM_REPLACEMENT};
+use crate::error::{Result, VoronoiError};
+use crate::stats::GenerationReport;
+#[cfg(feature = "profiling")]
+use std::time::Instant;
+
+/// Reusable buffers for repeated generation runs
+///
+/// Holding on to these between planets (see [`PlanetGenerator`](crate::PlanetGenerator))
+/// avoids re-allocating the per-vertex adjacency lists and Lloyd point buffers
+/// every time. Contents are meaningless between calls.
+#[derive(Debug, Default)]
+pub(crate) struct GenerationScratch {
+    /// Triangles around each hull vertex, indexed by vertex
+    pub(crate) vertex_triangles: Vec<Vec<usize>>,
+    /// Lloyd output points, swapped with the input each iteration
+    pub(crate) next_points: Vec<Point<f32>>,
+    /// Per-point movement of the last Lloyd iteration
+    pub(crate) displacements: Vec<f32>,
+    /// Neighbor candidates of the cell being built
+    pub(crate) neighbors: Vec<usize>,
+    /// Triangle buffers of the incremental Delaunay backend
+    pub(crate) delaunay: delaunay::IncrementalDelaunay,
+}
 
 /// Generate raw Voronoi cells from configuration (without terrain)
 ///
 /// Returns cells with geometry only (center, vertices, neighbors).
 /// Terrain must be sampled separately.
+///
+/// With `dedup_points` enabled (the default) the result has exactly
+/// `config.cell_count()` cells: points closer than `DEDUP_EPSILON * radius`
+/// are merged before the hull is built, and each merged point is replaced by
+/// a seeded random point in the widest nearby gap. Replacements skip Lloyd's
+/// relaxation, so those rare cells may be slightly irregular. With
+/// `dedup_points` disabled, the hull silently drops duplicates and the count
+/// can come out lower.
 pub fn generate_raw_cells(config: &PlanetConfig) -> Result<Vec<RawCell>> {
+    generate_raw_cells_with_scratch(config, &mut GenerationScratch::default())
+}
+
+/// Generate raw Voronoi cells with a cell centered exactly on each anchor
+///
+/// Each anchor direction (any length, it is projected onto the sphere)
+/// replaces the nearest generated point and is held fixed during Lloyd's
+/// relaxation while the other points relax around it, so the cell count stays
+/// `config.cell_count()` and the anchored cells have their center at the
+/// anchor. Cells next to an anchor come out slightly less regular than
+/// elsewhere, since the relaxation cannot move the anchor to balance them.
+///
+/// # Errors
+///
+/// Returns `VoronoiError::InvalidConfig` if an anchor is zero or not finite,
+/// if two anchors are closer than `DEDUP_EPSILON * radius`, or if there are
+/// more anchors than cells.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+/// use rust_voronoi_planet::generation::generate_raw_cells_with_anchors;
+///
+/// let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+/// let capital = Vec3::new(0.3, 0.8, -0.5);
+/// let cells = generate_raw_cells_with_anchors(&config, &[capital]).unwrap();
+/// assert!(cells.iter().any(|c| c.center.normalize().distance(capital.normalize()) < 1e-5));
+/// ```
+pub fn generate_raw_cells_with_anchors(config: &PlanetConfig, anchors: &[Vec3]) -> Result<Vec<RawCell>> {
+    generate_raw_cells_anchored(
+        config,
+        anchors,
+        &mut GenerationScratch::default(),
+        &mut GenerationReport::default(),
+    )
+}
+
+/// Generate raw Voronoi cells, reusing the buffers in `scratch`
+pub(crate) fn generate_raw_cells_with_scratch(
+    config: &PlanetConfig,
+    scratch: &mut GenerationScratch,
+) -> Result<Vec<RawCell>> {
+    generate_raw_cells_with_report(config, scratch, &mut GenerationReport::default())
+}
+
+/// Generate raw Voronoi cells, recording Lloyd results, merged points and
+/// (with the `profiling` feature) stage timings in `report`
+pub(crate) fn generate_raw_cells_with_report(
+    config: &PlanetConfig,
+    scratch: &mut GenerationScratch,
+    report: &mut GenerationReport,
+) -> Result<Vec<RawCell>> {
+    generate_raw_cells_anchored(config, &[], scratch, report)
+}
+
+/// Full generation pipeline with optional anchor points, see
+/// [`generate_raw_cells_with_anchors`]
+pub(crate) fn generate_raw_cells_anchored(
+    config: &PlanetConfig,
+    anchors: &[Vec3],
+    scratch: &mut GenerationScratch,
+    report: &mut GenerationReport,
+) -> Result<Vec<RawCell>> {
     let radius = config.radius();
     let cell_count = config.cell_count();
+    #[cfg(feature = "profiling")]
+    let mut stage = Instant::now();
 
     // Step 1: Generate points on sphere using configured distribution method
-    let points = match config.point_distribution {
-        PointDistribution::Random => points::generate_sphere_points(cell_count, radius, config.seed),
+    let mut points = match config.point_distribution {
+        PointDistribution::Random => points::generate_sphere_points(cell_count, radius, config.layout_seed()),
         PointDistribution::Fibonacci => {
-            fibonacci::generate_fibonacci_sphere_points(cell_count, radius, config.seed)
+            fibonacci::generate_fibonacci_sphere_points(cell_count, radius, config.layout_seed())
         }
+        PointDistribution::Halton => halton::generate_halton_sphere_points(cell_count, radius, config.layout_seed()),
     };
+    let fixed = pin_anchors(&mut points, anchors, radius)?;
+    #[cfg(feature = "profiling")]
+    {
+        report.timings.points = stage.elapsed();
+        stage = Instant::now();
+    }
 
     // Step 2: Apply Lloyd's relaxation with convergence detection (if enabled)
     let points = if config.lloyd_iterations > 0 {
         let options = LloydOptions {
             max_iterations: config.lloyd_iterations,
             convergence_threshold: config.lloyd_convergence,
+            ..LloydOptions::default()
         };
-        lloyd::lloyd_relaxation_with_options(points, radius, options)
+        let (points, lloyd) = lloyd::lloyd_relaxation_with_scratch(points, radius, options, &fixed, scratch);
+        report.lloyd = lloyd;
+        points
+    } else {
+        points
+    };
+    #[cfg(feature = "profiling")]
+    {
+        report.timings.lloyd = stage.elapsed();
+        stage = Instant::now();
+    }
+
+    // Merge coincident points so the hull doesn't drop them silently, then
+    // replace the merged ones so the cell count matches the configuration
+    let points = if config.dedup_points {
+        let mut points = dedup::dedup_points(&points, DEDUP_EPSILON * radius);
+        report.merged_points = cell_count.saturating_sub(points.len());
+        let seed = derive_seed(config.layout_seed(), SEED_STREAM_REPLACEMENT);
+        top_up_points(&mut points, cell_count, radius, seed);
+        points
     } else {
         points
     };
 
     // Step 3-5: Generate cells from points
-    voronoi::generate_cells(&points, radius)
+    let cells = voronoi::generate_cells_with_scratch(&points, radius, scratch);
+    #[cfg(feature = "profiling")]
+    {
+        report.timings.cells = stage.elapsed();
+    }
+    cells
+}
+
+/// Replace the point nearest each anchor with the anchor itself
+///
+/// Returns a mask marking the anchored points (empty when there are no
+/// anchors), for Lloyd's relaxation to hold them in place.
+fn pin_anchors(points: &mut [Point<f32>], anchors: &[Vec3], radius: f32) -> Result<Vec<bool>> {
+    if anchors.is_empty() {
+        return Ok(Vec::new());
+    }
+    if anchors.len() > points.len() {
+        return Err(VoronoiError::InvalidConfig(format!(
+            "{} anchors but only {} cells",
+            anchors.len(),
+            points.len()
+        )));
+    }
+
+    let mut pinned: Vec<Vec3> = Vec::with_capacity(anchors.len());
+    for anchor in anchors {
+        let position = anchor
+            .try_normalize()
+            .ok_or_else(|| VoronoiError::InvalidConfig(format!("anchor {:?} has no direction", anchor)))?
+            * radius;
+        if pinned.iter().any(|p| p.distance(position) < DEDUP_EPSILON * radius) {
+            return Err(VoronoiError::InvalidConfig(format!(
+                "anchor {:?} coincides with another anchor",
+                anchor
+            )));
+        }
+        pinned.push(position);
+    }
+
+    let mut fixed = vec![false; points.len()];
+    for position in pinned {
+        let distance = |p: &Point<f32>| position.distance_squared(Vec3::new(p.x, p.y, p.z));
+        let nearest = (0..points.len())
+            .filter(|&i| !fixed[i])
+            .min_by(|&a, &b| distance(&points[a]).total_cmp(&distance(&points[b])));
+        if let Some(nearest) = nearest {
+            points[nearest] = Point::new(position.x, position.y, position.z);
+            fixed[nearest] = true;
+        }
+    }
+    Ok(fixed)
+}
+
+/// Candidates drawn per replacement point; the one farthest from all others wins
+const REPLACEMENT_CANDIDATES: usize = 32;
+
+/// Add seeded random points until there are `target` of them
+///
+/// Each new point is the best of a few random candidates (the one farthest
+/// from every existing point), so replacements fill gaps instead of landing
+/// next to an existing point. Only runs when points were merged, which is
+/// rare, so the O(n) distance scan per candidate doesn't matter.
+pub(crate) fn top_up_points(points: &mut Vec<Point<f32>>, target: usize, radius: f32, seed: u32) {
+    if points.len() >= target {
+        return;
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+    while points.len() < target {
+        let best = (0..REPLACEMENT_CANDIDATES)
+            .map(|_| {
+                let direction = Vec3::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0))
+                    .try_normalize()
+                    .unwrap_or(Vec3::X);
+                let candidate = direction * radius;
+                let clearance = points
+                    .iter()
+                    .map(|p| candidate.distance_squared(Vec3::new(p.x, p.y, p.z)))
+                    .fold(f32::INFINITY, f32::min);
+                (candidate, clearance)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(candidate, _)| candidate)
+            .unwrap_or(Vec3::X * radius);
+        points.push(Point::new(best.x, best.y, best.z));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{PlanetConfigBuilder, PlanetSize};
+
+    /// Golden values for a known configuration
+    ///
+    /// If this fails, generation output changed: either an intentional
+    /// algorithm change (update the values and note it as a breaking change
+    /// for saved games) or platform/compiler drift in floating point.
+    #[test]
+    fn test_golden_cell_centers() {
+        let config = PlanetConfigBuilder::new()
+            .seed(1234)
+            .planet_size(PlanetSize::Custom { cell_count: 200, radius: 10.0 })
+            .point_distribution(PointDistribution::Random)
+            .lloyd_iterations(2)
+            .unwrap()
+            .lloyd_convergence(0.0)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let cells = generate_raw_cells(&config).unwrap();
+        assert_eq!(cells.len(), 200);
+
+        let expected: [(usize, [f32; 3], &[usize]); 4] = [
+            (0, [3.3796515, -2.4670682, -9.082485], &[125, 130, 173, 184, 187]),
+            (57, [-3.1776812, -7.8471165, 5.322133], &[56, 99, 115, 123, 137, 152, 176]),
+            (123, [-5.3088355, -7.4467278, 4.0450587], &[57, 92, 115, 116, 131, 134, 176]),
+            (199, [-0.12662148, -9.855083, 1.6915399], &[29, 51, 98, 109, 145, 167, 195]),
+        ];
+
+        for (id, center, neighbors) in expected {
+            let cell = &cells[id];
+            let drift = (cell.center - glam::Vec3::from(center)).length();
+            assert!(drift < 1e-5, "cell {} center drifted by {}", id, drift);
+            assert_eq!(cell.neighbors, neighbors, "cell {} neighbors changed", id);
+        }
+    }
+
+    #[test]
+    fn test_point_seed_controls_layout() {
+        let build = |point_seed: Option<u32>| {
+            let mut builder = PlanetConfigBuilder::new().seed(5).terrain_seed(6).planet_size(PlanetSize::Tiny);
+            if let Some(point_seed) = point_seed {
+                builder = builder.point_seed(point_seed);
+            }
+            let config = builder.build().unwrap();
+            generate_raw_cells(&config).unwrap()
+        };
+        let centers = |cells: &[RawCell]| cells.iter().map(|c| c.center).collect::<Vec<_>>();
+
+        let a = build(Some(1));
+        assert_eq!(centers(&a), centers(&build(Some(1))));
+        assert_ne!(centers(&a), centers(&build(Some(2))));
+
+        // An explicit point seed equal to the planet seed is the default layout
+        assert_eq!(centers(&build(None)), centers(&build(Some(5))));
+    }
+
+    /// Fraction of probe directions whose nearest cell center moves when the
+    /// cell count grows from 500 to 520 (0 Lloyd iterations)
+    fn nearest_center_churn(distribution: PointDistribution) -> f32 {
+        let build = |cell_count| {
+            let config = PlanetConfigBuilder::new()
+                .seed(99)
+                .planet_size(PlanetSize::Custom { cell_count, radius: 10.0 })
+                .point_distribution(distribution)
+                .lloyd_iterations(0)
+                .unwrap()
+                .build()
+                .unwrap();
+            generate_raw_cells(&config).unwrap()
+        };
+        let nearest = |cells: &[RawCell], p: glam::Vec3| {
+            cells
+                .iter()
+                .map(|c| c.center)
+                .min_by(|a, b| a.distance_squared(p).total_cmp(&b.distance_squared(p)))
+                .unwrap()
+        };
+
+        let small = build(500);
+        let large = build(520);
+        let probes = fibonacci::generate_fibonacci_sphere_points(2000, 10.0, 1);
+
+        let changed = probes
+            .iter()
+            .map(|p| glam::Vec3::new(p.x, p.y, p.z))
+            .filter(|&p| nearest(&small, p).distance(nearest(&large, p)) > 1e-4)
+            .count();
+        changed as f32 / probes.len() as f32
+    }
+
+    /// Characterizes how much terrain sampling shifts between adjacent cell counts
+    #[test]
+    fn test_cell_count_stability() {
+        let fibonacci = nearest_center_churn(PointDistribution::Fibonacci);
+        let halton = nearest_center_churn(PointDistribution::Halton);
+
+        // Fibonacci moves nearly every cell; Halton only adds ~4% new cells
+        assert!(fibonacci > 0.9, "fibonacci churn {}", fibonacci);
+        assert!(halton < 0.1, "halton churn {}", halton);
+    }
+
+    #[test]
+    fn test_top_up_replaces_merged_points() {
+        let radius = 10.0;
+        let mut points = points::generate_sphere_points(100, radius, 42);
+        points.extend_from_within(..10);
+
+        let mut deduped = dedup_points(&points, DEDUP_EPSILON * radius);
+        assert_eq!(deduped.len(), 100);
+        top_up_points(&mut deduped, points.len(), radius, 7);
+        assert_eq!(deduped.len(), 110);
+        assert_eq!(dedup_points(&deduped, DEDUP_EPSILON * radius).len(), 110);
+        assert!(deduped.iter().all(|p| (Vec3::new(p.x, p.y, p.z).length() - radius).abs() < 1e-4));
+
+        let cells = voronoi::generate_cells(&deduped, radius).unwrap();
+        assert_eq!(cells.len(), 110);
+
+        // Deterministic, and a no-op once the target is reached
+        let mut again = dedup_points(&points, DEDUP_EPSILON * radius);
+        top_up_points(&mut again, points.len(), radius, 7);
+        assert_eq!(again, deduped);
+        top_up_points(&mut again, 50, radius, 7);
+        assert_eq!(again.len(), 110);
+    }
 }
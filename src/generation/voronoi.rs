@@ -5,13 +5,10 @@
 
 use glam::Vec3;
 use parry3d::math::Point;
-use parry3d::transformation;
-use std::collections::{HashMap, HashSet};
-
 use crate::error::Result;
 
-/// Type alias for vertex-triangle adjacency map
-type VertexTriangleMap = HashMap<usize, Vec<usize>>;
+use super::delaunay::triangulate;
+use super::GenerationScratch;
 
 /// A Voronoi cell without terrain (geometry only)
 ///
@@ -44,20 +41,26 @@ pub struct RawCell {
 ///
 /// Vector of raw cells (without terrain), one per input point
 pub fn generate_cells(points: &[Point<f32>], radius: f32) -> Result<Vec<RawCell>> {
-    // Step 3: Compute convex hull (Delaunay triangulation)
-    let (vertices, triangle_indices) = transformation::convex_hull(points);
+    generate_cells_with_scratch(points, radius, &mut GenerationScratch::default())
+}
 
-    // Step 4: Build vertex-triangle adjacency map
-    let vertex_triangle_map = build_vertex_triangle_map(&triangle_indices);
+/// Generate Voronoi cells, reusing the adjacency buffers in `scratch`
+pub(crate) fn generate_cells_with_scratch(
+    points: &[Point<f32>],
+    radius: f32,
+    scratch: &mut GenerationScratch,
+) -> Result<Vec<RawCell>> {
+    // Step 3: Compute convex hull (Delaunay triangulation)
+    let (vertices, triangle_indices) = triangulate(points, &mut scratch.delaunay);
 
-    // Step 4b: Build triangle-vertex reverse lookup for O(1) neighbor finding
-    let triangle_vertex_map = build_triangle_vertex_map(&vertex_triangle_map);
+    // Step 4: Build vertex-triangle adjacency
+    fill_vertex_triangles(&triangle_indices, vertices.len(), &mut scratch.vertex_triangles);
 
     // Step 5: Construct Voronoi cells
     let cells: Vec<RawCell> = (0..vertices.len())
         .map(|vertex_idx| {
             // Get all triangles adjacent to this vertex
-            let adjacent_triangles = &vertex_triangle_map[&vertex_idx];
+            let adjacent_triangles = &scratch.vertex_triangles[vertex_idx];
 
             // Compute circumcenters of adjacent triangles (Voronoi cell vertices)
             let circumcenters: Vec<Vec3> = adjacent_triangles
@@ -83,7 +86,12 @@ pub fn generate_cells(points: &[Point<f32>], radius: f32) -> Result<Vec<RawCell>
             let ordered_vertices = order_voronoi_vertices(circumcenters, center, radius);
 
             // Determine neighbors (cells that share circumcenters/edges)
-            let neighbors = find_cell_neighbors(vertex_idx, &vertex_triangle_map, &triangle_vertex_map);
+            let neighbors = find_cell_neighbors(
+                vertex_idx,
+                adjacent_triangles,
+                &triangle_indices,
+                &mut scratch.neighbors,
+            );
 
             RawCell {
                 id: vertex_idx,
@@ -97,40 +105,27 @@ pub fn generate_cells(points: &[Point<f32>], radius: f32) -> Result<Vec<RawCell>
     Ok(cells)
 }
 
-/// Build map from vertex index to all triangles that include it
+/// Fill `map[v]` with the indices of all triangles that include vertex `v`
 ///
-/// This adjacency map is essential for finding all triangles adjacent to each seed point.
-fn build_vertex_triangle_map(triangle_indices: &[[u32; 3]]) -> VertexTriangleMap {
-    let mut map: VertexTriangleMap = HashMap::new();
+/// This adjacency is essential for finding all triangles adjacent to each seed
+/// point. Triangles are listed in index order. The inner vectors are cleared
+/// rather than dropped, so refilling the same buffer doesn't reallocate.
+pub(crate) fn fill_vertex_triangles(
+    triangle_indices: &[[u32; 3]],
+    vertex_count: usize,
+    map: &mut Vec<Vec<usize>>,
+) {
+    map.truncate(vertex_count);
+    for triangles in map.iter_mut() {
+        triangles.clear();
+    }
+    map.resize_with(vertex_count, Vec::new);
 
     for (tri_idx, triangle) in triangle_indices.iter().enumerate() {
         for &vertex_idx in triangle.iter() {
-            map.entry(vertex_idx as usize)
-                .or_insert_with(Vec::new)
-                .push(tri_idx);
-        }
-    }
-
-    map
-}
-
-/// Build reverse lookup from triangle index to vertices in that triangle
-///
-/// This allows O(1) lookup of which vertices are in a given triangle,
-/// avoiding the O(N²) iteration over all vertices.
-fn build_triangle_vertex_map(vertex_triangle_map: &VertexTriangleMap) -> HashMap<usize, Vec<usize>> {
-    let mut triangle_vertex_map: HashMap<usize, Vec<usize>> = HashMap::new();
-
-    for (&vertex_idx, triangle_list) in vertex_triangle_map.iter() {
-        for &tri_idx in triangle_list {
-            triangle_vertex_map
-                .entry(tri_idx)
-                .or_insert_with(Vec::new)
-                .push(vertex_idx);
+            map[vertex_idx as usize].push(tri_idx);
         }
     }
-
-    triangle_vertex_map
 }
 
 /// Compute the circumcenter of a spherical triangle
@@ -172,27 +167,37 @@ fn compute_spherical_circumcenter(
     normal.normalize() * radius
 }
 
+/// Orthonormal tangent basis (u, v) at a unit normal
+///
+/// The reference axis is the world axis least aligned with the normal, so the
+/// cross product never degenerates, including exactly at the poles. `(u, v,
+/// normal)` is right-handed. Shared by vertex ordering, UVs and interpolation
+/// so they all agree on orientation.
+pub(crate) fn tangent_basis(normal: Vec3) -> (Vec3, Vec3) {
+    let abs = normal.abs();
+    let reference = if abs.x <= abs.y && abs.x <= abs.z {
+        Vec3::X
+    } else if abs.y <= abs.z {
+        Vec3::Y
+    } else {
+        Vec3::Z
+    };
+
+    let tangent_u = reference.cross(normal).normalize();
+    let tangent_v = normal.cross(tangent_u).normalize();
+    (tangent_u, tangent_v)
+}
+
 /// Order Voronoi cell vertices counter-clockwise around seed point
 ///
 /// Projects circumcenters onto the tangent plane and sorts by angle.
-fn order_voronoi_vertices(circumcenters: Vec<Vec3>, seed_point: Vec3, _radius: f32) -> Vec<Vec3> {
+pub(super) fn order_voronoi_vertices(circumcenters: Vec<Vec3>, seed_point: Vec3, _radius: f32) -> Vec<Vec3> {
     if circumcenters.len() < 3 {
         return circumcenters;
     }
 
-    // Get normal at seed point
-    let normal = seed_point.normalize();
-
-    // Choose reference direction in tangent plane
-    let reference = if normal.x.abs() > 0.5 {
-        Vec3::new(0.0, 1.0, 0.0)
-    } else {
-        Vec3::new(1.0, 0.0, 0.0)
-    };
-
-    // Create orthogonal basis on tangent plane
-    let tangent_u = reference.cross(normal).normalize();
-    let tangent_v = normal.cross(tangent_u).normalize();
+    // Create orthogonal basis on tangent plane at the seed point
+    let (tangent_u, tangent_v) = tangent_basis(seed_point.normalize());
 
     // Compute angle for each circumcenter
     let mut vertices_with_angles: Vec<(Vec3, f32)> = circumcenters
@@ -215,33 +220,26 @@ fn order_voronoi_vertices(circumcenters: Vec<Vec3>, seed_point: Vec3, _radius: f
 /// Find neighbor cells by checking shared triangles
 ///
 /// Two cells are neighbors if they share at least one Delaunay triangle.
-///
-/// OPTIMIZED: Instead of iterating over all vertices for each triangle (O(N²)),
-/// we build a reverse lookup from triangles to vertices once.
+/// Candidates are collected in `scratch`, then sorted and deduplicated.
 fn find_cell_neighbors(
     cell_idx: usize,
-    vertex_triangle_map: &VertexTriangleMap,
-    triangle_vertex_map: &HashMap<usize, Vec<usize>>,
+    my_triangles: &[usize],
+    triangle_indices: &[[u32; 3]],
+    scratch: &mut Vec<usize>,
 ) -> Vec<usize> {
-    let my_triangles = &vertex_triangle_map[&cell_idx];
-
-    // Find all vertices that share triangles with this cell
-    let mut neighbors = HashSet::new();
-
+    scratch.clear();
     for &tri_idx in my_triangles {
-        // Get all vertices in this triangle directly (O(1) lookup)
-        if let Some(triangle_vertices) = triangle_vertex_map.get(&tri_idx) {
-            for &vertex in triangle_vertices {
-                if vertex != cell_idx {
-                    neighbors.insert(vertex);
-                }
+        for &vertex in &triangle_indices[tri_idx] {
+            if vertex as usize != cell_idx {
+                scratch.push(vertex as usize);
             }
         }
     }
 
-    let mut neighbor_list: Vec<usize> = neighbors.into_iter().collect();
-    neighbor_list.sort(); // Deterministic ordering
-    neighbor_list
+    // Deterministic ordering
+    scratch.sort_unstable();
+    scratch.dedup();
+    scratch.clone()
 }
 
 #[cfg(test)]
@@ -259,7 +257,7 @@ mod tests {
         // Verify all cells have valid geometry
         for cell in &cells {
             assert!(cell.vertices.len() >= 3, "Cell should have at least 3 vertices");
-            assert!(cell.neighbors.len() > 0, "Cell should have neighbors");
+            assert!(!cell.neighbors.is_empty(), "Cell should have neighbors");
 
             // Verify center is on sphere surface
             let distance = cell.center.length();
@@ -289,4 +287,34 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_order_vertices_at_poles() {
+        for pole in [Vec3::X, Vec3::Y, Vec3::Z, Vec3::NEG_X, Vec3::NEG_Y, Vec3::NEG_Z] {
+            let (u, v) = tangent_basis(pole);
+            assert!(u.is_finite() && v.is_finite());
+            assert!(u.dot(pole).abs() < 1e-6 && v.dot(pole).abs() < 1e-6);
+            assert!((u.cross(v) - pole).length() < 1e-6);
+        }
+
+        // Hexagon around the +Y pole, supplied out of order
+        let center = Vec3::Y * 10.0;
+        let ring: Vec<Vec3> = [0, 3, 1, 5, 2, 4]
+            .iter()
+            .map(|&k| {
+                let angle = k as f32 * std::f32::consts::TAU / 6.0;
+                Vec3::new(angle.cos(), 5.0, angle.sin()).normalize() * 10.0
+            })
+            .collect();
+
+        let ordered = order_voronoi_vertices(ring, center, 10.0);
+        assert_eq!(ordered.len(), 6);
+        assert!(ordered.iter().all(|v| v.is_finite()));
+
+        // Consecutive vertices turn counter-clockwise seen from outside
+        for i in 0..ordered.len() {
+            let (a, b) = (ordered[i] - center, ordered[(i + 1) % ordered.len()] - center);
+            assert!(a.cross(b).dot(center) > 0.0);
+        }
+    }
 }
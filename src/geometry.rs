@@ -0,0 +1,131 @@
+//! Small spherical geometry helpers shared across the crate
+//!
+//! Longitude follows the crate-wide convention `atan2(z, x)` in radians,
+//! in (-π, π], with the antimeridian at ±π. Differences between longitudes
+//! must go through [`longitude_delta`] (signed, shortest way) or
+//! [`longitude_east`] (eastward extent, which may exceed π) so they stay
+//! correct across that seam.
+
+use glam::Vec3;
+use std::f32::consts::{PI, TAU};
+
+/// Longitude of a direction in radians, in (-π, π]
+///
+/// Measured in the XZ plane from +X towards +Z, matching
+/// [`LatLonBounds`](crate::LatLonBounds). Returns 0 at the poles.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::{geometry::longitude, Vec3};
+///
+/// assert_eq!(longitude(Vec3::X), 0.0);
+/// assert_eq!(longitude(Vec3::NEG_X), std::f32::consts::PI);
+/// ```
+#[inline]
+pub fn longitude(direction: Vec3) -> f32 {
+    direction.z.atan2(direction.x)
+}
+
+/// Signed shortest angular difference from longitude `a` to `b`, in radians
+///
+/// Accounts for the ±π wrap: the result is `b - a` brought into (-π, π], so
+/// positive means `b` lies east of `a`. Exactly opposite longitudes give +π.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::geometry::longitude_delta;
+///
+/// // From 179°E to 179°W is 2° east, not 358° west
+/// let delta = longitude_delta(179f32.to_radians(), (-179f32).to_radians());
+/// assert!((delta.to_degrees() - 2.0).abs() < 1e-4);
+/// ```
+#[inline]
+pub fn longitude_delta(a: f32, b: f32) -> f32 {
+    let delta = longitude_east(a, b);
+    if delta > PI {
+        delta - TAU
+    } else {
+        delta
+    }
+}
+
+/// Angle travelled going east from longitude `a` to `b`, in radians, in [0, 2π)
+///
+/// Unlike [`longitude_delta`] this never takes the shorter way round, so it
+/// measures the width of longitude ranges wider than half the planet.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::geometry::longitude_east;
+///
+/// // 179°E to 179°W is 2° east; the way back is 358°
+/// let east = longitude_east(179f32.to_radians(), (-179f32).to_radians());
+/// assert!((east.to_degrees() - 2.0).abs() < 1e-4);
+/// let back = longitude_east((-179f32).to_radians(), 179f32.to_radians());
+/// assert!((back.to_degrees() - 358.0).abs() < 1e-3);
+/// ```
+#[inline]
+pub fn longitude_east(a: f32, b: f32) -> f32 {
+    let east = (b - a).rem_euclid(TAU);
+    // rem_euclid can round up to exactly TAU for tiny negative inputs
+    if east >= TAU {
+        0.0
+    } else {
+        east
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delta_deg(a: f32, b: f32) -> f32 {
+        longitude_delta(a.to_radians(), b.to_radians()).to_degrees()
+    }
+
+    #[test]
+    fn test_longitude_delta() {
+        // Across the antimeridian in both directions
+        assert!((delta_deg(179.0, -179.0) - 2.0).abs() < 1e-4);
+        assert!((delta_deg(-179.0, 179.0) + 2.0).abs() < 1e-4);
+        assert!((delta_deg(170.0, -100.0) - 90.0).abs() < 1e-4);
+
+        // Ordinary differences are unchanged
+        assert!((delta_deg(10.0, 30.0) - 20.0).abs() < 1e-4);
+        assert!((delta_deg(30.0, 10.0) + 20.0).abs() < 1e-4);
+        assert_eq!(longitude_delta(1.0, 1.0), 0.0);
+
+        // Antisymmetric away from the ±180° tie, and always in (-π, π]
+        for i in 0..360 {
+            let a = (i as f32 * 7.3).to_radians() - PI;
+            let b = (i as f32 * 13.1).to_radians() - PI;
+            let d = longitude_delta(a, b);
+            assert!(d > -PI && d <= PI);
+            if d.abs() < PI - 1e-3 {
+                assert!((d + longitude_delta(b, a)).abs() < 1e-4);
+            }
+        }
+        assert!((longitude_delta(0.0, PI) - PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_longitude_east() {
+        let east_deg = |a: f32, b: f32| longitude_east(a.to_radians(), b.to_radians()).to_degrees();
+        assert!((east_deg(179.0, -179.0) - 2.0).abs() < 1e-4);
+        assert!((east_deg(-179.0, 179.0) - 358.0).abs() < 1e-3);
+        assert!((east_deg(-90.0, 90.0) - 180.0).abs() < 1e-4);
+        assert_eq!(longitude_east(1.0, 1.0), 0.0);
+        assert!(longitude_east(0.0, -1e-9) < TAU);
+    }
+
+    #[test]
+    fn test_longitude() {
+        assert_eq!(longitude(Vec3::X), 0.0);
+        assert!((longitude(Vec3::Z) - PI / 2.0).abs() < 1e-6);
+        assert!((longitude(Vec3::new(-1.0, 0.0, -1e-7)) + PI).abs() < 1e-5);
+        assert_eq!(longitude(Vec3::Y), 0.0);
+    }
+}
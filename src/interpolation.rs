@@ -0,0 +1,211 @@
+//! Scattered data interpolation over cell centers
+//!
+//! Both methods work in the tangent plane at the query point (gnomonic
+//! projection), which is accurate as long as cells are small compared to
+//! the sphere radius.
+
+use glam::{Vec2, Vec3};
+
+use crate::generation::tangent_basis;
+
+/// Local 2D frame on the tangent plane at a point of the sphere
+struct TangentFrame {
+    normal: Vec3,
+    u: Vec3,
+    v: Vec3,
+}
+
+impl TangentFrame {
+    fn new(position: Vec3) -> Self {
+        let normal = position.normalize();
+        let (u, v) = tangent_basis(normal);
+        Self { normal, u, v }
+    }
+
+    /// Gnomonic projection of a direction onto the tangent plane (unit sphere)
+    fn project(&self, point: Vec3) -> Vec2 {
+        let dir = point.normalize();
+        let on_plane = dir / dir.dot(self.normal).max(f32::EPSILON) - self.normal;
+        Vec2::new(on_plane.dot(self.u), on_plane.dot(self.v))
+    }
+}
+
+/// Clip a convex polygon to the half-plane of points closer to `a` than to `b`
+fn clip_closer_to(polygon: &[Vec2], a: Vec2, b: Vec2) -> Vec<Vec2> {
+    // Inside: (b - a)·x <= (|b|² - |a|²) / 2
+    let normal = b - a;
+    let limit = (b.length_squared() - a.length_squared()) * 0.5;
+    let side = |p: Vec2| normal.dot(p) - limit;
+
+    let mut result = Vec::with_capacity(polygon.len() + 1);
+    for i in 0..polygon.len() {
+        let current = polygon[i];
+        let next = polygon[(i + 1) % polygon.len()];
+        let (sc, sn) = (side(current), side(next));
+
+        if sc <= 0.0 {
+            result.push(current);
+        }
+        if (sc <= 0.0) != (sn <= 0.0) {
+            let t = sc / (sc - sn);
+            result.push(current + (next - current) * t);
+        }
+    }
+    result
+}
+
+/// Area of a simple polygon (shoelace formula)
+fn polygon_area(polygon: &[Vec2]) -> f32 {
+    let mut twice_area = 0.0;
+    for i in 0..polygon.len() {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+        twice_area += a.perp_dot(b);
+    }
+    (twice_area * 0.5).abs()
+}
+
+/// Compute Sibson (natural neighbor) weights for a query point
+///
+/// `sites` are the centers of candidate cells around the query; they must
+/// surround it (e.g. the 2-ring of the nearest cell). Returns one weight per
+/// site, summing to 1. Sites that are not natural neighbors get weight 0.
+pub fn sibson_weights(query: Vec3, sites: &[Vec3]) -> Vec<f32> {
+    if sites.is_empty() {
+        return Vec::new();
+    }
+
+    let frame = TangentFrame::new(query);
+    let projected: Vec<Vec2> = sites.iter().map(|&s| frame.project(s)).collect();
+    let q = Vec2::ZERO;
+
+    // Bounding square comfortably larger than the query's new cell
+    let extent = projected.iter().map(|p| p.length()).fold(0.0, f32::max) * 2.0 + f32::EPSILON;
+    let square = [
+        Vec2::new(-extent, -extent),
+        Vec2::new(extent, -extent),
+        Vec2::new(extent, extent),
+        Vec2::new(-extent, extent),
+    ];
+
+    // Voronoi cell the query point would own if inserted
+    let mut query_cell = square.to_vec();
+    for &site in &projected {
+        query_cell = clip_closer_to(&query_cell, q, site);
+        if query_cell.is_empty() {
+            break;
+        }
+    }
+
+    // Area stolen from each site = part of the query cell previously owned by it
+    let stolen: Vec<f32> = (0..projected.len())
+        .map(|i| {
+            let mut region = query_cell.clone();
+            for (j, &other) in projected.iter().enumerate() {
+                if j != i && !region.is_empty() {
+                    region = clip_closer_to(&region, projected[i], other);
+                }
+            }
+            polygon_area(&region)
+        })
+        .collect();
+
+    let total: f32 = stolen.iter().sum();
+    if total <= f32::EPSILON {
+        // Query coincides with a site: that site takes all the weight
+        let nearest = (0..projected.len())
+            .min_by(|&a, &b| projected[a].length().total_cmp(&projected[b].length()))
+            .unwrap_or(0);
+        return (0..sites.len()).map(|i| if i == nearest { 1.0 } else { 0.0 }).collect();
+    }
+
+    stolen.into_iter().map(|area| area / total).collect()
+}
+
+/// Compute barycentric weights of a query point in a spherical triangle
+///
+/// The query is projected through the sphere center onto the triangle's
+/// plane. Returns `None` if it falls outside the triangle (by more than a
+/// small tolerance).
+pub fn barycentric_weights(query: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Option<[f32; 3]> {
+    let normal = (b - a).cross(c - a);
+    let dir = query.normalize();
+    let denom = normal.dot(dir);
+    if denom.abs() <= f32::EPSILON {
+        return None;
+    }
+
+    // Intersect the ray from the origin with the triangle plane
+    let distance = normal.dot(a) / denom;
+    if distance <= 0.0 {
+        return None;
+    }
+    let point = dir * distance;
+
+    let area = normal.length();
+    let wa = (c - b).cross(point - b).dot(normal) / (area * area);
+    let wb = (a - c).cross(point - c).dot(normal) / (area * area);
+    let wc = 1.0 - wa - wb;
+
+    let tolerance = -1e-4;
+    (wa >= tolerance && wb >= tolerance && wc >= tolerance).then_some([wa, wb, wc])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clip_and_area() {
+        let square = vec![
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+        ];
+        assert!((polygon_area(&square) - 4.0).abs() < 1e-6);
+
+        // Keep the half closer to the origin than to (2, 0): x <= 1 (whole square)
+        let clipped = clip_closer_to(&square, Vec2::ZERO, Vec2::new(2.0, 0.0));
+        assert!((polygon_area(&clipped) - 4.0).abs() < 1e-6);
+
+        // Closer to origin than to (1, 0): x <= 0.5
+        let clipped = clip_closer_to(&square, Vec2::ZERO, Vec2::new(1.0, 0.0));
+        assert!((polygon_area(&clipped) - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sibson_weights_symmetric() {
+        let radius = 10.0;
+        let query = Vec3::new(0.0, 0.0, radius);
+        let sites: Vec<Vec3> = (0..6)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::TAU / 6.0;
+                Vec3::new(angle.cos(), angle.sin(), 10.0).normalize() * radius
+            })
+            .collect();
+
+        let weights = sibson_weights(query, &sites);
+        assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        for w in weights {
+            assert!((w - 1.0 / 6.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_barycentric_weights() {
+        let a = Vec3::X;
+        let b = Vec3::Y;
+        let c = Vec3::Z;
+
+        let w = barycentric_weights(Vec3::ONE, a, b, c).unwrap();
+        for weight in w {
+            assert!((weight - 1.0 / 3.0).abs() < 1e-5);
+        }
+
+        let w = barycentric_weights(a, a, b, c).unwrap();
+        assert!((w[0] - 1.0).abs() < 1e-5);
+
+        assert!(barycentric_weights(-Vec3::ONE, a, b, c).is_none());
+    }
+}
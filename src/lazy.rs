@@ -0,0 +1,202 @@
+//! Planet with on-demand terrain sampling
+//!
+//! [`LazyTerrainPlanet`] keeps the cell geometry in memory but only samples
+//! terrain when a cell is first accessed, caching the result. This trades CPU
+//! for memory on very large custom planets and suits read-mostly access where
+//! only part of the planet is ever looked at. If every cell ends up being
+//! touched anyway, a regular [`VoronoiPlanet`](crate::VoronoiPlanet) is faster.
+
+use std::sync::OnceLock;
+
+use crate::cell::VoronoiCell;
+use crate::config::PlanetConfig;
+use crate::error::Result;
+use crate::generation::{generate_raw_cells, RawCell};
+use crate::terrain::TerrainSampler;
+
+/// A planet whose terrain is sampled lazily per cell
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// let config = PlanetConfigBuilder::new()
+///     .seed(42)
+///     .planet_size(PlanetSize::Tiny)
+///     .build()
+///     .unwrap();
+/// let sampler = PerlinTerrainSampler::new(config.terrain_seed);
+/// let planet = LazyTerrainPlanet::generate(config, sampler).unwrap();
+///
+/// // Only cell 0 is sampled
+/// println!("Cell 0 terrain: {:?}", planet.terrain(0));
+/// assert_eq!(planet.sampled_count(), 1);
+/// ```
+pub struct LazyTerrainPlanet<T, S> {
+    /// Configuration used to generate this planet
+    config: PlanetConfig,
+
+    /// Cell geometry (indexed by cell ID)
+    cells: Vec<RawCell>,
+
+    /// Terrain cache parallel to `cells`, filled on first access
+    terrain: Vec<OnceLock<T>>,
+
+    /// Sampler used to fill the cache
+    sampler: S,
+
+    /// Sphere radius passed to the sampler
+    radius: f32,
+}
+
+impl<T, S> LazyTerrainPlanet<T, S>
+where
+    S: TerrainSampler<Output = T>,
+{
+    /// Generate cell geometry now and defer terrain sampling to first access
+    pub fn generate(config: PlanetConfig, sampler: S) -> Result<Self> {
+        let raw_cells = generate_raw_cells(&config)?;
+        Ok(Self::from_raw_cells(config, raw_cells, sampler))
+    }
+
+    /// Build a lazy planet from precomputed raw cells
+    ///
+    /// Cell IDs must be `0..raw_cells.len()` in order, as with
+    /// [`VoronoiPlanet::from_raw_cells`](crate::VoronoiPlanet::from_raw_cells).
+    pub fn from_raw_cells(config: PlanetConfig, raw_cells: Vec<RawCell>, sampler: S) -> Self {
+        let terrain = raw_cells.iter().map(|_| OnceLock::new()).collect();
+
+        Self {
+            radius: config.radius(),
+            config,
+            cells: raw_cells,
+            terrain,
+            sampler,
+        }
+    }
+
+    /// Get the configuration used to generate this planet
+    #[inline]
+    pub fn config(&self) -> &PlanetConfig {
+        &self.config
+    }
+
+    /// Get the number of cells on this planet
+    #[inline]
+    pub fn cell_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Get the sphere radius
+    #[inline]
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Get the sampler used for terrain
+    #[inline]
+    pub fn sampler(&self) -> &S {
+        &self.sampler
+    }
+
+    /// Get the geometry of a cell without sampling its terrain
+    #[inline]
+    pub fn raw_cell(&self, id: usize) -> Option<&RawCell> {
+        self.cells.get(id)
+    }
+
+    /// Get neighbor IDs for a cell (empty for invalid IDs)
+    #[inline]
+    pub fn get_neighbors(&self, id: usize) -> &[usize] {
+        self.cells.get(id).map(|c| c.neighbors.as_slice()).unwrap_or(&[])
+    }
+
+    /// Get the terrain of a cell, sampling it on first access
+    pub fn terrain(&self, id: usize) -> Option<&T> {
+        let cell = self.cells.get(id)?;
+        Some(self.terrain[id].get_or_init(|| self.sampler.sample(cell.center, self.radius)))
+    }
+
+    /// Get the number of cells whose terrain has been sampled so far
+    pub fn sampled_count(&self) -> usize {
+        self.terrain.iter().filter(|t| t.get().is_some()).count()
+    }
+
+    /// Get a full cell, sampling its terrain on first access
+    ///
+    /// Returns an owned copy since cells are assembled on demand.
+    pub fn get_cell(&self, id: usize) -> Option<VoronoiCell<T>>
+    where
+        T: Clone,
+    {
+        let terrain = self.terrain(id)?.clone();
+        let raw = &self.cells[id];
+        Some(VoronoiCell::new(raw.id, raw.center, terrain, raw.neighbors.clone(), raw.vertices.clone()))
+    }
+
+    /// Find all cells with the given terrain
+    ///
+    /// Samples every cell that hasn't been sampled yet, so after this call the
+    /// whole planet is cached.
+    pub fn cells_of_terrain(&self, terrain: &T) -> Vec<usize>
+    where
+        T: PartialEq,
+    {
+        (0..self.cells.len())
+            .filter(|&id| self.terrain(id) == Some(terrain))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{PlanetConfigBuilder, PlanetSize};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Classifies by hemisphere and counts how often it's called
+    #[derive(Default)]
+    struct CountingSampler {
+        calls: AtomicUsize,
+    }
+
+    impl TerrainSampler for CountingSampler {
+        type Output = bool;
+
+        fn sample(&self, position: glam::Vec3, _radius: f32) -> bool {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            position.y > 0.0
+        }
+    }
+
+    #[test]
+    fn test_terrain_sampled_on_demand() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Custom { cell_count: 300, radius: 5.0 })
+            .build()
+            .unwrap();
+        let planet = LazyTerrainPlanet::generate(config, CountingSampler::default()).unwrap();
+        let calls = || planet.sampler().calls.load(Ordering::Relaxed);
+
+        // Geometry is available without sampling
+        assert!(planet.raw_cell(10).is_some());
+        assert!(!planet.get_neighbors(10).is_empty());
+        assert_eq!(calls(), 0);
+
+        // First access samples, later accesses hit the cache
+        let north = planet.terrain(10).copied();
+        assert_eq!(calls(), 1);
+        assert_eq!(planet.get_cell(10).unwrap().terrain, north.unwrap());
+        assert_eq!(planet.terrain(10).copied(), north);
+        assert_eq!(calls(), 1);
+        assert_eq!(planet.sampled_count(), 1);
+
+        // Terrain queries touch every cell exactly once
+        let northern = planet.cells_of_terrain(&true);
+        assert_eq!(calls(), planet.cell_count());
+        assert!(northern.iter().all(|&id| planet.raw_cell(id).unwrap().center.y > 0.0));
+        assert!(planet.terrain(planet.cell_count()).is_none());
+    }
+}
@@ -26,27 +26,57 @@
 //!
 //! - `spatial-index` (default): Enables O(log n) position-to-cell lookups using KD-tree
 //! - `serde`: Enables serialization support for configuration and cells
+//! - `logging`: Reports generation progress through the `log` crate (debug and info levels)
+//! - `incremental-delaunay`: Triangulates with [`generation::spherical_delaunay`] instead of
+//!   parry3d's convex hull, which is much faster for planets with 100k+ cells. Points get
+//!   the same neighbors, but Lloyd's relaxation sums in a different triangle order, so
+//!   generated planets are not bit-identical to the default backend
+//! - `profiling`: Records per-stage timings in [`GenerationReport`]
+//!
+//! # Concurrency
+//!
+//! Generation holds no shared or thread-local state: every buffer is owned by
+//! the call (or by a [`PlanetGenerator`]), and the KD-tree behind
+//! [`SpatialIndex`](spatial::SpatialIndex) is plain owned data. As a result
+//! [`VoronoiPlanet<T>`] is `Send + Sync` whenever `T` is, and planets can be
+//! generated on a worker thread (`std::thread::spawn`, `tokio::task::spawn_blocking`,
+//! rayon) and moved back to the caller. A custom sampler passed into such a
+//! task must itself be `Send`. Queries take `&self`, so a finished planet can
+//! be shared across threads behind an `Arc` without locking.
 
 // Modules
+mod logging;
 pub mod error;
 pub mod config;
 pub mod cell;
+pub mod geometry;
+pub mod attributes;
 pub mod generation;
 pub mod terrain;
 pub mod planet;
+pub mod lazy;
+pub mod batch;
+pub mod interpolation;
 pub mod mesh;
+pub mod stats;
 
 #[cfg(feature = "spatial-index")]
 pub mod spatial;
 
 // Re-export core types for convenience
 pub use error::{VoronoiError, Result};
-pub use config::{PlanetConfig, PlanetConfigBuilder, PlanetSize, PointDistribution};
-pub use cell::VoronoiCell;
-pub use planet::VoronoiPlanet;
-pub use terrain::{BasicTerrainType, TerrainSampler, PerlinTerrainSampler, PerlinConfig};
-pub use mesh::{MeshData, generate_mesh, generate_mesh_with_visibility, ColorMapper, BasicColorMapper, CustomColorMapper, TerrainColor};
-pub use generation::{RawCell, LloydOptions};
+pub use config::{ContinentStyle, PlanetConfig, CONFIG_VERSION, PlanetConfigBuilder, PlanetSize, PointDistribution};
+pub use cell::{LatLonBounds, VoronoiCell};
+pub use attributes::CellAttributes;
+pub use planet::{terrain_diff, NeighborRepair, Relief, VoronoiPlanet};
+pub use lazy::LazyTerrainPlanet;
+pub use batch::PlanetGenerator;
+pub use stats::{GenerationReport, RenderStats, UniformityReport};
+#[cfg(feature = "profiling")]
+pub use stats::StageTimings;
+pub use terrain::{BasicTerrainType, ContextSampler, TerrainSampler, RichTerrainSampler, TerrainData, PerlinTerrainSampler, PerlinConfig, SphericalHarmonicSampler, ImageHeightSampler};
+pub use mesh::{CellPointData, CellSpan, ColorBlend, Facing, MeshData, MeshOptions, QuantizedMesh, QuantizedPositions, Shading, Triangulation, UvMode, generate_mesh, generate_mesh_with_options, generate_mesh_with_visibility, generate_mesh_with_visibility_ramp, generate_mesh_with_displacement, generate_mesh_with_displacement_options, generate_mesh_with_skirts, generate_mesh_baked, generate_mesh_subdivided, generate_mesh_with_elevation_colors, generate_ocean_shell, generate_lod_meshes, generate_cell_point_cloud, DisplacementOptions, HeightNormalization, ColorMapper, BasicColorMapper, CustomColorMapper, ElevationColorMapper, IdColorMapper, MoistureColorMapper, NeighborCountColorMapper, AreaColorMapper, TerrainColor};
+pub use generation::{RawCell, ConvergenceMode, LloydOptions, LloydReport};
 
 #[cfg(feature = "spatial-index")]
 pub use spatial::SpatialIndex;
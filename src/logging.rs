@@ -0,0 +1,37 @@
+//! Internal logging macros
+//!
+//! With the `logging` feature these forward to the `log` crate, so progress
+//! messages go through the application's logger and can be filtered by level
+//! and target. Without it they compile to nothing; the arguments are still
+//! type-checked so both builds stay warning-free.
+
+#[cfg(feature = "logging")]
+macro_rules! log_debug {
+    ($($arg:tt)*) => { log::debug!($($arg)*) };
+}
+
+#[cfg(not(feature = "logging"))]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    };
+}
+
+#[cfg(feature = "logging")]
+macro_rules! log_info {
+    ($($arg:tt)*) => { log::info!($($arg)*) };
+}
+
+#[cfg(not(feature = "logging"))]
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use log_debug;
+pub(crate) use log_info;
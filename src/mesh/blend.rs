@@ -0,0 +1,60 @@
+//! Border color blending between neighboring cells
+
+use std::collections::HashMap;
+
+use super::TerrainColor;
+use crate::planet::VoronoiPlanet;
+
+/// How vertex colors are assigned within a cell
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorBlend {
+    /// Every vertex of a cell gets the cell's color (hard borders)
+    #[default]
+    PerCell,
+    /// Boundary vertices take the average color of all cells sharing them
+    ///
+    /// Cell centers keep their own color, so colors fade across each border
+    /// instead of jumping.
+    BorderAverage,
+}
+
+/// Key identifying a Voronoi vertex shared by several cells
+///
+/// Neighboring cells compute their shared vertices from the same Delaunay
+/// triangle, so snapping to a fine grid welds them reliably.
+fn weld_key(vertex: glam::Vec3, resolution: f32) -> (i32, i32, i32) {
+    let snapped = (vertex / resolution).round();
+    (snapped.x as i32, snapped.y as i32, snapped.z as i32)
+}
+
+/// Average colors of the cells sharing each boundary vertex
+///
+/// Returns one color per boundary vertex, parallel to each cell's `vertices`.
+pub(super) fn border_vertex_colors<T: Clone>(planet: &VoronoiPlanet<T>, cell_colors: &[TerrainColor]) -> Vec<Vec<TerrainColor>> {
+    let resolution = planet.radius() * 1e-5;
+    let mut sums: HashMap<(i32, i32, i32), ([f32; 4], f32)> = HashMap::new();
+
+    for (cell, color) in planet.cells().iter().zip(cell_colors) {
+        for &vertex in &cell.vertices {
+            let (sum, count) = sums.entry(weld_key(vertex, resolution)).or_default();
+            for (s, c) in sum.iter_mut().zip(color) {
+                *s += c;
+            }
+            *count += 1.0;
+        }
+    }
+
+    planet
+        .cells()
+        .iter()
+        .map(|cell| {
+            cell.vertices
+                .iter()
+                .map(|&vertex| {
+                    let (sum, count) = sums[&weld_key(vertex, resolution)];
+                    sum.map(|s| s / count)
+                })
+                .collect()
+        })
+        .collect()
+}
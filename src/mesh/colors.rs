@@ -1,14 +1,52 @@
 //! Color mapping for terrain types
 
-use crate::terrain::BasicTerrainType;
+use crate::cell::VoronoiCell;
+use crate::planet::VoronoiPlanet;
+use crate::terrain::{BasicTerrainType, TerrainData};
 
 /// RGBA color type
 pub type TerrainColor = [f32; 4];
 
 /// Trait for mapping terrain types to colors
+///
+/// Simple mappers only implement [`map_color`](Self::map_color). Mesh
+/// generation and [`VoronoiPlanet::bake_colors`] call
+/// [`map_color_cell`](Self::map_color_cell), so a mapper that overrides it can
+/// color by elevation, latitude, area or neighbor count instead.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// /// Tints cells from dark at the equator to white at the poles
+/// struct LatitudeMapper;
+///
+/// impl ColorMapper<BasicTerrainType> for LatitudeMapper {
+///     fn map_color(&self, _terrain: &BasicTerrainType) -> TerrainColor {
+///         [0.0, 0.0, 0.0, 1.0]
+///     }
+///
+///     fn map_color_cell(&self, cell: &VoronoiCell<BasicTerrainType>) -> TerrainColor {
+///         let t = cell.center.normalize().y.abs();
+///         [t, t, t, 1.0]
+///     }
+/// }
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let mesh = generate_mesh(&planet, &LatitudeMapper);
+/// ```
 pub trait ColorMapper<T> {
     /// Map a terrain type to an RGBA color
     fn map_color(&self, terrain: &T) -> TerrainColor;
+
+    /// Map a whole cell to an RGBA color
+    ///
+    /// Override this to color by cell geometry (ID, neighbors, area, position)
+    /// instead of terrain. Defaults to `map_color(&cell.terrain)`.
+    fn map_color_cell(&self, cell: &VoronoiCell<T>) -> TerrainColor {
+        self.map_color(&cell.terrain)
+    }
 }
 
 /// Default color mapper for BasicTerrainType
@@ -49,6 +87,73 @@ impl Default for CustomColorMapper {
     }
 }
 
+impl CustomColorMapper {
+    /// Create a mapper from colors in `BasicTerrainType` order
+    ///
+    /// The palette lists ocean, beach, land, mountain and ice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// let mapper = CustomColorMapper::from_palette(&[
+    ///     [0.0, 0.0, 0.5, 1.0],
+    ///     [1.0, 1.0, 0.0, 1.0],
+    ///     [0.0, 0.5, 0.0, 1.0],
+    ///     [0.4, 0.4, 0.4, 1.0],
+    ///     [1.0, 1.0, 1.0, 1.0],
+    /// ]);
+    /// assert_eq!(mapper.map_color(&BasicTerrainType::Beach), [1.0, 1.0, 0.0, 1.0]);
+    /// ```
+    pub fn from_palette(palette: &[TerrainColor; 5]) -> Self {
+        let [ocean, beach, land, mountain, ice] = *palette;
+        Self { ocean, beach, land, mountain, ice }
+    }
+
+    /// Palette for red-green color blindness (deuteranopia, also protanopia)
+    ///
+    /// Built from the Okabe-Ito colors: blue ocean, yellow beach, bluish green
+    /// land and vermillion mountains stay apart without relying on the
+    /// red-green axis.
+    pub fn colorblind_deuteranopia() -> Self {
+        Self::from_palette(&[
+            [0.0, 0.447, 0.698, 1.0],
+            [0.941, 0.894, 0.259, 1.0],
+            [0.0, 0.62, 0.451, 1.0],
+            [0.835, 0.369, 0.0, 1.0],
+            [1.0, 1.0, 1.0, 1.0],
+        ])
+    }
+
+    /// Palette for blue-yellow color blindness (tritanopia)
+    ///
+    /// Separates the types mostly by red-green contrast and lightness: navy
+    /// ocean, pink beach, bluish green land and dark red mountains.
+    pub fn colorblind_tritanopia() -> Self {
+        Self::from_palette(&[
+            [0.05, 0.1, 0.35, 1.0],
+            [0.9, 0.6, 0.6, 1.0],
+            [0.0, 0.62, 0.451, 1.0],
+            [0.55, 0.2, 0.25, 1.0],
+            [1.0, 1.0, 1.0, 1.0],
+        ])
+    }
+
+    /// Saturated palette with strong lightness steps between neighbors
+    ///
+    /// For low-vision players and small or low-quality displays.
+    pub fn high_contrast() -> Self {
+        Self::from_palette(&[
+            [0.0, 0.0, 0.55, 1.0],
+            [1.0, 0.9, 0.0, 1.0],
+            [0.0, 0.7, 0.0, 1.0],
+            [0.45, 0.2, 0.0, 1.0],
+            [1.0, 1.0, 1.0, 1.0],
+        ])
+    }
+}
+
 impl ColorMapper<BasicTerrainType> for CustomColorMapper {
     fn map_color(&self, terrain: &BasicTerrainType) -> TerrainColor {
         match terrain {
@@ -61,6 +166,241 @@ impl ColorMapper<BasicTerrainType> for CustomColorMapper {
     }
 }
 
+/// Terrain mapper that shades land from arid to verdant by moisture
+///
+/// `Land` cells blend from `arid` (moisture 0) to `verdant` (moisture 1);
+/// every other terrain, and land cells without a moisture entry, use the
+/// `base` colors. Moisture is looked up by cell ID in
+/// [`map_color_cell`](ColorMapper::map_color_cell), so it works with every
+/// mesh generator and [`VoronoiPlanet::bake_colors`].
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+/// let sampler = PerlinTerrainSampler::from_planet_config(&config);
+/// let planet = VoronoiPlanet::generate_with_rich_sampler(config, &sampler).unwrap();
+///
+/// let mapper = MoistureColorMapper::from_terrain_data(planet.terrain_data());
+/// let mesh = generate_mesh(&planet, &mapper);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoistureColorMapper {
+    /// Moisture per cell in [0, 1], indexed by cell ID (values are clamped)
+    pub moisture: Vec<f32>,
+    /// Land color at moisture 0
+    pub arid: TerrainColor,
+    /// Land color at moisture 1
+    pub verdant: TerrainColor,
+    /// Colors for all other terrain types
+    pub base: CustomColorMapper,
+}
+
+impl MoistureColorMapper {
+    /// Create a mapper with dry-brown to lush-green land
+    pub fn new(moisture: Vec<f32>) -> Self {
+        Self {
+            moisture,
+            arid: [0.65, 0.55, 0.3, 1.0],
+            verdant: [0.1, 0.5, 0.15, 1.0],
+            base: CustomColorMapper::default(),
+        }
+    }
+
+    /// Create a mapper from the moisture of rich-sampler terrain data
+    ///
+    /// See [`VoronoiPlanet::terrain_data`].
+    pub fn from_terrain_data(data: &[TerrainData]) -> Self {
+        Self::new(data.iter().map(|d| d.moisture).collect())
+    }
+}
+
+impl ColorMapper<BasicTerrainType> for MoistureColorMapper {
+    fn map_color(&self, terrain: &BasicTerrainType) -> TerrainColor {
+        self.base.map_color(terrain)
+    }
+
+    fn map_color_cell(&self, cell: &VoronoiCell<BasicTerrainType>) -> TerrainColor {
+        match (cell.terrain, self.moisture.get(cell.id)) {
+            (BasicTerrainType::Land, Some(&moisture)) => {
+                let t = moisture.clamp(0.0, 1.0);
+                std::array::from_fn(|i| self.arid[i] * (1.0 - t) + self.verdant[i] * t)
+            }
+            _ => self.map_color(&cell.terrain),
+        }
+    }
+}
+
+/// Hypsometric color ramp mapping elevation to interpolated colors
+///
+/// Colors are linearly interpolated between the two stops surrounding an
+/// elevation; values outside the ramp take the nearest end color. Use it with
+/// [`generate_mesh_with_elevation_colors`](super::generate_mesh_with_elevation_colors)
+/// and a per-cell elevation array, or directly as a `ColorMapper<f32>` on
+/// planets whose terrain type is the elevation itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElevationColorMapper {
+    stops: Vec<(f32, TerrainColor)>,
+}
+
+impl ElevationColorMapper {
+    /// Create a ramp from `(elevation, color)` stops (sorted automatically)
+    pub fn new(mut stops: Vec<(f32, TerrainColor)>) -> Self {
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { stops }
+    }
+
+    /// Get the ramp stops in ascending elevation order
+    pub fn stops(&self) -> &[(f32, TerrainColor)] {
+        &self.stops
+    }
+
+    /// Interpolate the ramp color at an elevation
+    ///
+    /// Returns opaque black for an empty ramp.
+    pub fn color_at(&self, elevation: f32) -> TerrainColor {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return [0.0, 0.0, 0.0, 1.0],
+        };
+        if elevation.is_nan() || elevation <= first.0 {
+            return first.1;
+        }
+        if elevation >= last.0 {
+            return last.1;
+        }
+
+        // First stop above the elevation; the one before it is below
+        let upper = self.stops.partition_point(|stop| stop.0 <= elevation);
+        let (low, high) = (self.stops[upper - 1], self.stops[upper]);
+        let t = (elevation - low.0) / (high.0 - low.0);
+        std::array::from_fn(|i| low.1[i] + (high.1[i] - low.1[i]) * t)
+    }
+}
+
+impl Default for ElevationColorMapper {
+    /// Classic atlas ramp for Perlin-range elevations (about [-1, 1])
+    fn default() -> Self {
+        Self::new(vec![
+            (-1.0, [0.02, 0.1, 0.35, 1.0]),  // Abyss
+            (-0.12, [0.2, 0.45, 0.8, 1.0]),  // Shelf
+            (-0.07, [0.85, 0.8, 0.55, 1.0]), // Coast
+            (0.1, [0.25, 0.55, 0.2, 1.0]),   // Lowland
+            (0.4, [0.55, 0.45, 0.3, 1.0]),   // Highland
+            (0.8, [0.95, 0.95, 0.95, 1.0]),  // Peaks
+        ])
+    }
+}
+
+impl ColorMapper<f32> for ElevationColorMapper {
+    fn map_color(&self, terrain: &f32) -> TerrainColor {
+        self.color_at(*terrain)
+    }
+}
+
+/// Color used by the debug mappers when only a terrain value is available
+const DEBUG_NEUTRAL: TerrainColor = [0.5, 0.5, 0.5, 1.0];
+
+/// Debug mapper giving every cell a distinct pseudo-random color by ID
+///
+/// Makes cell boundaries and ID order visible regardless of terrain.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdColorMapper;
+
+impl<T> ColorMapper<T> for IdColorMapper {
+    fn map_color(&self, _terrain: &T) -> TerrainColor {
+        DEBUG_NEUTRAL
+    }
+
+    fn map_color_cell(&self, cell: &VoronoiCell<T>) -> TerrainColor {
+        // SplitMix64 finalizer spreads consecutive IDs over the color cube
+        let mut z = (cell.id as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        // Keep channels in [0.2, 1.0] so no cell is near-black
+        let channel = |shift: u32| 0.2 + 0.8 * ((z >> shift) & 0xFF) as f32 / 255.0;
+        [channel(0), channel(8), channel(16), 1.0]
+    }
+}
+
+/// Debug mapper coloring cells by neighbor count
+///
+/// Triangular and quadrilateral cells (the slivers reported by
+/// [`VoronoiPlanet::degenerate_cells`]) show up red and orange; hexagons are
+/// green, with pentagons and heptagons on either side of it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NeighborCountColorMapper;
+
+impl NeighborCountColorMapper {
+    /// Get the color for a neighbor count
+    pub fn color_for_count(count: usize) -> TerrainColor {
+        match count {
+            0..=3 => [0.9, 0.1, 0.1, 1.0], // Red: degenerate
+            4 => [1.0, 0.55, 0.1, 1.0],    // Orange
+            5 => [0.95, 0.9, 0.2, 1.0],    // Yellow
+            6 => [0.2, 0.7, 0.3, 1.0],     // Green
+            7 => [0.2, 0.6, 0.9, 1.0],     // Blue
+            _ => [0.6, 0.3, 0.8, 1.0],     // Purple
+        }
+    }
+}
+
+impl<T> ColorMapper<T> for NeighborCountColorMapper {
+    fn map_color(&self, _terrain: &T) -> TerrainColor {
+        DEBUG_NEUTRAL
+    }
+
+    fn map_color_cell(&self, cell: &VoronoiCell<T>) -> TerrainColor {
+        Self::color_for_count(cell.neighbor_count())
+    }
+}
+
+/// Debug mapper shading cells from blue (smallest) to red (largest) by area
+///
+/// Uses exact spherical areas. Cells outside `[min_area, max_area]` clamp to
+/// the end colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AreaColorMapper {
+    /// Sphere radius the areas are measured on
+    pub radius: f32,
+    /// Area mapped to blue
+    pub min_area: f32,
+    /// Area mapped to red
+    pub max_area: f32,
+}
+
+impl AreaColorMapper {
+    /// Create a mapper spanning the smallest to largest cell of a planet
+    pub fn for_planet<T: Clone>(planet: &VoronoiPlanet<T>) -> Self {
+        let report = planet.uniformity_report();
+        Self {
+            radius: planet.radius(),
+            min_area: report.min_area,
+            max_area: report.max_area,
+        }
+    }
+}
+
+impl<T> ColorMapper<T> for AreaColorMapper {
+    fn map_color(&self, _terrain: &T) -> TerrainColor {
+        DEBUG_NEUTRAL
+    }
+
+    fn map_color_cell(&self, cell: &VoronoiCell<T>) -> TerrainColor {
+        let span = self.max_area - self.min_area;
+        let t = if span > 0.0 {
+            ((cell.spherical_area(self.radius) - self.min_area) / span).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        [t, 0.2, 1.0 - t, 1.0]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,6 +433,93 @@ mod tests {
         assert_eq!(land_color, [0.3, 0.5, 0.1, 1.0]);
     }
 
+    #[test]
+    fn test_accessible_palettes() {
+        // Approximate deuteranopia and tritanopia simulation matrices (Machado et al. 2009)
+        const DEUTERANOPIA: [[f32; 3]; 3] = [
+            [0.367, 0.861, -0.228],
+            [0.280, 0.673, 0.047],
+            [-0.012, 0.043, 0.969],
+        ];
+        const TRITANOPIA: [[f32; 3]; 3] = [
+            [1.256, -0.077, -0.179],
+            [-0.078, 0.931, 0.148],
+            [0.005, 0.691, 0.304],
+        ];
+        let terrains = [
+            BasicTerrainType::Ocean,
+            BasicTerrainType::Beach,
+            BasicTerrainType::Land,
+            BasicTerrainType::Mountain,
+            BasicTerrainType::Ice,
+        ];
+        let min_distance = |mapper: &CustomColorMapper, vision: Option<[[f32; 3]; 3]>| {
+            let seen: Vec<[f32; 3]> = terrains
+                .iter()
+                .map(|t| {
+                    let c = mapper.map_color(t);
+                    vision.map_or([c[0], c[1], c[2]], |m| {
+                        m.map(|row| row[0] * c[0] + row[1] * c[1] + row[2] * c[2])
+                    })
+                })
+                .collect();
+            let mut min = f32::INFINITY;
+            for i in 0..seen.len() {
+                for j in i + 1..seen.len() {
+                    let d: f32 = (0..3).map(|k| (seen[i][k] - seen[j][k]).powi(2)).sum();
+                    min = min.min(d.sqrt());
+                }
+            }
+            min
+        };
+
+        let presets = [
+            (CustomColorMapper::colorblind_deuteranopia(), Some(DEUTERANOPIA)),
+            (CustomColorMapper::colorblind_tritanopia(), Some(TRITANOPIA)),
+            (CustomColorMapper::high_contrast(), None),
+        ];
+        for (mapper, vision) in presets {
+            let colors: Vec<TerrainColor> = terrains.iter().map(|t| mapper.map_color(t)).collect();
+            for (i, a) in colors.iter().enumerate() {
+                assert_eq!(a[3], 1.0);
+                assert!(colors[i + 1..].iter().all(|b| b != a), "duplicate color in {:?}", mapper);
+            }
+
+            // Still distinguishable for the targeted color vision
+            assert!(min_distance(&mapper, None) > 0.25, "{:?}", mapper);
+            assert!(min_distance(&mapper, vision) > 0.25, "{:?}", mapper);
+        }
+
+        let palette = [[0.1, 0.2, 0.3, 1.0], [0.2; 4], [0.3; 4], [0.4; 4], [0.5; 4]];
+        let mapper = CustomColorMapper::from_palette(&palette);
+        for (terrain, color) in terrains.iter().zip(palette) {
+            assert_eq!(mapper.map_color(terrain), color);
+        }
+    }
+
+    #[test]
+    fn test_moisture_color_mapper() {
+        let mapper = MoistureColorMapper::new(vec![0.0, 1.0, 0.0, 1.0]);
+        let cell = |id: usize, terrain: BasicTerrainType| VoronoiCell::new(id, glam::Vec3::X, terrain, vec![], vec![]);
+
+        // Dry land is browner (more red, less green) than wet land
+        let dry = mapper.map_color_cell(&cell(0, BasicTerrainType::Land));
+        let wet = mapper.map_color_cell(&cell(1, BasicTerrainType::Land));
+        assert!(dry[0] > wet[0] && dry[1] - dry[0] < wet[1] - wet[0]);
+        assert_eq!(dry, mapper.arid);
+        assert_eq!(wet, mapper.verdant);
+
+        // Other terrain ignores moisture
+        let ocean = mapper.base.ocean;
+        assert_eq!(mapper.map_color_cell(&cell(2, BasicTerrainType::Ocean)), ocean);
+        assert_eq!(mapper.map_color_cell(&cell(3, BasicTerrainType::Ocean)), ocean);
+
+        // Missing moisture keeps the base land color; values are clamped
+        assert_eq!(mapper.map_color_cell(&cell(9, BasicTerrainType::Land)), mapper.base.land);
+        let clamped = MoistureColorMapper::new(vec![5.0]);
+        assert_eq!(clamped.map_color_cell(&cell(0, BasicTerrainType::Land)), clamped.verdant);
+    }
+
     #[test]
     fn test_all_terrain_types_have_colors() {
         let mapper = BasicColorMapper;
@@ -104,4 +531,69 @@ mod tests {
         let _ = mapper.map_color(&BasicTerrainType::Mountain);
         let _ = mapper.map_color(&BasicTerrainType::Ice);
     }
+
+    #[test]
+    fn test_elevation_color_ramp() {
+        let low = [0.0, 0.0, 1.0, 1.0];
+        let mid = [0.0, 1.0, 0.0, 1.0];
+        let high = [1.0, 1.0, 1.0, 1.0];
+        // Unsorted input is fine
+        let ramp = ElevationColorMapper::new(vec![(1.0, high), (-1.0, low), (0.0, mid)]);
+        assert_eq!(ramp.stops()[0].0, -1.0);
+
+        // Ends clamp to the end colors
+        assert_eq!(ramp.color_at(-5.0), low);
+        assert_eq!(ramp.color_at(-1.0), low);
+        assert_eq!(ramp.color_at(3.0), high);
+
+        // Interpolates between the surrounding stops
+        assert_eq!(ramp.color_at(0.0), mid);
+        assert_eq!(ramp.color_at(-0.5), [0.0, 0.5, 0.5, 1.0]);
+        assert_eq!(ramp.color_at(0.5), [0.5, 1.0, 0.5, 1.0]);
+        assert_eq!(ramp.map_color(&0.25), [0.25, 1.0, 0.25, 1.0]);
+
+        assert_eq!(ElevationColorMapper::new(vec![]).color_at(0.0), [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_debug_color_mappers() {
+        use glam::Vec3;
+
+        let cell = |id: usize, neighbors: usize| {
+            let vertices = (0..neighbors)
+                .map(|i| {
+                    let angle = i as f32 / neighbors as f32 * std::f32::consts::TAU;
+                    Vec3::new(angle.cos() * 0.1, angle.sin() * 0.1, 1.0).normalize()
+                })
+                .collect();
+            VoronoiCell::new(id, Vec3::Z, BasicTerrainType::Land, (0..neighbors).collect(), vertices)
+        };
+
+        // Different neighbor counts get different colors, degenerate cells stand out
+        let mapper = NeighborCountColorMapper;
+        let colors: Vec<TerrainColor> = (3..=8).map(|n| mapper.map_color_cell(&cell(0, n))).collect();
+        for i in 0..colors.len() {
+            for j in i + 1..colors.len() {
+                assert_ne!(colors[i], colors[j], "{} and {} neighbors", i + 3, j + 3);
+            }
+        }
+        assert_eq!(colors[0], NeighborCountColorMapper::color_for_count(3));
+
+        // IDs hash to distinct colors
+        assert_ne!(IdColorMapper.map_color_cell(&cell(1, 6)), IdColorMapper.map_color_cell(&cell(2, 6)));
+
+        // Bigger cells are redder
+        let small = cell(0, 5);
+        let large = cell(1, 8);
+        let areas = AreaColorMapper {
+            radius: 1.0,
+            min_area: small.spherical_area(1.0),
+            max_area: large.spherical_area(1.0),
+        };
+        assert_eq!(areas.map_color_cell(&small), [0.0, 0.2, 1.0, 1.0]);
+        assert_eq!(areas.map_color_cell(&large), [1.0, 0.2, 0.0, 1.0]);
+
+        // The terrain-only path stays usable
+        assert_eq!(mapper.map_color(&BasicTerrainType::Land), DEBUG_NEUTRAL);
+    }
 }
@@ -0,0 +1,109 @@
+//! Height clamping and normalization for displaced meshes
+
+/// Smallest allowed radial scale; keeps displaced vertices from reaching the
+/// planet center and inverting through it
+pub(super) const MIN_RADIAL_SCALE: f32 = 0.01;
+
+/// How raw displacement values are rescaled before `scale` is applied
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeightNormalization {
+    /// Use displacement values as-is
+    #[default]
+    None,
+    /// Linearly map the observed [min, max] of the values to [-1, 1]
+    ///
+    /// The largest offset then equals `scale` regardless of the input range.
+    /// If all values are equal they map to 0.
+    MinMax,
+}
+
+/// Safety limits for [`generate_mesh_with_displacement_options`](super::generate_mesh_with_displacement_options)
+///
+/// Heights are world-unit offsets from the sphere surface. Whatever the
+/// settings, offsets below `-0.99 * radius` are raised so geometry never
+/// passes through the planet center, and non-finite values are treated as 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DisplacementOptions {
+    /// Lowest allowed offset in world units (e.g. `-0.1 * radius`)
+    pub min_height: Option<f32>,
+    /// Highest allowed offset in world units
+    pub max_height: Option<f32>,
+    /// Rescaling applied to the raw values before `scale`
+    pub normalization: HeightNormalization,
+}
+
+impl DisplacementOptions {
+    /// Resolve per-cell radial scale factors (`(radius + offset) / radius`)
+    pub(super) fn radial_scales(
+        &self,
+        displacement: &[f32],
+        cell_count: usize,
+        scale: f32,
+        radius: f32,
+    ) -> Vec<f32> {
+        let (low, high) = displacement
+            .iter()
+            .filter(|d| d.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &d| (lo.min(d), hi.max(d)));
+
+        (0..cell_count)
+            .map(|id| {
+                let raw = displacement.get(id).copied().filter(|d| d.is_finite()).unwrap_or(0.0);
+
+                let value = match self.normalization {
+                    HeightNormalization::MinMax if high > low => (raw - low) / (high - low) * 2.0 - 1.0,
+                    HeightNormalization::MinMax => 0.0,
+                    HeightNormalization::None => raw,
+                };
+
+                let mut offset = value * scale;
+                if !offset.is_finite() {
+                    offset = 0.0;
+                }
+                if let Some(max) = self.max_height {
+                    offset = offset.min(max);
+                }
+                if let Some(min) = self.min_height {
+                    offset = offset.max(min);
+                }
+
+                ((radius + offset) / radius).max(MIN_RADIAL_SCALE)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization_and_clamping() {
+        let values = [-4.0, 0.0, 4.0, f32::NAN];
+        let radius = 10.0;
+
+        let plain = DisplacementOptions::default().radial_scales(&values, 5, 1.0, radius);
+        assert_eq!(plain, vec![0.6, 1.0, 1.4, 1.0, 1.0]);
+
+        let normalized = DisplacementOptions {
+            normalization: HeightNormalization::MinMax,
+            ..Default::default()
+        };
+        let scales = normalized.radial_scales(&values, 3, 2.0, radius);
+        assert_eq!(scales, vec![0.8, 1.0, 1.2]);
+
+        let clamped = DisplacementOptions {
+            min_height: Some(-1.0),
+            max_height: Some(0.5),
+            ..Default::default()
+        };
+        let scales = clamped.radial_scales(&values, 3, 1.0, radius);
+        assert_eq!(scales, vec![0.9, 1.0, 1.05]);
+    }
+
+    #[test]
+    fn test_never_inverts() {
+        let scales = DisplacementOptions::default().radial_scales(&[-20.0, -1e30], 2, 1.0, 10.0);
+        assert_eq!(scales, vec![MIN_RADIAL_SCALE, MIN_RADIAL_SCALE]);
+    }
+}
@@ -0,0 +1,214 @@
+//! Multi-resolution planet meshes
+
+use std::collections::HashMap;
+
+use glam::Vec3;
+use parry3d::math::Point;
+use parry3d::transformation;
+
+use super::{generate_mesh, ColorMapper, MeshData, TerrainColor};
+use crate::planet::VoronoiPlanet;
+
+/// Generate up to `levels` meshes of decreasing detail
+///
+/// - **Level 0** is the full Voronoi mesh from [`generate_mesh`], about six
+///   triangles per cell.
+/// - **Level 1** is the dual mesh: cell centers joined into their Delaunay
+///   triangulation, about two triangles per cell. Each vertex carries its
+///   cell's color, so colors blend across cell borders.
+/// - **Level 2 and up** keep a maximal independent set of the previous level's
+///   vertices (no two kept vertices were adjacent) and re-triangulate them,
+///   which typically leaves a quarter to a third of the triangles.
+///
+/// Every level is a closed surface with outward-facing triangles. Fewer than
+/// `levels` meshes are returned once a level can't be simplified further
+/// (fewer than four vertices left).
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let lods = generate_lod_meshes(&planet, &BasicColorMapper, 3);
+/// for (level, mesh) in lods.iter().enumerate() {
+///     println!("LOD {}: {} triangles", level, mesh.triangle_count());
+/// }
+/// ```
+pub fn generate_lod_meshes<T, C>(planet: &VoronoiPlanet<T>, color_mapper: &C, levels: usize) -> Vec<MeshData>
+where
+    T: Clone,
+    C: ColorMapper<T>,
+{
+    let mut meshes = Vec::with_capacity(levels);
+    if levels == 0 {
+        return meshes;
+    }
+    meshes.push(generate_mesh(planet, color_mapper));
+
+    let colors: Vec<TerrainColor> = planet.cells().iter().map(|c| color_mapper.map_color_cell(c)).collect();
+    let mut kept: Vec<usize> = (0..planet.cell_count()).collect();
+    let mut adjacency: Vec<Vec<usize>> = Vec::new();
+
+    while meshes.len() < levels {
+        if meshes.len() > 1 {
+            kept = independent_subset(&kept, &adjacency);
+        }
+        if kept.len() < 4 {
+            break;
+        }
+
+        let (mesh, cells, next_adjacency) = dual_mesh(planet, &kept, &colors);
+        let previous = meshes.last().map_or(usize::MAX, MeshData::triangle_count);
+        if mesh.triangle_count() == 0 || mesh.triangle_count() >= previous {
+            break;
+        }
+
+        meshes.push(mesh);
+        kept = cells;
+        adjacency = next_adjacency;
+    }
+
+    meshes
+}
+
+/// Greedy maximal independent set over `kept`, in order
+///
+/// `adjacency[i]` lists local indices into `kept`. Returns the kept cell IDs.
+fn independent_subset(kept: &[usize], adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let mut blocked = vec![false; kept.len()];
+    let mut subset = Vec::new();
+
+    for (i, &cell) in kept.iter().enumerate() {
+        if blocked[i] {
+            continue;
+        }
+        subset.push(cell);
+        for &n in &adjacency[i] {
+            blocked[n] = true;
+        }
+    }
+
+    subset
+}
+
+/// Triangulate the centers of `cells` via their convex hull
+///
+/// Returns the mesh, the cell ID of each mesh vertex, and the vertex adjacency
+/// (local indices) for further decimation.
+fn dual_mesh<T>(
+    planet: &VoronoiPlanet<T>,
+    cells: &[usize],
+    colors: &[TerrainColor],
+) -> (MeshData, Vec<usize>, Vec<Vec<usize>>)
+where
+    T: Clone,
+{
+    let centers: Vec<Vec3> = cells.iter().map(|&id| planet.cells()[id].center).collect();
+    let points: Vec<Point<f32>> = centers.iter().map(|c| Point::new(c.x, c.y, c.z)).collect();
+    let (vertices, triangles) = transformation::convex_hull(&points);
+
+    // The hull reorders its vertices; it copies them exactly, so match by bits
+    let by_position: HashMap<[u32; 3], usize> = centers
+        .iter()
+        .zip(cells)
+        .map(|(c, &id)| (c.to_array().map(f32::to_bits), id))
+        .collect();
+    let vertex_cells: Vec<usize> = vertices
+        .iter()
+        .map(|v| by_position[&[v.x, v.y, v.z].map(f32::to_bits)])
+        .collect();
+
+    let mut mesh = MeshData::default();
+    for (v, &id) in vertices.iter().zip(&vertex_cells) {
+        let position = Vec3::new(v.x, v.y, v.z);
+        mesh.positions.push(position.to_array());
+        mesh.normals.push(position.normalize().to_array());
+        mesh.colors.push(colors[id]);
+    }
+
+    let mut adjacency = vec![Vec::new(); vertices.len()];
+    for &[a, b, c] in &triangles {
+        let [pa, pb, pc] = [a, b, c].map(|i| Vec3::from(mesh.positions[i as usize]));
+        // Wind counter-clockwise seen from outside
+        if (pb - pa).cross(pc - pa).dot(pa + pb + pc) >= 0.0 {
+            mesh.indices.extend([a, b, c]);
+        } else {
+            mesh.indices.extend([a, c, b]);
+        }
+
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            let (from, to) = (from as usize, to as usize);
+            if !adjacency[from].contains(&to) {
+                adjacency[from].push(to);
+                adjacency[to].push(from);
+            }
+        }
+    }
+
+    (mesh, vertex_cells, adjacency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::BasicColorMapper;
+    use crate::{PlanetConfigBuilder, PlanetSize};
+
+    /// Check that a mesh is closed once vertices at equal positions are welded
+    fn assert_closed(mesh: &MeshData, level: usize) {
+        let mut welded: HashMap<[i64; 3], u32> = HashMap::new();
+        let ids: Vec<u32> = mesh
+            .positions
+            .iter()
+            .map(|p| {
+                let key = p.map(|x| (x as f64 * 1e4).round() as i64);
+                let next = welded.len() as u32;
+                *welded.entry(key).or_insert(next)
+            })
+            .collect();
+
+        // Every directed edge is matched by exactly one edge in the opposite direction
+        let mut edges: HashMap<(u32, u32), i32> = HashMap::new();
+        for tri in mesh.indices.chunks_exact(3) {
+            for i in 0..3 {
+                let (a, b) = (ids[tri[i] as usize], ids[tri[(i + 1) % 3] as usize]);
+                *edges.entry((a, b)).or_insert(0) += 1;
+            }
+        }
+        for (&(a, b), &count) in &edges {
+            assert_eq!(count, 1, "level {}: edge {}-{} used {} times", level, a, b, count);
+            assert_eq!(edges.get(&(b, a)), Some(&1), "level {}: edge {}-{} is open", level, a, b);
+        }
+    }
+
+    #[test]
+    fn test_lod_levels_decrease_and_stay_closed() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Custom { cell_count: 1000, radius: 5.0 })
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let lods = generate_lod_meshes(&planet, &BasicColorMapper, 4);
+        assert_eq!(lods.len(), 4);
+        assert_eq!(lods[0].triangle_count(), generate_mesh(&planet, &BasicColorMapper).triangle_count());
+        assert_eq!(lods[1].triangle_count(), 2 * planet.cell_count() - 4);
+
+        for pair in lods.windows(2) {
+            assert!(pair[1].triangle_count() < pair[0].triangle_count());
+        }
+        for (level, mesh) in lods.iter().enumerate() {
+            assert_eq!(mesh.positions.len(), mesh.colors.len());
+            assert_closed(mesh, level);
+        }
+
+        assert!(generate_lod_meshes(&planet, &BasicColorMapper, 0).is_empty());
+
+        // Requests beyond what can be simplified stop early
+        let many = generate_lod_meshes(&planet, &BasicColorMapper, 50);
+        assert!(many.len() < 50);
+        assert!(many.last().unwrap().triangle_count() >= 4);
+    }
+}
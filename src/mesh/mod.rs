@@ -2,10 +2,32 @@
 //!
 //! Generates engine-agnostic mesh data from VoronoiPlanet cells.
 
+mod blend;
 mod colors;
+mod displacement;
+mod lod;
+mod ocean;
+mod point_cloud;
+mod quantize;
+mod skirt;
+mod subdivide;
+mod triangulate;
+mod uv;
 
-pub use colors::{ColorMapper, BasicColorMapper, CustomColorMapper, TerrainColor};
+pub use blend::ColorBlend;
+pub use colors::{AreaColorMapper, ColorMapper, BasicColorMapper, CustomColorMapper, ElevationColorMapper, IdColorMapper, MoistureColorMapper, NeighborCountColorMapper, TerrainColor};
+pub use displacement::{DisplacementOptions, HeightNormalization};
+pub use lod::generate_lod_meshes;
+pub use ocean::generate_ocean_shell;
+pub use point_cloud::{generate_cell_point_cloud, CellPointData};
+pub use quantize::{QuantizedMesh, QuantizedPositions};
+pub use skirt::generate_mesh_with_skirts;
+pub use subdivide::generate_mesh_subdivided;
+pub use triangulate::Triangulation;
+pub use uv::{cell_tangent_uvs, UvMode};
 
+use crate::cell::VoronoiCell;
+use triangulate::cell_triangles;
 use crate::planet::VoronoiPlanet;
 use glam::Vec3;
 
@@ -19,12 +41,38 @@ use glam::Vec3;
 pub struct MeshData {
     /// Vertex positions (3D coordinates)
     pub positions: Vec<[f32; 3]>,
-    /// Vertex normals (normalized direction from sphere center)
+    /// Vertex normals (direction from sphere center, or face normal with `Shading::FlatFacet`;
+    /// negated with `Facing::Inward`)
     pub normals: Vec<[f32; 3]>,
     /// Vertex colors (RGBA)
     pub colors: Vec<[f32; 4]>,
+    /// Vertex texture coordinates (empty unless a `UvMode` other than `None` is used)
+    pub uvs: Vec<[f32; 2]>,
     /// Triangle indices
     pub indices: Vec<u32>,
+    /// Vertex range of each cell, indexed by cell ID
+    ///
+    /// Filled by the per-cell mesh generators ([`generate_mesh`] and friends)
+    /// unless border colors are blended; empty for other meshes. Used by
+    /// [`update_cells`](Self::update_cells).
+    pub cell_spans: Vec<CellSpan>,
+}
+
+/// Contiguous range of mesh vertices emitted for one cell
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CellSpan {
+    /// Index of the cell's first vertex
+    pub first_vertex: u32,
+    /// Number of vertices (0 for degenerate cells)
+    pub vertex_count: u32,
+}
+
+impl CellSpan {
+    /// Vertex index range, usable to slice `positions`, `normals` or `colors`
+    #[inline]
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.first_vertex as usize..(self.first_vertex + self.vertex_count) as usize
+    }
 }
 
 impl MeshData {
@@ -42,6 +90,104 @@ impl MeshData {
     pub fn is_empty(&self) -> bool {
         self.positions.is_empty()
     }
+
+    /// Get the axis-aligned bounding box of the vertex positions as `(min, max)`
+    ///
+    /// Returns zeros for an empty mesh.
+    pub fn bounds(&self) -> ([f32; 3], [f32; 3]) {
+        if self.positions.is_empty() {
+            return ([0.0; 3], [0.0; 3]);
+        }
+
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for p in &self.positions {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(p[axis]);
+                max[axis] = max[axis].max(p[axis]);
+            }
+        }
+        (min, max)
+    }
+
+    /// Recolor only the vertices of changed cells
+    ///
+    /// After editing a few cells' terrain (e.g. with
+    /// [`resample_region`](VoronoiPlanet::resample_region)), this rewrites the
+    /// colors of just those cells instead of rebuilding the whole mesh. Only
+    /// colors are touched: terrain edits don't move geometry, so rebuild if
+    /// the cells themselves or a displacement changed.
+    ///
+    /// Requires the [`cell_spans`](Self::cell_spans) recorded by the per-cell
+    /// mesh generators; IDs without a span (invalid IDs, or meshes built with
+    /// border blending, LOD or other layouts) are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let mut mesh = generate_mesh(&planet, &BasicColorMapper);
+    /// planet.resample_region(&[3, 4], &PerlinTerrainSampler::new(7));
+    /// mesh.update_cells(&planet, &BasicColorMapper, &[3, 4]);
+    /// ```
+    pub fn update_cells<T, C>(&mut self, planet: &VoronoiPlanet<T>, color_mapper: &C, changed: &[usize])
+    where
+        T: Clone,
+        C: ColorMapper<T>,
+    {
+        for &id in changed {
+            let (Some(span), Some(cell)) = (self.cell_spans.get(id), planet.get_cell(id)) else {
+                continue;
+            };
+            self.colors[span.range()].fill(color_mapper.map_color_cell(cell));
+        }
+    }
+}
+
+/// How vertex normals are generated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Shading {
+    /// Shared fan vertices with radial normals (smooth sphere shading)
+    #[default]
+    Smooth,
+    /// Every triangle gets its own three vertices with the geometric face normal
+    ///
+    /// Produces crisp facets for a stylized low-poly look. Each cell emits
+    /// `3 * n` vertices instead of `n + 1`.
+    FlatFacet,
+}
+
+/// Which side of the surface the mesh is meant to be seen from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Facing {
+    /// Counter-clockwise winding and normals seen from outside the planet
+    #[default]
+    Outward,
+    /// Reversed winding and negated normals, for viewing from inside
+    ///
+    /// For hollow worlds and skybox-style planets: with back-face culling the
+    /// surface is visible, and lit correctly, from a camera at the center.
+    Inward,
+}
+
+/// Options controlling mesh generation
+///
+/// Passed to [`generate_mesh_with_options`]. The default matches
+/// [`generate_mesh`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MeshOptions {
+    /// Texture coordinate generation mode
+    pub uv_mode: UvMode,
+    /// Normal generation mode
+    pub shading: Shading,
+    /// Polygon triangulation strategy
+    pub triangulation: Triangulation,
+    /// Vertex color assignment
+    pub color_blend: ColorBlend,
+    /// Side the triangles and normals face
+    pub face_orientation: Facing,
 }
 
 /// Generate mesh from planet with color mapping
@@ -56,6 +202,33 @@ where
     generate_mesh_with_visibility(planet, color_mapper, None, [0.0, 0.0, 0.0, 1.0])
 }
 
+/// Generate mesh with custom options
+///
+/// Same triangulation as [`generate_mesh`], with extra vertex attributes
+/// controlled by `options`.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let options = MeshOptions { uv_mode: UvMode::PerCellTangent, ..Default::default() };
+/// let mesh = generate_mesh_with_options(&planet, &BasicColorMapper, &options);
+/// assert_eq!(mesh.uvs.len(), mesh.vertex_count());
+/// ```
+pub fn generate_mesh_with_options<T, C>(
+    planet: &VoronoiPlanet<T>,
+    color_mapper: &C,
+    options: &MeshOptions,
+) -> MeshData
+where
+    T: Clone,
+    C: ColorMapper<T>,
+{
+    build_mesh(planet, options, |cell| color_mapper.map_color_cell(cell), |_| 1.0)
+}
+
 /// Generate mesh with fog of war support
 ///
 /// # Arguments
@@ -73,80 +246,350 @@ where
     T: Clone,
     C: ColorMapper<T>,
 {
-    let mut mesh = MeshData::default();
-
     // Convert visible_cells to HashSet for O(1) lookup
     let visible_set: Option<std::collections::HashSet<usize>> =
         visible_cells.map(|cells| cells.iter().copied().collect());
 
-    for cell in planet.cells() {
-        // Skip degenerate cells
-        if cell.vertices.len() < 3 {
-            continue;
-        }
+    build_mesh(
+        planet,
+        &MeshOptions::default(),
+        |cell| {
+            // Check visibility
+            let is_visible = visible_set
+                .as_ref()
+                .map(|set| set.contains(&cell.id))
+                .unwrap_or(true);
 
-        // Check visibility
-        let is_visible = visible_set
-            .as_ref()
-            .map(|set| set.contains(&cell.id))
-            .unwrap_or(true);
+            if is_visible {
+                color_mapper.map_color_cell(cell)
+            } else {
+                hidden_color
+            }
+        },
+        |_| 1.0,
+    )
+}
 
-        // Get color
-        let color = if is_visible {
-            color_mapper.map_color(&cell.terrain)
-        } else {
-            hidden_color
-        };
+/// Generate mesh with soft fog of war
+///
+/// Like [`generate_mesh_with_visibility`], but each cell has a visibility in
+/// `0.0..=1.0` instead of a yes/no flag. Cell colors are blended toward
+/// `hidden_color` by `1 - visibility` (all four channels, so a transparent
+/// hidden color fades cells out), giving soft fog edges and partially explored
+/// cells. Values are clamped; cells without an entry count as hidden.
+///
+/// # Arguments
+/// * `planet` - The planet to generate mesh for
+/// * `color_mapper` - Maps terrain types to colors
+/// * `visibility` - Visibility per cell, indexed by cell ID
+/// * `hidden_color` - Color of fully hidden cells
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// // Fade visibility out with hop distance from cell 0
+/// let mut visibility = vec![0.0; planet.cell_count()];
+/// for (hops, ring) in planet.rings_from(0, 4).iter().enumerate() {
+///     for &id in ring {
+///         visibility[id] = 1.0 - hops as f32 / 4.0;
+///     }
+/// }
+/// let mesh = generate_mesh_with_visibility_ramp(&planet, &BasicColorMapper, &visibility, [0.0, 0.0, 0.0, 1.0]);
+/// ```
+pub fn generate_mesh_with_visibility_ramp<T, C>(
+    planet: &VoronoiPlanet<T>,
+    color_mapper: &C,
+    visibility: &[f32],
+    hidden_color: TerrainColor,
+) -> MeshData
+where
+    T: Clone,
+    C: ColorMapper<T>,
+{
+    build_mesh(
+        planet,
+        &MeshOptions::default(),
+        |cell| {
+            let t = visibility.get(cell.id).copied().unwrap_or(0.0).clamp(0.0, 1.0);
+            let color = color_mapper.map_color_cell(cell);
+            std::array::from_fn(|i| hidden_color[i] + (color[i] - hidden_color[i]) * t)
+        },
+        |_| 1.0,
+    )
+}
+
+/// Generate mesh with per-cell radial displacement
+///
+/// Each cell is pushed outward (or inward) along the sphere normal by
+/// `displacement[cell.id] * scale` world units, turning the sphere into a
+/// terraced heightfield. Cells without a displacement entry stay on the sphere.
+/// Offsets that would push a cell through the planet center are limited; use
+/// [`generate_mesh_with_displacement_options`] for explicit clamps.
+///
+/// # Arguments
+/// * `planet` - The planet to generate mesh for
+/// * `color_mapper` - Maps terrain types to colors
+/// * `displacement` - Per-cell displacement values, indexed by cell ID
+///   (e.g. elevations from [`VoronoiPlanet::terrain_data`])
+/// * `scale` - World units of offset per unit of displacement
+pub fn generate_mesh_with_displacement<T, C>(
+    planet: &VoronoiPlanet<T>,
+    color_mapper: &C,
+    displacement: &[f32],
+    scale: f32,
+) -> MeshData
+where
+    T: Clone,
+    C: ColorMapper<T>,
+{
+    generate_mesh_with_displacement_options(
+        planet,
+        color_mapper,
+        displacement,
+        scale,
+        &DisplacementOptions::default(),
+    )
+}
+
+/// Generate displaced mesh with height clamping and normalization
+///
+/// Like [`generate_mesh_with_displacement`], but heights from unbounded
+/// sources can be rescaled and clamped into a safe band so the mesh does not
+/// self-intersect or blow up.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let heights: Vec<f32> = planet.cells().iter().map(|c| c.center.x * 100.0).collect();
+/// let options = DisplacementOptions {
+///     min_height: Some(-0.05 * planet.radius()),
+///     max_height: Some(0.1 * planet.radius()),
+///     normalization: HeightNormalization::MinMax,
+/// };
+/// let mesh = generate_mesh_with_displacement_options(&planet, &BasicColorMapper, &heights, 1.0, &options);
+/// ```
+pub fn generate_mesh_with_displacement_options<T, C>(
+    planet: &VoronoiPlanet<T>,
+    color_mapper: &C,
+    displacement: &[f32],
+    scale: f32,
+    options: &DisplacementOptions,
+) -> MeshData
+where
+    T: Clone,
+    C: ColorMapper<T>,
+{
+    let scales = options.radial_scales(displacement, planet.cell_count(), scale, planet.radius());
+
+    build_mesh(
+        planet,
+        &MeshOptions::default(),
+        |cell| color_mapper.map_color_cell(cell),
+        |cell| scales[cell.id],
+    )
+}
+
+/// Generate mesh colored by per-cell elevation through a color ramp
+///
+/// Every cell takes `ramp.color_at(elevations[cell.id])`; cells without an
+/// entry use the ramp color at 0.0. Terrain types are ignored.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+/// let sampler = PerlinTerrainSampler::new(config.terrain_seed);
+/// let planet = VoronoiPlanet::generate_with_rich_sampler(config, &sampler).unwrap();
+/// let elevations: Vec<f32> = planet.terrain_data().iter().map(|d| d.elevation).collect();
+///
+/// let mesh = generate_mesh_with_elevation_colors(&planet, &elevations, &ElevationColorMapper::default());
+/// ```
+pub fn generate_mesh_with_elevation_colors<T>(
+    planet: &VoronoiPlanet<T>,
+    elevations: &[f32],
+    ramp: &ElevationColorMapper,
+) -> MeshData
+where
+    T: Clone,
+{
+    build_mesh(
+        planet,
+        &MeshOptions::default(),
+        |cell| ramp.color_at(elevations.get(cell.id).copied().unwrap_or(0.0)),
+        |_| 1.0,
+    )
+}
+
+/// Generate mesh using colors stored by [`VoronoiPlanet::bake_colors`]
+///
+/// Skips the color mapper entirely, which is handy when the mesh is rebuilt
+/// often (LOD, fog of war) but terrain never changes. Cells without a baked
+/// color (e.g. the planet was never baked) are rendered white.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// planet.bake_colors(&BasicColorMapper);
+/// let mesh = generate_mesh_baked(&planet);
+/// ```
+pub fn generate_mesh_baked<T: Clone>(planet: &VoronoiPlanet<T>) -> MeshData {
+    build_mesh(
+        planet,
+        &MeshOptions::default(),
+        |cell| planet.baked_color(cell.id).unwrap_or([1.0, 1.0, 1.0, 1.0]),
+        |_| 1.0,
+    )
+}
+
+/// Triangulate every non-degenerate cell with per-cell color and radial scale
+fn build_mesh<T, F, R>(
+    planet: &VoronoiPlanet<T>,
+    options: &MeshOptions,
+    mut cell_color: F,
+    mut radial_scale: R,
+) -> MeshData
+where
+    T: Clone,
+    F: FnMut(&VoronoiCell<T>) -> TerrainColor,
+    R: FnMut(&VoronoiCell<T>) -> f32,
+{
+    let mut mesh = MeshData::default();
+    let colors: Vec<TerrainColor> = planet.cells().iter().map(&mut cell_color).collect();
+    let border_colors = (options.color_blend == ColorBlend::BorderAverage)
+        .then(|| blend::border_vertex_colors(planet, &colors));
 
-        // Triangulate cell
+    for (cell, color) in planet.cells().iter().zip(colors) {
+        let first_vertex = mesh.positions.len() as u32;
         triangulate_cell(
             cell.center,
             &cell.vertices,
             color,
+            border_colors.as_ref().map(|b| b[cell.id].as_slice()),
+            radial_scale(cell),
+            options,
             &mut mesh,
         );
+        let vertex_count = mesh.positions.len() as u32 - first_vertex;
+        mesh.cell_spans.push(CellSpan { first_vertex, vertex_count });
+    }
+
+    // Blended boundary colors depend on the neighbors, so a cell can't be recolored alone
+    if border_colors.is_some() {
+        mesh.cell_spans = Vec::new();
+    }
+
+    if options.face_orientation == Facing::Inward {
+        for tri in mesh.indices.chunks_exact_mut(3) {
+            tri.swap(1, 2);
+        }
+        for normal in &mut mesh.normals {
+            *normal = normal.map(|c| -c);
+        }
     }
 
     mesh
 }
 
 /// Triangulate a single cell as a triangle fan
+///
+/// Positions are multiplied by `radial_scale` (1.0 keeps them on the sphere).
+/// `boundary_colors`, if given, overrides `color` per boundary vertex.
+/// Cells with fewer than 3 vertices have no area and emit nothing, so callers
+/// don't need to filter degenerate cells first.
 fn triangulate_cell(
     center: Vec3,
     vertices: &[Vec3],
     color: TerrainColor,
+    boundary_colors: Option<&[TerrainColor]>,
+    radial_scale: f32,
+    options: &MeshOptions,
     mesh: &mut MeshData,
 ) {
+    if vertices.len() < 3 {
+        return;
+    }
+
+    if options.shading == Shading::FlatFacet {
+        triangulate_cell_faceted(center, vertices, color, boundary_colors, radial_scale, options, mesh);
+        return;
+    }
+
     let base_idx = mesh.positions.len() as u32;
 
     // Add center vertex
-    mesh.positions.push([center.x, center.y, center.z]);
+    let position = center * radial_scale;
+    mesh.positions.push([position.x, position.y, position.z]);
     let center_normal = center.normalize();
     mesh.normals.push([center_normal.x, center_normal.y, center_normal.z]);
     mesh.colors.push(color);
 
     // Add boundary vertices
-    for vertex in vertices {
-        mesh.positions.push([vertex.x, vertex.y, vertex.z]);
+    for (i, vertex) in vertices.iter().enumerate() {
+        let position = *vertex * radial_scale;
+        mesh.positions.push([position.x, position.y, position.z]);
         let normal = vertex.normalize();
         mesh.normals.push([normal.x, normal.y, normal.z]);
-        mesh.colors.push(color);
+        mesh.colors.push(boundary_colors.map_or(color, |c| c[i]));
+    }
+
+    if options.uv_mode == UvMode::PerCellTangent {
+        mesh.uvs.extend(cell_tangent_uvs(center, vertices));
+    }
+
+    for tri in cell_triangles(center, vertices, options.triangulation) {
+        mesh.indices.extend(tri.map(|i| base_idx + i));
     }
+}
+
+/// Triangulate a single cell with unshared vertices and per-face normals
+fn triangulate_cell_faceted(
+    center: Vec3,
+    vertices: &[Vec3],
+    color: TerrainColor,
+    boundary_colors: Option<&[TerrainColor]>,
+    radial_scale: f32,
+    options: &MeshOptions,
+    mesh: &mut MeshData,
+) {
+    let uvs = (options.uv_mode == UvMode::PerCellTangent).then(|| cell_tangent_uvs(center, vertices));
+    let local = |i: u32| if i == 0 { center } else { vertices[i as usize - 1] };
+    let local_color = |i: u32| match boundary_colors {
+        Some(colors) if i > 0 => colors[i as usize - 1],
+        _ => color,
+    };
 
-    // Create triangle fan indices
-    let num_vertices = vertices.len();
-    for i in 0..num_vertices {
-        let next_i = (i + 1) % num_vertices;
-        mesh.indices.push(base_idx);                          // Center
-        mesh.indices.push(base_idx + 1 + i as u32);           // Current vertex
-        mesh.indices.push(base_idx + 1 + next_i as u32);      // Next vertex
+    for tri in cell_triangles(center, vertices, options.triangulation) {
+        let corners = tri.map(|i| local(i) * radial_scale);
+        let face_normal = (corners[1] - corners[0]).cross(corners[2] - corners[0]).normalize_or_zero();
+
+        let base_idx = mesh.positions.len() as u32;
+        for (corner, i) in corners.into_iter().zip(tri) {
+            mesh.positions.push([corner.x, corner.y, corner.z]);
+            mesh.normals.push([face_normal.x, face_normal.y, face_normal.z]);
+            mesh.colors.push(local_color(i));
+        }
+        if let Some(uvs) = &uvs {
+            mesh.uvs.extend(tri.map(|i| uvs[i as usize]));
+        }
+        mesh.indices.extend([base_idx, base_idx + 1, base_idx + 2]);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{PlanetConfigBuilder, PlanetSize};
+    use crate::{BasicTerrainType, PlanetConfigBuilder, PlanetSize, TerrainSampler};
 
     #[test]
     fn test_generate_mesh() {
@@ -163,6 +606,8 @@ mod tests {
         assert!(!mesh.is_empty());
         assert!(mesh.vertex_count() > 0);
         assert!(mesh.triangle_count() > 0);
+        assert_eq!(mesh.triangle_count(), planet.estimated_triangle_count());
+        assert_eq!(mesh.vertex_count(), planet.estimated_vertex_count());
         assert_eq!(mesh.positions.len(), mesh.normals.len());
         assert_eq!(mesh.positions.len(), mesh.colors.len());
         assert_eq!(mesh.indices.len() % 3, 0);
@@ -192,6 +637,80 @@ mod tests {
         assert!(mesh.vertex_count() > 0);
     }
 
+    #[test]
+    fn test_mesh_with_visibility_ramp() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let hidden = [0.0, 0.0, 0.0, 0.0];
+
+        let first_color = |mesh: &MeshData, id: usize| mesh.colors[mesh.cell_spans[id].first_vertex as usize];
+        let full = generate_mesh(&planet, &BasicColorMapper);
+
+        let mut visibility = vec![1.0; planet.cell_count()];
+        visibility[1] = 0.0;
+        visibility[2] = 0.5;
+        visibility[3] = -2.0;
+        let mesh = generate_mesh_with_visibility_ramp(&planet, &BasicColorMapper, &visibility[..planet.cell_count() - 1], hidden);
+        assert_eq!(mesh.positions, full.positions);
+
+        assert_eq!(first_color(&mesh, 0), first_color(&full, 0));
+        assert_eq!(first_color(&mesh, 1), hidden);
+        let half = first_color(&full, 2).map(|c| c * 0.5);
+        assert!(first_color(&mesh, 2).iter().zip(half).all(|(a, b)| (a - b).abs() < 1e-6));
+        assert_eq!(first_color(&mesh, 3), hidden);
+        // Missing entries are hidden
+        assert_eq!(first_color(&mesh, planet.cell_count() - 1), hidden);
+    }
+
+    #[test]
+    fn test_inward_facing() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        for shading in [Shading::Smooth, Shading::FlatFacet] {
+            let outward = generate_mesh_with_options(&planet, &BasicColorMapper, &MeshOptions { shading, ..Default::default() });
+            let options = MeshOptions { shading, face_orientation: Facing::Inward, ..Default::default() };
+            let inward = generate_mesh_with_options(&planet, &BasicColorMapper, &options);
+
+            assert_eq!(inward.positions, outward.positions);
+            assert_eq!(inward.normals.len(), outward.normals.len());
+            for (a, b) in inward.normals.iter().zip(&outward.normals) {
+                assert_eq!(*a, b.map(|c| -c));
+            }
+
+            // Winding is reversed, so geometric normals now point at the center
+            for (tri, out) in inward.indices.chunks_exact(3).zip(outward.indices.chunks_exact(3)) {
+                assert_eq!([tri[0], tri[2], tri[1]], [out[0], out[1], out[2]]);
+                let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| Vec3::from_array(inward.positions[i as usize]));
+                assert!((b - a).cross(c - a).dot(a + b + c) <= 0.0);
+                assert!((b - a).cross(c - a).dot(Vec3::from_array(inward.normals[tri[0] as usize])) >= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mesh_bounds() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let (min, max) = generate_mesh(&planet, &BasicColorMapper).bounds();
+        let (planet_min, planet_max) = planet.aabb();
+        assert_eq!((Vec3::from(min), Vec3::from(max)), (planet_min, planet_max));
+        assert_eq!(MeshData::default().bounds(), ([0.0; 3], [0.0; 3]));
+    }
+
     #[test]
     fn test_mesh_consistency() {
         let config = PlanetConfigBuilder::new()
@@ -210,4 +729,371 @@ mod tests {
         assert_eq!(mesh1.vertex_count(), mesh2.vertex_count());
         assert_eq!(mesh1.triangle_count(), mesh2.triangle_count());
     }
+
+    #[test]
+    fn test_baked_colors_match_mapper() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let mut planet = VoronoiPlanet::generate(config).unwrap();
+        assert!(planet.baked_color(0).is_none());
+
+        planet.bake_colors(&BasicColorMapper);
+        assert_eq!(planet.baked_colors().len(), planet.cell_count());
+
+        let mapped = generate_mesh(&planet, &BasicColorMapper);
+        let baked = generate_mesh_baked(&planet);
+        assert_eq!(mapped.positions, baked.positions);
+        assert_eq!(mapped.colors, baked.colors);
+        assert_eq!(mapped.indices, baked.indices);
+    }
+
+    #[test]
+    fn test_update_cells_recolors_only_changed_cells() {
+        struct MountainSampler;
+        impl TerrainSampler for MountainSampler {
+            type Output = BasicTerrainType;
+            fn sample(&self, _position: Vec3, _radius: f32) -> BasicTerrainType {
+                BasicTerrainType::Mountain
+            }
+        }
+
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let mut planet = VoronoiPlanet::generate(config).unwrap();
+        let original = generate_mesh(&planet, &BasicColorMapper);
+        assert_eq!(original.cell_spans.len(), planet.cell_count());
+
+        let id = (0..planet.cell_count())
+            .find(|&id| planet.cells()[id].terrain != BasicTerrainType::Mountain)
+            .unwrap();
+        planet.resample_region(&[id], &MountainSampler);
+
+        let mut mesh = original.clone();
+        mesh.update_cells(&planet, &BasicColorMapper, &[id, usize::MAX]);
+
+        let span = mesh.cell_spans[id].range();
+        for (i, (updated, old)) in mesh.colors.iter().zip(&original.colors).enumerate() {
+            if span.contains(&i) {
+                assert_eq!(*updated, BasicColorMapper.map_color(&BasicTerrainType::Mountain));
+                assert_ne!(updated, old);
+            } else {
+                assert_eq!(updated.map(f32::to_bits), old.map(f32::to_bits));
+            }
+        }
+        assert_eq!(mesh.positions, original.positions);
+        assert_eq!(mesh.colors, generate_mesh(&planet, &BasicColorMapper).colors);
+
+        // Blended meshes record no spans, so updates are skipped
+        let options = MeshOptions { color_blend: ColorBlend::BorderAverage, ..Default::default() };
+        assert!(generate_mesh_with_options(&planet, &BasicColorMapper, &options).cell_spans.is_empty());
+    }
+
+    #[test]
+    fn test_mesh_per_cell_tangent_uvs() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let plain = generate_mesh(&planet, &BasicColorMapper);
+        assert!(plain.uvs.is_empty());
+
+        let options = MeshOptions { uv_mode: UvMode::PerCellTangent, ..Default::default() };
+        let mesh = generate_mesh_with_options(&planet, &BasicColorMapper, &options);
+        assert_eq!(mesh.uvs.len(), mesh.vertex_count());
+        assert_eq!(mesh.positions, plain.positions);
+
+        // First cell: center at (0.5, 0.5), vertices spread around it
+        let cell = planet.get_cell(0).unwrap();
+        let center_uv = mesh.uvs[0];
+        assert!((center_uv[0] - 0.5).abs() < 1e-6 && (center_uv[1] - 0.5).abs() < 1e-6);
+
+        let vertex_uvs = &mesh.uvs[1..=cell.vertex_count()];
+        let (mut sum_u, mut sum_v) = (0.0, 0.0);
+        for uv in vertex_uvs {
+            assert!((0.0..=1.0).contains(&uv[0]) && (0.0..=1.0).contains(&uv[1]));
+            sum_u += uv[0] - 0.5;
+            sum_v += uv[1] - 0.5;
+        }
+        // Vertices surround the center rather than sitting on one side
+        let n = vertex_uvs.len() as f32;
+        assert!((sum_u / n).abs() < 0.25 && (sum_v / n).abs() < 0.25);
+    }
+
+    #[test]
+    fn test_triangulate_degenerate_cell() {
+        let center = Vec3::new(0.0, 0.0, 1.0);
+        let vertices = [Vec3::new(0.1, 0.0, 1.0), Vec3::new(0.0, 0.1, 1.0)];
+
+        for shading in [Shading::Smooth, Shading::FlatFacet] {
+            let options = MeshOptions { shading, ..Default::default() };
+            let mut mesh = MeshData::default();
+            triangulate_cell(center, &vertices, [1.0; 4], None, 1.0, &options, &mut mesh);
+            triangulate_cell(center, &vertices[..1], [1.0; 4], None, 1.0, &options, &mut mesh);
+            triangulate_cell(center, &[], [1.0; 4], None, 1.0, &options, &mut mesh);
+
+            assert_eq!(mesh.triangle_count(), 0);
+            assert!(mesh.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_displacement_clamping() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let radius = planet.radius();
+        let max_vertex_radius = |mesh: &MeshData| {
+            mesh.positions.iter().map(|p| Vec3::from(*p).length()).fold(0.0, f32::max)
+        };
+
+        // Extreme heights are capped at max_height
+        let extreme = vec![1e6; planet.cell_count()];
+        let options = DisplacementOptions { max_height: Some(1.0), ..Default::default() };
+        let mesh = generate_mesh_with_displacement_options(&planet, &BasicColorMapper, &extreme, 1.0, &options);
+        assert!(max_vertex_radius(&mesh) <= (radius + 1.0) * 1.0001);
+
+        // Sinking by twice the radius must not flip vertices through the origin
+        let sunk = vec![-radius * 2.0; planet.cell_count()];
+        let mesh = generate_mesh_with_displacement(&planet, &BasicColorMapper, &sunk, 1.0);
+        let plain = generate_mesh(&planet, &BasicColorMapper);
+        for (displaced, original) in mesh.positions.iter().zip(&plain.positions) {
+            let (displaced, original) = (Vec3::from(*displaced), Vec3::from(*original));
+            assert!(displaced.length() > 0.0);
+            assert!(displaced.dot(original) > 0.0, "vertex inverted through center");
+        }
+    }
+
+    #[test]
+    fn test_border_color_blending() {
+        use crate::BasicTerrainType::{Land, Ocean};
+
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let options = MeshOptions { color_blend: ColorBlend::BorderAverage, ..Default::default() };
+        let mesh = generate_mesh_with_options(&planet, &BasicColorMapper, &options);
+        let ocean = BasicColorMapper.map_color(&Ocean);
+        let land = BasicColorMapper.map_color(&Land);
+
+        // Find a land cell vertex where only land and ocean cells meet, ocean included
+        let touching = |v: Vec3| -> Vec<&VoronoiCell<_>> {
+            planet.cells().iter().filter(|c| c.vertices.iter().any(|w| w.distance(v) < 1e-4)).collect()
+        };
+        let (land_cell, slot, cells_at_vertex) = planet
+            .cells()
+            .iter()
+            .filter(|c| c.terrain == Land)
+            .flat_map(|c| (0..c.vertex_count()).map(move |i| (c, i)))
+            .find_map(|(c, i)| {
+                let around = touching(c.vertices[i]);
+                let only_land_ocean = around.iter().all(|n| matches!(n.terrain, Land | Ocean));
+                let has_ocean = around.iter().any(|n| n.terrain == Ocean);
+                (only_land_ocean && has_ocean).then_some((c, i, around))
+            })
+            .expect("coastline exists");
+
+        // Smooth fan layout: each cell emits its center, then its boundary vertices
+        let base: usize = planet.cells()[..land_cell.id].iter().map(|c| c.vertex_count() + 1).sum();
+        assert_eq!(mesh.colors[base], land, "cell center keeps its own color");
+
+        let blended = mesh.colors[base + 1 + slot];
+        assert_ne!(blended, land);
+        assert_ne!(blended, ocean);
+
+        let n = cells_at_vertex.len() as f32;
+        let ocean_share = cells_at_vertex.iter().filter(|c| c.terrain == Ocean).count() as f32 / n;
+        for ch in 0..4 {
+            let expected = ocean[ch] * ocean_share + land[ch] * (1.0 - ocean_share);
+            assert!((blended[ch] - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_ocean_shell_closed_sphere() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let sea_level = planet.radius() * 0.98;
+        let shell = generate_ocean_shell(&planet, sea_level, [0.0, 0.2, 0.8, 0.5]);
+
+        assert!(shell.triangle_count() >= planet.cell_count());
+        for p in &shell.positions {
+            assert!((Vec3::from(*p).length() - sea_level).abs() < 1e-3);
+        }
+
+        // Closed surface: every edge is used by exactly two triangles, once in each direction
+        let mut edges = std::collections::HashMap::new();
+        for tri in shell.indices.chunks_exact(3) {
+            for i in 0..3 {
+                *edges.entry((tri[i], tri[(i + 1) % 3])).or_insert(0) += 1;
+            }
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| Vec3::from(shell.positions[i as usize]));
+            assert!((b - a).cross(c - a).dot(a) > 0.0, "shell triangle faces inward");
+        }
+        for (&(a, b), &count) in &edges {
+            assert_eq!(count, 1);
+            assert_eq!(edges.get(&(b, a)), Some(&1), "edge {}-{} is open", a, b);
+        }
+
+        // Euler characteristic of a sphere
+        let euler = shell.vertex_count() as i64 - (edges.len() / 2) as i64 + shell.triangle_count() as i64;
+        assert_eq!(euler, 2);
+    }
+
+    #[test]
+    fn test_mesh_with_elevation_colors() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let (low, high) = ([0.0, 0.0, 0.0, 1.0], [1.0, 1.0, 1.0, 1.0]);
+        let ramp = ElevationColorMapper::new(vec![(0.0, low), (1.0, high)]);
+
+        // Elevation rises with the cell's height above the equator
+        let elevations: Vec<f32> = planet
+            .cells()
+            .iter()
+            .map(|c| c.center.y / planet.radius())
+            .collect();
+        let mesh = generate_mesh_with_elevation_colors(&planet, &elevations, &ramp);
+
+        let mut base = 0;
+        for cell in planet.cells() {
+            let expected = ramp.color_at(elevations[cell.id]);
+            assert_eq!(mesh.colors[base], expected);
+            if elevations[cell.id] <= 0.0 {
+                assert_eq!(expected, low);
+            }
+            base += cell.vertex_count() + 1;
+        }
+    }
+
+    /// Colors by the latitude of the cell center, ignoring terrain
+    struct LatitudeMapper;
+
+    impl ColorMapper<BasicTerrainType> for LatitudeMapper {
+        fn map_color(&self, _terrain: &BasicTerrainType) -> TerrainColor {
+            [0.0, 0.0, 0.0, 1.0]
+        }
+
+        fn map_color_cell(&self, cell: &VoronoiCell<BasicTerrainType>) -> TerrainColor {
+            let t = cell.center.normalize().y * 0.5 + 0.5;
+            [t, 0.0, 1.0 - t, 1.0]
+        }
+    }
+
+    #[test]
+    fn test_cell_aware_color_mapper() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let mut planet = VoronoiPlanet::generate(config).unwrap();
+
+        let hidden = [0.0, 0.0, 0.0, 1.0];
+        let visible: Vec<usize> = (0..planet.cell_count()).step_by(2).collect();
+        let mesh = generate_mesh_with_visibility(&planet, &LatitudeMapper, Some(&visible), hidden);
+
+        let mut base = 0;
+        for cell in planet.cells() {
+            let expected = if cell.id % 2 == 0 { LatitudeMapper.map_color_cell(cell) } else { hidden };
+            assert_eq!(mesh.colors[base], expected);
+            base += cell.vertex_count() + 1;
+        }
+
+        // Northern cells are redder than southern ones
+        let by_height = |a: &&VoronoiCell<_>, b: &&VoronoiCell<_>| a.center.y.total_cmp(&b.center.y);
+        let north = planet.cells().iter().max_by(by_height).unwrap().id;
+        let south = planet.cells().iter().min_by(by_height).unwrap().id;
+        planet.bake_colors(&LatitudeMapper);
+        assert!(planet.baked_color(north).unwrap()[0] > 0.9);
+        assert!(planet.baked_color(south).unwrap()[0] < 0.1);
+    }
+
+    #[test]
+    fn test_flat_facet_normals() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let smooth = generate_mesh(&planet, &BasicColorMapper);
+        let options = MeshOptions { shading: Shading::FlatFacet, ..Default::default() };
+        let mesh = generate_mesh_with_options(&planet, &BasicColorMapper, &options);
+
+        assert_eq!(mesh.triangle_count(), smooth.triangle_count());
+        assert_eq!(mesh.vertex_count(), mesh.triangle_count() * 3);
+        assert_eq!(mesh.normals.len(), mesh.vertex_count());
+
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| Vec3::from(mesh.positions[i as usize]));
+            let face = (b - a).cross(c - a).normalize();
+
+            for &i in tri {
+                let normal = Vec3::from(mesh.normals[i as usize]);
+                assert!((normal - face).length() < 1e-4, "vertex {} normal differs from face", i);
+            }
+            // Facets point away from the planet
+            assert!(face.dot(a) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_rich_sampler_feeds_displacement_mesh() {
+        use crate::PerlinTerrainSampler;
+
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let sampler = PerlinTerrainSampler::new(config.terrain_seed);
+        let planet = VoronoiPlanet::generate_with_rich_sampler(config, &sampler).unwrap();
+
+        let elevations: Vec<f32> = planet.terrain_data().iter().map(|d| d.elevation).collect();
+        assert_eq!(elevations.len(), planet.cell_count());
+
+        let flat = generate_mesh(&planet, &BasicColorMapper);
+        let displaced = generate_mesh_with_displacement(&planet, &BasicColorMapper, &elevations, 0.5);
+        assert_eq!(flat.vertex_count(), displaced.vertex_count());
+        assert_eq!(flat.indices, displaced.indices);
+
+        // Cells with non-zero elevation must have moved off the sphere
+        let radius = planet.radius();
+        let moved = displaced
+            .positions
+            .iter()
+            .filter(|p| (Vec3::from(**p).length() - radius).abs() > 1e-3)
+            .count();
+        assert!(moved > 0);
+    }
 }
@@ -0,0 +1,51 @@
+//! Smooth sea-level shell for water rendering
+
+use super::{MeshData, TerrainColor};
+use crate::generation::icosphere::unit_icosphere;
+use crate::planet::VoronoiPlanet;
+
+/// Finest subdivision used for the shell (81,920 triangles)
+const MAX_SHELL_SUBDIVISIONS: u32 = 6;
+
+/// Generate a smooth closed sphere at sea level for water rendering
+///
+/// Realistic oceans are usually drawn as a separate surface with a water
+/// shader rather than by coloring ocean cells. Combine this shell with a
+/// displaced land mesh (see
+/// [`generate_mesh_with_displacement`](super::generate_mesh_with_displacement))
+/// where ocean cells are pushed below `sea_level_radius`, so only land pokes
+/// through the water.
+///
+/// The shell is a subdivided icosahedron with roughly as many triangles as the
+/// planet has cells, capped at 81,920. Normals point radially outward.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let water = generate_ocean_shell(&planet, planet.radius(), [0.1, 0.3, 0.7, 0.8]);
+/// assert!(water.triangle_count() >= 20);
+/// ```
+pub fn generate_ocean_shell<T>(planet: &VoronoiPlanet<T>, sea_level_radius: f32, color: TerrainColor) -> MeshData
+where
+    T: Clone,
+{
+    // Each subdivision quadruples the 20 icosahedron faces
+    let mut subdivisions = 0;
+    while subdivisions < MAX_SHELL_SUBDIVISIONS && 20 * 4usize.pow(subdivisions) < planet.cell_count() {
+        subdivisions += 1;
+    }
+
+    let (vertices, triangles) = unit_icosphere(subdivisions);
+
+    MeshData {
+        positions: vertices.iter().map(|v| (*v * sea_level_radius).to_array()).collect(),
+        normals: vertices.iter().map(|v| v.to_array()).collect(),
+        colors: vec![color; vertices.len()],
+        uvs: Vec::new(),
+        indices: triangles.iter().flatten().map(|&i| i as u32).collect(),
+        cell_spans: Vec::new(),
+    }
+}
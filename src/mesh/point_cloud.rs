@@ -0,0 +1,152 @@
+//! One-vertex-per-cell layout for GPU expansion in geometry or mesh shaders
+
+use super::ColorMapper;
+use crate::planet::VoronoiPlanet;
+
+/// Compact per-cell data, one entry per cell in cell ID order
+///
+/// Boundary offsets are stored with a fixed stride of `max_sides`: the
+/// offsets of cell `i` are `offsets[i * max_sides..(i + 1) * max_sides]`, of
+/// which the first `side_counts[i]` are used and the rest are zero. A shader
+/// fans triangles from `center` to `center + offset[k]` for consecutive `k`,
+/// wrapping from the last used offset back to the first.
+#[derive(Debug, Clone, Default)]
+pub struct CellPointData {
+    /// Cell centers
+    pub centers: Vec<[f32; 3]>,
+    /// Unit normals (direction from the sphere center)
+    pub normals: Vec<[f32; 3]>,
+    /// Cell colors (RGBA)
+    pub colors: Vec<[f32; 4]>,
+    /// Number of used offsets per cell (at most `max_sides`)
+    pub side_counts: Vec<u32>,
+    /// Boundary vertex positions relative to the center, `max_sides` per cell,
+    /// counter-clockwise seen from outside, zero-padded
+    pub offsets: Vec<[f32; 3]>,
+    /// Offset slots reserved per cell
+    pub max_sides: usize,
+}
+
+impl CellPointData {
+    /// Number of cells
+    #[inline]
+    pub fn cell_count(&self) -> usize {
+        self.centers.len()
+    }
+
+    /// Used boundary offsets of one cell (empty for an invalid index)
+    pub fn cell_offsets(&self, index: usize) -> &[[f32; 3]] {
+        let count = self.side_counts.get(index).copied().unwrap_or(0) as usize;
+        let start = index * self.max_sides;
+        self.offsets.get(start..start + count).unwrap_or(&[])
+    }
+}
+
+/// Generate one point per cell with padded boundary offsets
+///
+/// Instead of triangles, emits each cell's center, normal and color plus up
+/// to `max_sides` boundary vertex offsets, so a geometry or mesh shader can
+/// expand the cells on the GPU from a buffer of `cell_count` points. Pick
+/// `max_sides` to cover the largest cell ([`VoronoiPlanet::max_degree`] is the
+/// usual vertex count bound, typically 7-9 for relaxed planets).
+///
+/// Cells with more than `max_sides` boundary vertices are clamped: `max_sides`
+/// of their vertices, spread evenly around the polygon, are kept, so the cell
+/// renders as a coarser polygon with small gaps to its neighbors. Cells with
+/// fewer vertices are zero-padded, and `side_counts` records the number used.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let points = generate_cell_point_cloud(&planet, &BasicColorMapper, 8);
+/// assert_eq!(points.cell_count(), planet.cell_count());
+/// assert_eq!(points.offsets.len(), 8 * planet.cell_count());
+/// ```
+pub fn generate_cell_point_cloud<T, C>(planet: &VoronoiPlanet<T>, color_mapper: &C, max_sides: usize) -> CellPointData
+where
+    T: Clone,
+    C: ColorMapper<T>,
+{
+    let cell_count = planet.cell_count();
+    let mut data = CellPointData {
+        centers: Vec::with_capacity(cell_count),
+        normals: Vec::with_capacity(cell_count),
+        colors: Vec::with_capacity(cell_count),
+        side_counts: Vec::with_capacity(cell_count),
+        offsets: vec![[0.0; 3]; cell_count * max_sides],
+        max_sides,
+    };
+
+    for (index, cell) in planet.cells().iter().enumerate() {
+        let n = cell.vertices.len();
+        let used = n.min(max_sides);
+        let slots = &mut data.offsets[index * max_sides..(index + 1) * max_sides];
+        for (k, slot) in slots.iter_mut().take(used).enumerate() {
+            // Evenly spread picks when clamping, identity otherwise
+            let vertex = cell.vertices[k * n / used];
+            *slot = (vertex - cell.center).to_array();
+        }
+
+        data.centers.push(cell.center.to_array());
+        data.normals.push(cell.center.normalize_or_zero().to_array());
+        data.colors.push(color_mapper.map_color_cell(cell));
+        data.side_counts.push(used as u32);
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::BasicColorMapper;
+    use glam::Vec3;
+    use crate::{PlanetConfigBuilder, PlanetSize};
+
+    #[test]
+    fn test_generate_cell_point_cloud() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let max_sides = 6;
+        let data = generate_cell_point_cloud(&planet, &BasicColorMapper, max_sides);
+
+        assert_eq!(data.cell_count(), planet.cell_count());
+        assert_eq!(data.colors.len(), planet.cell_count());
+        assert_eq!(data.offsets.len(), max_sides * planet.cell_count());
+
+        let mut clamped = 0;
+        let mut padded = 0;
+        for cell in planet.cells() {
+            let n = cell.vertices.len();
+            let used = data.side_counts[cell.id] as usize;
+            assert_eq!(used, n.min(max_sides));
+            assert_eq!(Vec3::from_array(data.centers[cell.id]), cell.center);
+
+            // Used offsets point at boundary vertices, the rest are zero
+            let slots = &data.offsets[cell.id * max_sides..(cell.id + 1) * max_sides];
+            for offset in &slots[..used] {
+                let vertex = cell.center + Vec3::from_array(*offset);
+                assert!(cell.vertices.iter().any(|v| v.distance(vertex) < 1e-4));
+            }
+            assert!(slots[used..].iter().all(|o| *o == [0.0; 3]));
+            assert_eq!(data.cell_offsets(cell.id), &slots[..used]);
+
+            if n > max_sides {
+                clamped += 1;
+            } else if n < max_sides {
+                padded += 1;
+            } else {
+                assert!((Vec3::from_array(slots[0]) + cell.center).distance(cell.vertices[0]) < 1e-4);
+            }
+        }
+        assert!(clamped > 0 && padded > 0);
+        assert!(data.cell_offsets(planet.cell_count()).is_empty());
+    }
+}
@@ -0,0 +1,233 @@
+//! Position quantization for compact mesh storage and transfer
+
+use super::MeshData;
+
+/// Quantized position components, stored in the narrowest fitting integer
+///
+/// Depths of 16 bits or fewer use `u16`, i.e. 6 bytes per vertex, half the
+/// 12 bytes of the `f32` positions. Deeper grids need `u32` and save no space
+/// over `f32`; they only pin positions to a fixed grid.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantizedPositions {
+    /// Components of meshes quantized to at most 16 bits
+    U16(Vec<[u16; 3]>),
+    /// Components of meshes quantized to 17 bits or more
+    U32(Vec<[u32; 3]>),
+}
+
+impl Default for QuantizedPositions {
+    fn default() -> Self {
+        QuantizedPositions::U16(Vec::new())
+    }
+}
+
+impl QuantizedPositions {
+    /// Number of positions
+    pub fn len(&self) -> usize {
+        match self {
+            QuantizedPositions::U16(p) => p.len(),
+            QuantizedPositions::U32(p) => p.len(),
+        }
+    }
+
+    /// Check if there are no positions
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Grid coordinates of one position, widened to `u32`
+    pub fn get(&self, index: usize) -> Option<[u32; 3]> {
+        match self {
+            QuantizedPositions::U16(p) => p.get(index).map(|q| q.map(u32::from)),
+            QuantizedPositions::U32(p) => p.get(index).copied(),
+        }
+    }
+
+    /// Iterate over grid coordinates, widened to `u32`
+    pub fn iter(&self) -> impl Iterator<Item = [u32; 3]> + '_ {
+        (0..self.len()).filter_map(|i| self.get(i))
+    }
+
+    /// Size of the stored components in bytes
+    pub fn byte_size(&self) -> usize {
+        match self {
+            QuantizedPositions::U16(p) => std::mem::size_of_val(p.as_slice()),
+            QuantizedPositions::U32(p) => std::mem::size_of_val(p.as_slice()),
+        }
+    }
+}
+
+/// Mesh with positions quantized to an integer grid
+///
+/// Created by [`MeshData::quantize`]. Each position component is stored as an
+/// integer in `0..=2^bits - 1` spanning the mesh's axis-aligned bounding box,
+/// as `u16` up to 16 bits and `u32` beyond (see [`QuantizedPositions`]).
+/// Normals, colors, UVs and indices are kept unchanged.
+///
+/// # Error Bound
+///
+/// Rounding to the nearest grid step bounds the reconstruction error per axis to
+/// half a step: `extent / (2^bits - 1) / 2`, where `extent` is the bounding box
+/// size on that axis. For a planet of radius 25.8 (extent 51.6), 16 bits gives
+/// at most ~0.0004 units of error per axis.
+#[derive(Debug, Clone, Default)]
+pub struct QuantizedMesh {
+    /// Quantized vertex positions (integer grid coordinates)
+    pub positions: QuantizedPositions,
+    /// Bit depth of the quantized components
+    pub bits: u8,
+    /// World units per grid step on each axis (dequantization scale)
+    pub scale: [f32; 3],
+    /// Bounding box minimum (dequantization offset)
+    pub offset: [f32; 3],
+    /// Vertex normals (unchanged)
+    pub normals: Vec<[f32; 3]>,
+    /// Vertex colors (unchanged)
+    pub colors: Vec<[f32; 4]>,
+    /// Vertex texture coordinates (unchanged)
+    pub uvs: Vec<[f32; 2]>,
+    /// Triangle indices (unchanged)
+    pub indices: Vec<u32>,
+}
+
+impl QuantizedMesh {
+    /// Maximum reconstruction error per axis (half a grid step)
+    pub fn max_error(&self) -> [f32; 3] {
+        self.scale.map(|s| s * 0.5)
+    }
+
+    /// Reconstruct approximate mesh data from the quantized positions
+    pub fn dequantize(&self) -> MeshData {
+        let positions = self
+            .positions
+            .iter()
+            .map(|q| {
+                [
+                    self.offset[0] + q[0] as f32 * self.scale[0],
+                    self.offset[1] + q[1] as f32 * self.scale[1],
+                    self.offset[2] + q[2] as f32 * self.scale[2],
+                ]
+            })
+            .collect();
+
+        MeshData {
+            positions,
+            normals: self.normals.clone(),
+            colors: self.colors.clone(),
+            uvs: self.uvs.clone(),
+            indices: self.indices.clone(),
+            cell_spans: Vec::new(),
+        }
+    }
+}
+
+impl MeshData {
+    /// Quantize positions to `bits` of precision within the mesh bounding box
+    ///
+    /// `bits` is clamped to `1..=24` (f32 cannot represent finer steps anyway).
+    /// See [`QuantizedMesh`] for the error bound.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let mesh = generate_mesh(&planet, &BasicColorMapper);
+    /// let quantized = mesh.quantize(16);
+    /// let restored = quantized.dequantize();
+    /// assert_eq!(restored.vertex_count(), mesh.vertex_count());
+    /// ```
+    pub fn quantize(&self, bits: u8) -> QuantizedMesh {
+        let bits = bits.clamp(1, 24);
+        let steps = ((1u32 << bits) - 1) as f32;
+
+        let (min, max) = self.bounds();
+
+        let scale: [f32; 3] = std::array::from_fn(|axis| (max[axis] - min[axis]) / steps);
+
+        let grid = |p: &[f32; 3]| -> [u32; 3] {
+            std::array::from_fn(|axis| {
+                if scale[axis] > 0.0 {
+                    ((p[axis] - min[axis]) / scale[axis]).round().clamp(0.0, steps) as u32
+                } else {
+                    0
+                }
+            })
+        };
+        // Components never exceed 2^bits - 1, so the narrowing is lossless
+        let positions = if bits <= 16 {
+            QuantizedPositions::U16(self.positions.iter().map(|p| grid(p).map(|c| c as u16)).collect())
+        } else {
+            QuantizedPositions::U32(self.positions.iter().map(grid).collect())
+        };
+
+        QuantizedMesh {
+            positions,
+            bits,
+            scale,
+            offset: min,
+            normals: self.normals.clone(),
+            colors: self.colors.clone(),
+            uvs: self.uvs.clone(),
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantize_round_trip_16_bits() {
+        let mesh = MeshData {
+            positions: (0..1000)
+                .map(|i| {
+                    let t = i as f32 * 0.1;
+                    [t.cos() * 25.8, (t * 0.3).sin() * 25.8, t.sin() * 12.0]
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let quantized = mesh.quantize(16);
+        let restored = quantized.dequantize();
+        let bound = quantized.max_error();
+
+        assert_eq!(restored.positions.len(), mesh.positions.len());
+        for (original, restored) in mesh.positions.iter().zip(&restored.positions) {
+            for axis in 0..3 {
+                // Allow for f32 rounding on top of the quantization bound
+                let error = (original[axis] - restored[axis]).abs();
+                assert!(error <= bound[axis] * 1.01 + 1e-6, "axis {} error {}", axis, error);
+            }
+        }
+        assert!(bound.iter().all(|&b| b < 0.001));
+
+        // 16-bit components take half the space of the f32 positions
+        assert!(matches!(quantized.positions, QuantizedPositions::U16(_)));
+        assert_eq!(quantized.positions.byte_size(), std::mem::size_of_val(mesh.positions.as_slice()) / 2);
+    }
+
+    #[test]
+    fn test_quantize_wide_bits_use_u32() {
+        let mesh = MeshData {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [0.5, 1.0, 1.5]],
+            ..Default::default()
+        };
+
+        let quantized = mesh.quantize(20);
+        assert!(matches!(quantized.positions, QuantizedPositions::U32(_)));
+        assert_eq!(quantized.positions.get(1), Some([(1 << 20) - 1; 3]));
+        let restored = quantized.dequantize();
+        assert!((restored.positions[2][1] - 1.0).abs() <= quantized.max_error()[1] * 1.01);
+    }
+
+    #[test]
+    fn test_quantize_clamps_bits_and_handles_empty() {
+        let empty = MeshData::default().quantize(0);
+        assert_eq!(empty.bits, 1);
+        assert!(empty.dequantize().is_empty());
+    }
+}
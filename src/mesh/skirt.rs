@@ -0,0 +1,152 @@
+//! Displaced cell meshes with skirts hiding the cracks between cells
+
+use glam::Vec3;
+
+use super::displacement::MIN_RADIAL_SCALE;
+use super::{triangulate_cell, CellSpan, ColorMapper, MeshData, MeshOptions};
+use crate::cell::VoronoiCell;
+use crate::planet::VoronoiPlanet;
+
+/// Generate a displaced mesh with vertical skirts around every cell
+///
+/// Each cell is pushed along the sphere normal by `height_fn(cell)` world
+/// units, like [`generate_mesh_with_displacement`](super::generate_mesh_with_displacement),
+/// so neighbors at different heights leave gaps at cliffs. Below each
+/// boundary edge a wall quad reaches down by `skirt_depth`, covering the gap
+/// to any lower neighbor. Skirts face away from their cell, share the cell's
+/// color and use the wall normal for flat side lighting.
+///
+/// Per cell this adds 4 vertices and 2 triangles for every boundary edge on
+/// top of the fan; `skirt_depth <= 0` emits no skirts. Pick a depth at least
+/// as large as the biggest height step between neighbors. Offsets that would
+/// push a cell through the planet center are limited, and skirts stop at the
+/// center. `cell_spans` cover both the fan and the skirt of each cell.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let step = planet.radius() * 0.02;
+/// let height = |cell: &VoronoiCell<BasicTerrainType>| if cell.terrain.is_land() { step } else { 0.0 };
+/// let mesh = generate_mesh_with_skirts(&planet, &BasicColorMapper, height, step);
+/// ```
+pub fn generate_mesh_with_skirts<T, C, F>(
+    planet: &VoronoiPlanet<T>,
+    color_mapper: &C,
+    height_fn: F,
+    skirt_depth: f32,
+) -> MeshData
+where
+    T: Clone,
+    C: ColorMapper<T>,
+    F: Fn(&VoronoiCell<T>) -> f32,
+{
+    let mut mesh = MeshData::default();
+    let options = MeshOptions::default();
+    let radius = planet.radius();
+
+    for cell in planet.cells() {
+        let first_vertex = mesh.positions.len() as u32;
+        let color = color_mapper.map_color_cell(cell);
+        let height = height_fn(cell);
+        let height = if height.is_finite() { height } else { 0.0 };
+        let scale = ((radius + height) / radius).max(MIN_RADIAL_SCALE);
+
+        triangulate_cell(cell.center, &cell.vertices, color, None, scale, &options, &mut mesh);
+        if skirt_depth > 0.0 && cell.vertices.len() >= 3 {
+            add_skirt(cell, scale, skirt_depth, color, &mut mesh);
+        }
+
+        let vertex_count = mesh.positions.len() as u32 - first_vertex;
+        mesh.cell_spans.push(CellSpan { first_vertex, vertex_count });
+    }
+
+    mesh
+}
+
+/// Emit one outward-facing wall quad below each boundary edge of a cell
+///
+/// Vertices per edge are top start, top end, bottom end, bottom start.
+fn add_skirt<T>(cell: &VoronoiCell<T>, scale: f32, depth: f32, color: [f32; 4], mesh: &mut MeshData) {
+    let n = cell.vertices.len();
+    let lower = |top: Vec3| top.normalize_or_zero() * (top.length() - depth).max(0.0);
+
+    for i in 0..n {
+        let top_start = cell.vertices[i] * scale;
+        let top_end = cell.vertices[(i + 1) % n] * scale;
+        let corners = [top_start, top_end, lower(top_end), lower(top_start)];
+
+        // Edges run counter-clockwise seen from outside, so edge x up points away from the cell
+        let up = (top_start + top_end).normalize_or_zero();
+        let normal = (top_end - top_start).cross(up).normalize_or_zero();
+
+        let base = mesh.positions.len() as u32;
+        for corner in corners {
+            mesh.positions.push(corner.to_array());
+            mesh.normals.push(normal.to_array());
+            mesh.colors.push(color);
+        }
+        mesh.indices.extend([base, base + 3, base + 1, base + 1, base + 3, base + 2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::BasicColorMapper;
+    use crate::{BasicTerrainType, PlanetConfigBuilder, PlanetSize};
+
+    #[test]
+    fn test_generate_mesh_with_skirts() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let r = planet.radius();
+        let depth = 0.1 * r;
+        let height = |cell: &VoronoiCell<BasicTerrainType>| if cell.id % 3 == 0 { 0.05 * r } else { 0.0 };
+
+        let plain = generate_mesh_with_skirts(&planet, &BasicColorMapper, height, 0.0);
+        assert_eq!(plain.triangle_count(), planet.estimated_triangle_count());
+
+        let mesh = generate_mesh_with_skirts(&planet, &BasicColorMapper, height, depth);
+        assert_eq!(mesh.triangle_count(), 3 * plain.triangle_count());
+        assert_eq!(mesh.cell_spans.len(), planet.cell_count());
+
+        for cell in planet.cells() {
+            let n = cell.vertices.len();
+            let span = mesh.cell_spans[cell.id];
+            assert_eq!(span.vertex_count as usize, 1 + n + 4 * n);
+            let position = |i: usize| Vec3::from_array(mesh.positions[span.first_vertex as usize + i]);
+            let top = cell.vertices[0].length() + height(cell);
+
+            // Fan at the displaced height, skirt bottoms `depth` below the boundary
+            assert!((position(1).length() - top).abs() < 1e-4 * r);
+            for edge in 0..n {
+                let quad = 1 + n + 4 * edge;
+                for (upper, bottom) in [(quad, quad + 3), (quad + 1, quad + 2)] {
+                    assert!((position(upper).length() - top).abs() < 1e-4 * r);
+                    assert!((position(upper).length() - position(bottom).length() - depth).abs() < 1e-4 * r);
+                }
+
+                // Walls face away from their cell
+                let normal = Vec3::from_array(mesh.normals[span.first_vertex as usize + quad]);
+                let outward = (position(quad) + position(quad + 1)) * 0.5 - position(0);
+                assert!(normal.dot(outward) > 0.0);
+            }
+        }
+
+        // Triangle winding agrees with the wall normals
+        for tri in mesh.indices.chunks_exact(3).skip(plain.triangle_count()).take(200) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| Vec3::from_array(mesh.positions[i as usize]));
+            let normal = Vec3::from_array(mesh.normals[tri[0] as usize]);
+            if normal.dot(a) < 0.5 * a.length() {
+                assert!((b - a).cross(c - a).dot(normal) > 0.0);
+            }
+        }
+    }
+}
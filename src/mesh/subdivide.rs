@@ -0,0 +1,132 @@
+//! Cell meshes with subdivided, sphere-hugging boundary edges
+
+use glam::Vec3;
+
+use super::{CellSpan, ColorMapper, MeshData};
+use crate::planet::VoronoiPlanet;
+
+/// Generate mesh with every cell edge split into `subdivisions + 1` arcs
+///
+/// Inserts `subdivisions` evenly spaced points along each boundary edge,
+/// projected back onto the sphere, and fans the cell from its center to the
+/// refined boundary. Cell silhouettes follow the curvature instead of cutting
+/// straight chords, which shows on large cells and close-up views. Every cell
+/// gets the same detail; `subdivisions = 0` produces the same triangles as
+/// [`generate_mesh`](super::generate_mesh).
+///
+/// Shared edge points are always interpolated from the same endpoint, whichever
+/// cell walks the edge, so neighbors produce bit-identical points and the mesh
+/// stays closed. Cells with fewer than 3 vertices emit nothing. Each cell
+/// uses `1 + n * (subdivisions + 1)` vertices and `n * (subdivisions + 1)`
+/// triangles for `n` boundary vertices, and `cell_spans` are recorded.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+///
+/// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let mesh = generate_mesh_subdivided(&planet, &BasicColorMapper, 2);
+/// assert_eq!(mesh.triangle_count(), 3 * planet.estimated_triangle_count());
+/// ```
+pub fn generate_mesh_subdivided<T, C>(planet: &VoronoiPlanet<T>, color_mapper: &C, subdivisions: usize) -> MeshData
+where
+    T: Clone,
+    C: ColorMapper<T>,
+{
+    let mut mesh = MeshData::default();
+    let segments = subdivisions + 1;
+
+    for cell in planet.cells() {
+        let first_vertex = mesh.positions.len() as u32;
+        let n = cell.vertices.len();
+        if n >= 3 {
+            let color = color_mapper.map_color_cell(cell);
+            let mut push = |position: Vec3| {
+                mesh.positions.push(position.to_array());
+                mesh.normals.push(position.normalize().to_array());
+                mesh.colors.push(color);
+            };
+
+            push(cell.center);
+            for i in 0..n {
+                let (a, b) = (cell.vertices[i], cell.vertices[(i + 1) % n]);
+                push(a);
+                for step in 1..segments {
+                    push(arc_point(a, b, step, segments));
+                }
+            }
+
+            let ring = (n * segments) as u32;
+            for i in 0..ring {
+                mesh.indices.extend([first_vertex, first_vertex + 1 + i, first_vertex + 1 + (i + 1) % ring]);
+            }
+        }
+        let vertex_count = mesh.positions.len() as u32 - first_vertex;
+        mesh.cell_spans.push(CellSpan { first_vertex, vertex_count });
+    }
+
+    mesh
+}
+
+/// Point `step / segments` of the way from `a` to `b`, pushed out to their interpolated radius
+///
+/// The neighbor on the other side walks the edge from `b` to `a`, so the
+/// endpoints are put in a fixed order first to get the same rounding.
+fn arc_point(a: Vec3, b: Vec3, step: usize, segments: usize) -> Vec3 {
+    let reversed = a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)).then(a.z.total_cmp(&b.z)).is_gt();
+    let (a, b, step) = if reversed { (b, a, segments - step) } else { (a, b, step) };
+
+    let t = step as f32 / segments as f32;
+    let radius = a.length() + (b.length() - a.length()) * t;
+    a.lerp(b, t).normalize() * radius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::{generate_mesh, BasicColorMapper};
+    use crate::{PlanetConfigBuilder, PlanetSize};
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_generate_mesh_subdivided() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let base = generate_mesh(&planet, &BasicColorMapper);
+
+        let flat = generate_mesh_subdivided(&planet, &BasicColorMapper, 0);
+        assert_eq!(flat.triangle_count(), base.triangle_count());
+        assert_eq!(flat.positions, base.positions);
+        assert_eq!(flat.indices, base.indices);
+
+        let mesh = generate_mesh_subdivided(&planet, &BasicColorMapper, 1);
+        assert_eq!(mesh.triangle_count(), 2 * base.triangle_count());
+        assert_eq!(mesh.cell_spans.len(), planet.cell_count());
+        for (id, cell) in planet.cells().iter().enumerate() {
+            assert_eq!(mesh.cell_spans[id].vertex_count as usize, 1 + 2 * cell.vertices.len());
+        }
+
+        // Every vertex sits on the sphere
+        let r = planet.radius();
+        for p in &mesh.positions {
+            assert!((Vec3::from_array(*p).length() - r).abs() < 1e-4 * r);
+        }
+        assert_eq!(mesh.normals.len(), mesh.positions.len());
+        assert_eq!(mesh.colors.len(), mesh.positions.len());
+        assert!(mesh.indices.iter().all(|&i| (i as usize) < mesh.positions.len()));
+
+        // Both cells along an edge emit bit-identical edge points
+        let mesh = generate_mesh_subdivided(&planet, &BasicColorMapper, 4);
+        let centers: HashSet<[u32; 3]> = planet.cells().iter().map(|c| c.center.to_array().map(f32::to_bits)).collect();
+        let mut counts: HashMap<[u32; 3], usize> = HashMap::new();
+        for p in &mesh.positions {
+            *counts.entry(p.map(f32::to_bits)).or_insert(0) += 1;
+        }
+        assert!(counts.iter().all(|(p, &count)| centers.contains(p) || count >= 2));
+    }
+}
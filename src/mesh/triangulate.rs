@@ -0,0 +1,170 @@
+//! Polygon triangulation strategies for cell meshes
+
+use glam::Vec3;
+
+use super::uv::tangent_project;
+
+/// How each cell polygon is split into triangles
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Triangulation {
+    /// Triangle fan from the cell center to each boundary edge
+    ///
+    /// Fast, and correct for genuine Voronoi cells, which are always
+    /// star-shaped around their center.
+    #[default]
+    Fan,
+    /// Ear clipping over the boundary vertices only
+    ///
+    /// Handles non-star-shaped polygons (e.g. after merging cells) without
+    /// overlapping triangles. Emits `n - 2` triangles per cell instead of `n`.
+    /// The center vertex is still emitted, unreferenced, so per-cell vertex
+    /// layout matches the fan.
+    EarClip,
+}
+
+/// Triangles for one cell as local indices (0 = center, `1 + i` = vertex `i`)
+///
+/// All triangles are counter-clockwise seen from outside the sphere.
+pub(super) fn cell_triangles(center: Vec3, vertices: &[Vec3], mode: Triangulation) -> Vec<[u32; 3]> {
+    let n = vertices.len() as u32;
+    match mode {
+        Triangulation::Fan => (0..n).map(|i| [0, 1 + i, 1 + (i + 1) % n]).collect(),
+        Triangulation::EarClip => ear_clip(&tangent_project(center, vertices))
+            .into_iter()
+            .map(|tri| tri.map(|i| 1 + i as u32))
+            .collect(),
+    }
+}
+
+/// Twice the signed area of triangle (a, b, c); positive when counter-clockwise
+fn cross(a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> f32 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Ear-clip a simple polygon, returning triangles as polygon indices
+///
+/// Output triangles keep the polygon's winding. O(n²) in the worst case,
+/// which is fine for cell-sized polygons.
+fn ear_clip(polygon: &[(f32, f32)]) -> Vec<[usize; 3]> {
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+
+    // Work in counter-clockwise order, flipping the output back if needed
+    let signed_area: f32 = (0..polygon.len())
+        .map(|i| {
+            let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum();
+    let clockwise = signed_area < 0.0;
+    if clockwise {
+        remaining.reverse();
+    }
+
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let ear = (0..count).find(|&i| {
+            let (prev, cur, next) = (remaining[(i + count - 1) % count], remaining[i], remaining[(i + 1) % count]);
+            let (a, b, c) = (polygon[prev], polygon[cur], polygon[next]);
+            if cross(a, b, c) <= 0.0 {
+                return false;
+            }
+
+            // No other vertex may sit inside (or on) the candidate ear
+            remaining.iter().all(|&j| {
+                j == prev
+                    || j == cur
+                    || j == next
+                    || cross(a, b, polygon[j]) < 0.0
+                    || cross(b, c, polygon[j]) < 0.0
+                    || cross(c, a, polygon[j]) < 0.0
+            })
+        });
+
+        // Degenerate input (collinear or self-intersecting): clip anyway to terminate
+        let i = ear.unwrap_or(0);
+        let (prev, cur, next) = (remaining[(i + count - 1) % count], remaining[i], remaining[(i + 1) % count]);
+        triangles.push([prev, cur, next]);
+        remaining.remove(i);
+    }
+
+    if remaining.len() == 3 {
+        triangles.push([remaining[0], remaining[1], remaining[2]]);
+    }
+
+    if clockwise {
+        for tri in &mut triangles {
+            tri.swap(1, 2);
+        }
+    }
+    triangles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(polygon: &[(f32, f32)], tri: [usize; 3]) -> f32 {
+        cross(polygon[tri[0]], polygon[tri[1]], polygon[tri[2]]) * 0.5
+    }
+
+    #[test]
+    fn test_ear_clip_non_convex() {
+        // Comb shape: not star-shaped around any point near its centroid
+        let polygon = [
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 3.0),
+            (3.0, 3.0),
+            (3.0, 1.0),
+            (2.0, 1.0),
+            (2.0, 3.0),
+            (1.0, 3.0),
+            (1.0, 1.0),
+            (0.5, 1.0),
+            (0.5, 3.0),
+            (0.0, 3.0),
+        ];
+        // Base bar plus three teeth
+        let polygon_area = 4.0 + 2.0 + 2.0 + 1.0;
+
+        let triangles = ear_clip(&polygon);
+        assert_eq!(triangles.len(), polygon.len() - 2);
+
+        // Every triangle keeps the winding and the areas add up exactly,
+        // so no two triangles overlap
+        let mut total = 0.0;
+        for &tri in &triangles {
+            let a = area(&polygon, tri);
+            assert!(a > 0.0, "triangle {:?} flipped or degenerate", tri);
+            total += a;
+        }
+        assert!((total - polygon_area).abs() < 1e-4, "{} vs {}", total, polygon_area);
+
+        // Clockwise input produces clockwise triangles
+        let reversed: Vec<(f32, f32)> = polygon.iter().rev().copied().collect();
+        for tri in ear_clip(&reversed) {
+            assert!(area(&reversed, tri) < 0.0);
+        }
+    }
+
+    #[test]
+    fn test_cell_triangles_winding_matches_fan() {
+        let center = Vec3::new(0.0, 0.0, 10.0);
+        let vertices = [
+            Vec3::new(1.0, 0.0, 10.0),
+            Vec3::new(0.0, 1.0, 10.0),
+            Vec3::new(-1.0, 0.0, 10.0),
+            Vec3::new(0.0, -1.0, 10.0),
+        ];
+        let local = |i: u32| if i == 0 { center } else { vertices[i as usize - 1] };
+
+        for mode in [Triangulation::Fan, Triangulation::EarClip] {
+            for tri in cell_triangles(center, &vertices, mode) {
+                let [a, b, c] = tri.map(local);
+                assert!((b - a).cross(c - a).dot(center) > 0.0, "{:?} faces inward", mode);
+            }
+        }
+        assert_eq!(cell_triangles(center, &vertices, Triangulation::EarClip).len(), 2);
+    }
+}
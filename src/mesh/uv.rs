@@ -0,0 +1,84 @@
+//! Texture coordinate generation for cell meshes
+
+use glam::Vec3;
+
+use crate::generation::tangent_basis;
+
+/// How texture coordinates are generated for mesh vertices
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UvMode {
+    /// No UVs (`MeshData::uvs` stays empty)
+    #[default]
+    None,
+    /// Independent local UV square per cell
+    ///
+    /// Each cell's vertices are gnomonically projected onto the tangent plane
+    /// at the cell center and scaled to fit [0, 1], with the center at (0.5, 0.5).
+    /// Ideal for decals and grid textures, as there is no polar distortion.
+    PerCellTangent,
+}
+
+/// Compute per-cell tangent-plane UVs for a cell's center and boundary vertices
+///
+/// Returns one UV for the center followed by one per boundary vertex, in the
+/// same order the mesh triangle fan emits them. The longer tangent axis spans
+/// the full [0, 1] range so the aspect ratio is preserved.
+pub fn cell_tangent_uvs(center: Vec3, vertices: &[Vec3]) -> Vec<[f32; 2]> {
+    let projected = tangent_project(center, vertices);
+
+    let extent = projected
+        .iter()
+        .fold(0.0_f32, |acc, &(u, v)| acc.max(u.abs()).max(v.abs()));
+    let scale = if extent > 0.0 { 0.5 / extent } else { 0.0 };
+
+    std::iter::once([0.5, 0.5])
+        .chain(projected.into_iter().map(|(u, v)| [0.5 + u * scale, 0.5 + v * scale]))
+        .collect()
+}
+
+/// Gnomonically project boundary vertices onto the tangent plane at `center`
+///
+/// The (u, v) axes form a right-handed frame with the outward normal, so
+/// polygons that are counter-clockwise seen from outside stay counter-clockwise.
+pub(super) fn tangent_project(center: Vec3, vertices: &[Vec3]) -> Vec<(f32, f32)> {
+    let normal = center.normalize();
+
+    // Same tangent basis as the vertex ordering in generation
+    let (tangent_u, tangent_v) = tangent_basis(normal);
+
+    // Intersect the ray through each vertex with the tangent plane
+    vertices
+        .iter()
+        .map(|vertex| {
+            let dir = vertex.normalize();
+            let on_plane = dir / dir.dot(normal).max(f32::EPSILON) - normal;
+            (on_plane.dot(tangent_u), on_plane.dot(tangent_v))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_tangent_uvs() {
+        let center = Vec3::new(0.0, 0.0, 10.0);
+        let vertices = vec![
+            Vec3::new(1.0, 0.0, 10.0).normalize() * 10.0,
+            Vec3::new(0.0, 1.0, 10.0).normalize() * 10.0,
+            Vec3::new(-1.0, 0.0, 10.0).normalize() * 10.0,
+            Vec3::new(0.0, -1.0, 10.0).normalize() * 10.0,
+        ];
+
+        let uvs = cell_tangent_uvs(center, &vertices);
+        assert_eq!(uvs.len(), 5);
+        assert_eq!(uvs[0], [0.5, 0.5]);
+
+        for uv in &uvs[1..] {
+            assert!((0.0..=1.0).contains(&uv[0]) && (0.0..=1.0).contains(&uv[1]));
+            let distance = ((uv[0] - 0.5).powi(2) + (uv[1] - 0.5).powi(2)).sqrt();
+            assert!((distance - 0.5).abs() < 1e-4, "Square cell should touch the UV border");
+        }
+    }
+}
@@ -1,15 +1,55 @@
 //! VoronoiPlanet main structure
 
-use crate::cell::VoronoiCell;
-use crate::config::PlanetConfig;
-use crate::error::Result;
-use crate::generation::generate_raw_cells;
-use crate::terrain::{TerrainSampler, BasicTerrainType, PerlinTerrainSampler};
+use crate::attributes::CellAttributes;
+use crate::cell::{arc_length, LatLonBounds, VoronoiCell};
+use crate::config::{derive_seed, PlanetConfig, PlanetConfigBuilder, PlanetSize, SEED_STREAM_VARIANT};
+use crate::error::{Result, VoronoiError};
+use crate::generation::{
+    dedup_points, generate_cells, generate_raw_cells, generate_raw_cells_with_anchors, generate_raw_cells_with_report, tangent_basis, GenerationScratch, RawCell,
+    DEDUP_EPSILON,
+};
+use crate::stats::{GenerationReport, RenderStats, UniformityReport};
+use crate::terrain::{ContextSampler, TerrainSampler, RichTerrainSampler, TerrainData, BasicTerrainType, PerlinTerrainSampler};
 
+use crate::mesh::{ColorMapper, TerrainColor};
+use crate::interpolation::{barycentric_weights, sibson_weights};
 #[cfg(feature = "spatial-index")]
 use crate::spatial::SpatialIndex;
-#[cfg(feature = "spatial-index")]
-use glam::Vec3;
+use glam::{IVec3, Mat4, Quat, Vec3};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Local relief of a cell compared to its neighbors
+///
+/// Returned by [`VoronoiPlanet::relative_relief`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relief {
+    /// Higher than every neighbor (local maximum)
+    Peak,
+    /// Lower than every neighbor (local minimum)
+    Pit,
+    /// Higher than some neighbors and lower than or level with others
+    Slope,
+    /// Level with every neighbor, or no neighbors at all
+    Flat,
+}
+
+/// How [`VoronoiPlanet::repair_neighbor_symmetry`] resolves one-sided neighbor links
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NeighborRepair {
+    /// Add the missing reverse link, keeping every adjacency that was listed
+    #[default]
+    AddMissing,
+    /// Remove the one-sided link, keeping only adjacencies both cells agree on
+    RemoveDangling,
+}
 
 /// A complete Voronoi-tessellated planet
 ///
@@ -20,6 +60,11 @@ use glam::Vec3;
 ///
 /// * `T` - Terrain type for cells (e.g., `BasicTerrainType` or custom enum)
 ///
+/// # Thread Safety
+///
+/// `VoronoiPlanet<T>` is `Send` and `Sync` when `T` is, so it can be generated
+/// on a background thread and moved back, or shared behind an `Arc`.
+///
 /// # Examples
 ///
 /// ```
@@ -51,6 +96,26 @@ pub struct VoronoiPlanet<T> {
     /// Sphere radius for distance calculations
     radius: f32,
 
+    /// Auxiliary terrain data parallel to `cells` (empty unless generated with a rich sampler)
+    terrain_data: Vec<TerrainData>,
+
+    /// Resolved render colors parallel to `cells` (empty until `bake_colors` is called)
+    baked_colors: Vec<TerrainColor>,
+
+    /// Great-circle distances parallel to each cell's `neighbors` (empty until
+    /// `precompute_edge_costs` is called)
+    edge_costs: Vec<Vec<f32>>,
+
+    /// Compressed-sparse-row neighbor offsets, `cell_count + 1` entries
+    /// (empty until `build_neighbor_csr` is called)
+    neighbor_offsets: Vec<u32>,
+
+    /// All neighbor lists concatenated in cell order, indexed by `neighbor_offsets`
+    neighbor_data: Vec<u32>,
+
+    /// Cell ID of each input point, in input order (empty unless built with `from_points`)
+    input_cells: Vec<usize>,
+
     /// Spatial index for fast position-to-cell lookups (optional, requires spatial-index feature)
     #[cfg(feature = "spatial-index")]
     spatial_index: SpatialIndex,
@@ -87,9 +152,91 @@ impl VoronoiPlanet<BasicTerrainType> {
     /// assert!(planet.cell_count() > 0);
     /// ```
     pub fn generate(config: PlanetConfig) -> Result<Self> {
-        let sampler = PerlinTerrainSampler::new(config.terrain_seed);
+        let sampler = PerlinTerrainSampler::from_planet_config(&config);
         Self::generate_with_sampler(config, &sampler)
     }
+
+    /// Generate a planet like [`generate`](Self::generate), plus generation statistics
+    ///
+    /// The report holds the final and requested cell counts, how many points
+    /// were merged and replaced, the Lloyd's relaxation outcome and the terrain
+    /// distribution. With the `profiling` feature it also records per-stage
+    /// timings. The planet is identical to the one `generate` returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+    /// let (planet, report) = VoronoiPlanet::generate_with_report(config).unwrap();
+    /// assert_eq!(report.cell_count, planet.cell_count());
+    /// println!("Lloyd ran {} iterations, converged: {}", report.lloyd.iterations, report.lloyd.converged);
+    /// ```
+    pub fn generate_with_report(config: PlanetConfig) -> Result<(Self, GenerationReport)> {
+        #[cfg(feature = "profiling")]
+        let start = std::time::Instant::now();
+
+        let mut report = GenerationReport::default();
+        let raw_cells = generate_raw_cells_with_report(&config, &mut GenerationScratch::default(), &mut report)?;
+
+        #[cfg(feature = "profiling")]
+        let terrain_start = std::time::Instant::now();
+        let planet = Self::from_raw_cells(config, raw_cells, &PerlinTerrainSampler::from_planet_config(&config));
+        #[cfg(feature = "profiling")]
+        {
+            report.timings.terrain = terrain_start.elapsed();
+            report.timings.total = start.elapsed();
+        }
+
+        report.cell_count = planet.cell_count();
+        report.requested_cell_count = planet.requested_cell_count();
+        for cell in &planet.cells {
+            *report.terrain_counts.entry(cell.terrain).or_insert(0) += 1;
+        }
+        Ok((planet, report))
+    }
+
+    /// Trace every land/water border as a closed loop of vertex positions
+    ///
+    /// Applies [`region_outlines`](Self::region_outlines) to all non-water
+    /// cells, so each island, continent coast and lake shore becomes one loop,
+    /// running counter-clockwise around the land. Useful for drawing animated
+    /// surf or coastline strokes. Planets without both land and water have no
+    /// coastline and return an empty list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// for coast in planet.coastline_loops() {
+    ///     println!("Coastline with {} points", coast.len());
+    /// }
+    /// ```
+    pub fn coastline_loops(&self) -> Vec<Vec<Vec3>> {
+        let land: HashSet<usize> = self.cells.iter().filter(|c| !c.terrain.is_water()).map(|c| c.id).collect();
+        self.region_outlines(&land)
+    }
+
+    /// Find the closest ocean cell
+    ///
+    /// Shorthand for [`nearest_cell_matching`](Self::nearest_cell_matching)
+    /// with [`BasicTerrainType::is_water`]. Returns `from` if it is ocean, and
+    /// `None` if `from` is invalid or the planet has no ocean.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// if let Some(port) = planet.nearest_ocean(0) {
+    ///     println!("Nearest ocean: cell {}", port);
+    /// }
+    /// ```
+    pub fn nearest_ocean(&self, from: usize) -> Option<usize> {
+        self.nearest_cell_matching(from, BasicTerrainType::is_water)
+    }
 }
 
 impl<T: Clone> VoronoiPlanet<T> {
@@ -124,10 +271,175 @@ impl<T: Clone> VoronoiPlanet<T> {
     where
         S: TerrainSampler<Output = T>,
     {
+        // Generate raw cells (geometry only, no terrain)
+        let raw_cells = generate_raw_cells(&config)?;
+
+        Ok(Self::from_raw_cells(config, raw_cells, sampler))
+    }
+
+    /// Generate a planet in two phases: base terrain, then a neighborhood pass
+    ///
+    /// Samples base terrain for every cell with `sampler`, then replaces each
+    /// cell's terrain with `refiner.refine(cell, base, neighbor_bases)`. See
+    /// [`ContextSampler`](crate::terrain::ContextSampler) for what the refiner
+    /// sees. Geometry is the same as with
+    /// [`generate_with_sampler`](Self::generate_with_sampler).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    /// use rust_voronoi_planet::terrain::ContextSampler;
+    ///
+    /// // Land never touches ocean directly: coastal land becomes beach
+    /// struct Shores;
+    ///
+    /// impl ContextSampler for Shores {
+    ///     type Output = BasicTerrainType;
+    ///
+    ///     fn refine(&self, _cell: &RawCell, terrain: &BasicTerrainType, neighbors: &[BasicTerrainType]) -> BasicTerrainType {
+    ///         if *terrain == BasicTerrainType::Land && neighbors.contains(&BasicTerrainType::Ocean) {
+    ///             BasicTerrainType::Beach
+    ///         } else {
+    ///             *terrain
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+    /// let sampler = PerlinTerrainSampler::from_planet_config(&config);
+    /// let planet = VoronoiPlanet::generate_with_context(config, &sampler, &Shores).unwrap();
+    /// ```
+    pub fn generate_with_context<S, R>(config: PlanetConfig, sampler: &S, refiner: &R) -> Result<Self>
+    where
+        S: TerrainSampler<Output = T>,
+        R: ContextSampler<Output = T>,
+    {
+        let raw_cells = generate_raw_cells(&config)?;
         let radius = config.radius();
 
-        // Generate raw cells (geometry only, no terrain)
+        let base: Vec<T> = raw_cells.iter().map(|raw| sampler.sample(raw.center, radius)).collect();
+        let mut neighbors_terrain = Vec::new();
+        let cells = raw_cells
+            .into_iter()
+            .zip(&base)
+            .map(|(raw, terrain)| {
+                neighbors_terrain.clear();
+                neighbors_terrain.extend(raw.neighbors.iter().map(|&n| base[n].clone()));
+                let terrain = refiner.refine(&raw, terrain, &neighbors_terrain);
+                VoronoiCell::new(raw.id, raw.center, terrain, raw.neighbors, raw.vertices)
+            })
+            .collect();
+
+        Ok(Self::from_cells(config, cells, Vec::new()))
+    }
+
+    /// Generate a planet with cells centered on fixed anchor points
+    ///
+    /// Like [`generate_with_sampler`](Self::generate_with_sampler), but each
+    /// anchor (a direction from the planet center, e.g. a capital or landmark
+    /// location) replaces the nearest seed point and is held in place during
+    /// Lloyd's relaxation. Every anchor therefore gets a cell whose center is
+    /// exactly the anchor projected onto the sphere, while the cell count is
+    /// unchanged. Cells around an anchor are slightly less uniform than
+    /// elsewhere, since relaxation cannot move the anchor to balance them. See
+    /// [`generate_raw_cells_with_anchors`](crate::generation::generate_raw_cells_with_anchors).
+    ///
+    /// # Errors
+    ///
+    /// Returns `VoronoiError::InvalidConfig` for a zero or non-finite anchor,
+    /// two (nearly) coincident anchors, or more anchors than cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+    /// let sampler = PerlinTerrainSampler::from_planet_config(&config);
+    /// let capital = Vec3::new(0.3, 0.8, -0.5);
+    /// let planet = VoronoiPlanet::generate_with_anchors(config, &sampler, &[capital]).unwrap();
+    /// ```
+    pub fn generate_with_anchors<S>(config: PlanetConfig, sampler: &S, anchors: &[Vec3]) -> Result<Self>
+    where
+        S: TerrainSampler<Output = T>,
+    {
+        let raw_cells = generate_raw_cells_with_anchors(&config, anchors)?;
+        Ok(Self::from_raw_cells(config, raw_cells, sampler))
+    }
+
+    /// Generate several planets that share one cell layout
+    ///
+    /// Builds the cell geometry and spatial index once for `config`, then
+    /// samples terrain with each sampler in turn, returning one planet per
+    /// sampler in the same order. Every variant has exactly the cells of
+    /// [`generate_with_sampler`](Self::generate_with_sampler) with the same
+    /// config, but the point generation, Lloyd's relaxation and
+    /// triangulation run only once. Useful for showing alternative worlds of
+    /// the same size side by side. Returns an empty list if `samplers` is
+    /// empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`generate_with_sampler`](Self::generate_with_sampler).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+    /// let earth = PerlinTerrainSampler::new(1);
+    /// let mars = PerlinTerrainSampler::new(2);
+    /// let planets = VoronoiPlanet::generate_variants(config, &[&earth, &mars]).unwrap();
+    /// assert_eq!(planets.len(), 2);
+    /// ```
+    pub fn generate_variants(config: PlanetConfig, samplers: &[&dyn TerrainSampler<Output = T>]) -> Result<Vec<Self>> {
+        let Some((first, rest)) = samplers.split_first() else {
+            return Ok(Vec::new());
+        };
+
         let raw_cells = generate_raw_cells(&config)?;
+        let base = Self::from_raw_cells(config, raw_cells, *first);
+
+        let mut variants = Vec::with_capacity(samplers.len());
+        for sampler in rest {
+            let mut variant = base.clone();
+            for cell in &mut variant.cells {
+                cell.terrain = sampler.sample(cell.center, variant.radius);
+            }
+            variants.push(variant);
+        }
+        variants.insert(0, base);
+
+        Ok(variants)
+    }
+
+    /// Build a planet from precomputed raw cells
+    ///
+    /// Use this to run cells from an alternative generator (for example
+    /// [`generate_icosphere_cells`](crate::generation::generate_icosphere_cells))
+    /// through the regular terrain, query and mesh pipeline. Cell IDs must be
+    /// `0..raw_cells.len()` in order, and the cells should lie on a sphere of
+    /// `config.radius()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    /// use rust_voronoi_planet::generation::generate_icosphere_cells;
+    ///
+    /// let config = PlanetConfigBuilder::new().seed(42).build().unwrap();
+    /// let raw_cells = generate_icosphere_cells(3, config.radius()).unwrap();
+    /// let sampler = PerlinTerrainSampler::new(config.terrain_seed);
+    /// let planet = VoronoiPlanet::from_raw_cells(config, raw_cells, &sampler);
+    /// assert_eq!(planet.cell_count(), 642);
+    /// ```
+    pub fn from_raw_cells<S>(config: PlanetConfig, raw_cells: Vec<RawCell>, sampler: &S) -> Self
+    where
+        S: TerrainSampler<Output = T> + ?Sized,
+    {
+        let radius = config.radius();
 
         // Apply terrain sampling to create full cells
         let cells: Vec<VoronoiCell<T>> = raw_cells
@@ -145,6 +457,230 @@ impl<T: Clone> VoronoiPlanet<T> {
             })
             .collect();
 
+        Self::from_cells(config, cells, Vec::new())
+    }
+
+    /// Build a planet from an externally supplied set of seed points
+    ///
+    /// Runs the regular cell construction and terrain sampling on points you
+    /// provide (for example imported from data), skipping point generation and
+    /// Lloyd's relaxation. Points are projected onto the sphere of `radius`, and
+    /// coincident points are merged as with `dedup_points`.
+    ///
+    /// Cell IDs follow the order of the convex hull, which does not match the
+    /// input order; use [`cell_for_input_point`](Self::cell_for_input_point) to
+    /// map an input index to its cell. The resulting [`config`](Self::config) is a
+    /// `PlanetSize::Custom` describing the input with seed 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VoronoiError::InvalidConfig` if `radius` is not positive, fewer
+    /// than 4 points are given, or a point is zero-length or not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// // Octahedron: six cells, each touching four others
+    /// let points = [Vec3::X, Vec3::NEG_X, Vec3::Y, Vec3::NEG_Y, Vec3::Z, Vec3::NEG_Z];
+    /// let planet = VoronoiPlanet::from_points(&points, 5.0, &PerlinTerrainSampler::new(1)).unwrap();
+    /// assert_eq!(planet.cell_count(), 6);
+    /// ```
+    pub fn from_points<S>(points: &[Vec3], radius: f32, sampler: &S) -> Result<Self>
+    where
+        S: TerrainSampler<Output = T>,
+    {
+        if !(radius > 0.0 && radius.is_finite()) {
+            return Err(VoronoiError::InvalidConfig(format!(
+                "radius must be positive (got {})",
+                radius
+            )));
+        }
+        if points.len() < 4 {
+            return Err(VoronoiError::InvalidConfig(format!(
+                "at least 4 points are needed to build cells (got {})",
+                points.len()
+            )));
+        }
+
+        let projected = points
+            .iter()
+            .map(|p| {
+                let length = p.length();
+                if !(length > 0.0 && length.is_finite()) {
+                    return Err(VoronoiError::InvalidConfig(format!(
+                        "point {:?} cannot be projected onto the sphere",
+                        p
+                    )));
+                }
+                let on_sphere = *p / length * radius;
+                Ok(parry3d::math::Point::new(on_sphere.x, on_sphere.y, on_sphere.z))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let config = PlanetConfigBuilder::new()
+            .seed(0)
+            .planet_size(PlanetSize::Custom { cell_count: points.len(), radius })
+            .lloyd_iterations(0)?
+            .build()?;
+
+        let deduped = dedup_points(&projected, DEDUP_EPSILON * radius);
+        let raw_cells = generate_cells(&deduped, radius)?;
+
+        // The hull reorders its vertices, so recover each input's cell by position
+        let mut planet = Self::from_raw_cells(config, raw_cells, sampler);
+        planet.input_cells = projected
+            .iter()
+            .filter_map(|p| planet.nearest_cell(Vec3::new(p.x, p.y, p.z)))
+            .collect();
+        Ok(planet)
+    }
+
+    /// Get the cell built from an input point of [`from_points`](Self::from_points)
+    ///
+    /// `original_index` is the point's position in the slice passed to
+    /// `from_points`. Merged duplicates map to the same cell. Returns `None` if
+    /// the index is out of range or the planet wasn't built from points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// let points = [Vec3::X, Vec3::NEG_X, Vec3::Y, Vec3::NEG_Y, Vec3::Z, Vec3::NEG_Z];
+    /// let planet = VoronoiPlanet::from_points(&points, 5.0, &PerlinTerrainSampler::new(1)).unwrap();
+    /// let cell = planet.cell_for_input_point(4).unwrap();
+    /// assert!(planet.get_cell(cell).unwrap().center.z > 4.9);
+    /// ```
+    #[inline]
+    pub fn cell_for_input_point(&self, original_index: usize) -> Option<usize> {
+        self.input_cells.get(original_index).copied()
+    }
+
+    /// Generate a planet with a sampler that also returns auxiliary terrain data
+    ///
+    /// Works like [`generate_with_sampler`](Self::generate_with_sampler), but keeps the
+    /// elevation, temperature and moisture computed during classification so they can
+    /// be queried later with [`cell_data`](Self::cell_data) without resampling noise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// let config = PlanetConfigBuilder::new()
+    ///     .seed(42)
+    ///     .planet_size(PlanetSize::Tiny)
+    ///     .build()
+    ///     .unwrap();
+    /// let sampler = PerlinTerrainSampler::new(42);
+    /// let planet = VoronoiPlanet::generate_with_rich_sampler(config, &sampler).unwrap();
+    ///
+    /// let data = planet.cell_data(0).unwrap();
+    /// println!("Cell 0 elevation: {}", data.elevation);
+    /// ```
+    pub fn generate_with_rich_sampler<S>(config: PlanetConfig, sampler: &S) -> Result<Self>
+    where
+        S: RichTerrainSampler<Output = T>,
+    {
+        let radius = config.radius();
+        let raw_cells = generate_raw_cells(&config)?;
+
+        let mut terrain_data = Vec::with_capacity(raw_cells.len());
+        let cells: Vec<VoronoiCell<T>> = raw_cells
+            .into_iter()
+            .map(|raw| {
+                let (terrain, data) = sampler.sample_rich(raw.center, radius);
+                terrain_data.push(data);
+                VoronoiCell::new(
+                    raw.id,
+                    raw.center,
+                    terrain,
+                    raw.neighbors,
+                    raw.vertices,
+                )
+            })
+            .collect();
+
+        Ok(Self::from_cells(config, cells, terrain_data))
+    }
+
+    /// Generate a planet whose cell geometry follows a per-cell elevation
+    ///
+    /// Runs [`generate_with_sampler`](Self::generate_with_sampler), then moves
+    /// each cell's center and vertices radially to `radius + elevation(cell)`
+    /// and rebuilds the spatial index on the displaced centers. Geometry-based
+    /// queries such as [`VoronoiCell::approximate_area`] and
+    /// [`find_cell_at`](Self::find_cell_at) then see the real shape, while
+    /// [`radius`](Self::radius) stays the base radius and
+    /// [`validate`](Self::validate) no longer holds.
+    ///
+    /// Each cell is displaced independently, so a vertex shared by cells of
+    /// different elevation ends up at several heights and the surface shows
+    /// cracks between neighbors. Weld vertices (or render with
+    /// [`generate_mesh_with_displacement`](crate::generate_mesh_with_displacement))
+    /// if a closed surface is needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VoronoiError::InvalidConfig` if an elevation is not finite or
+    /// would push a cell through the planet center.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::*;
+    ///
+    /// let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+    /// let sampler = PerlinTerrainSampler::from_planet_config(&config);
+    /// let planet = VoronoiPlanet::generate_displaced(config, &sampler, |cell| {
+    ///     if cell.terrain == BasicTerrainType::Mountain { 0.5 } else { 0.0 }
+    /// })
+    /// .unwrap();
+    /// ```
+    pub fn generate_displaced<S, F>(config: PlanetConfig, sampler: &S, elevation: F) -> Result<Self>
+    where
+        S: TerrainSampler<Output = T>,
+        F: Fn(&VoronoiCell<T>) -> f32,
+    {
+        let mut planet = Self::generate_with_sampler(config, sampler)?;
+        let radius = planet.radius;
+
+        let radii = planet
+            .cells
+            .iter()
+            .map(|cell| {
+                let cell_radius = radius + elevation(cell);
+                if cell_radius.is_finite() && cell_radius > 0.0 {
+                    Ok(cell_radius)
+                } else {
+                    Err(VoronoiError::InvalidConfig(format!(
+                        "elevation of cell {} gives invalid radius {}",
+                        cell.id, cell_radius
+                    )))
+                }
+            })
+            .collect::<Result<Vec<f32>>>()?;
+
+        for (cell, cell_radius) in planet.cells.iter_mut().zip(radii) {
+            cell.center = cell.center.normalize() * cell_radius;
+            for vertex in &mut cell.vertices {
+                *vertex = vertex.normalize() * cell_radius;
+            }
+        }
+
+        #[cfg(feature = "spatial-index")]
+        {
+            let centers: Vec<Vec3> = planet.cells.iter().map(|c| c.center).collect();
+            planet.spatial_index = SpatialIndex::new(&centers);
+        }
+
+        Ok(planet)
+    }
+
+    /// Assemble a planet from finished cells and build its spatial index
+    fn from_cells(config: PlanetConfig, cells: Vec<VoronoiCell<T>>, terrain_data: Vec<TerrainData>) -> Self {
         // Build spatial index (requires spatial-index feature)
         #[cfg(feature = "spatial-index")]
         let spatial_index = {
@@ -152,13 +688,19 @@ impl<T: Clone> VoronoiPlanet<T> {
             SpatialIndex::new(&centers)
         };
 
-        Ok(Self {
+        Self {
+            radius: config.radius(),
             config,
             cells,
-            radius,
+            terrain_data,
+            baked_colors: Vec::new(),
+            edge_costs: Vec::new(),
+            neighbor_offsets: Vec::new(),
+            neighbor_data: Vec::new(),
+            input_cells: Vec::new(),
             #[cfg(feature = "spatial-index")]
             spatial_index,
-        })
+        }
     }
 
     /// Get the configuration used to generate this planet
@@ -176,6 +718,30 @@ impl<T: Clone> VoronoiPlanet<T> {
         &self.config
     }
 
+    /// Get an owned copy of the configuration for saving
+    ///
+    /// Always serializable with the `serde` feature, whatever the terrain
+    /// type `T` is, so it works as an escape hatch when `T` does not implement
+    /// `Serialize` (or doesn't need to be stored at all). Regenerating from
+    /// the config reproduces the same cells; with a custom sampler, pass the
+    /// same sampler to [`generate_with_sampler`](Self::generate_with_sampler)
+    /// to get the same terrain back. Edits made after generation (terrain
+    /// changes, [`rotate`](Self::rotate), [`jitter_centers`](Self::jitter_centers))
+    /// are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let saved = planet.to_config_only();
+    /// let regenerated = VoronoiPlanet::generate(saved).unwrap();
+    /// assert_eq!(regenerated.cell_count(), planet.cell_count());
+    /// ```
+    pub fn to_config_only(&self) -> PlanetConfig {
+        self.config
+    }
+
     /// Get the number of cells on this planet
     ///
     /// # Example
@@ -190,6 +756,17 @@ impl<T: Clone> VoronoiPlanet<T> {
         self.cells.len()
     }
 
+    /// Get the number of cells the configuration asked for
+    ///
+    /// Equal to [`cell_count`](Self::cell_count) for generated planets unless
+    /// `dedup_points` was disabled and the hull dropped duplicate points. For
+    /// [`from_points`](Self::from_points) this is the number of input points,
+    /// which exceeds the cell count when duplicates were merged.
+    #[inline]
+    pub fn requested_cell_count(&self) -> usize {
+        self.config.cell_count()
+    }
+
     /// Get the sphere radius
     ///
     /// # Example
@@ -244,192 +821,3834 @@ impl<T: Clone> VoronoiPlanet<T> {
         &self.cells
     }
 
-    /// Get neighbor IDs for a cell
+    /// Get the auxiliary terrain data for a cell
     ///
-    /// Returns a slice of cell IDs that are adjacent to the given cell.
-    /// Returns empty slice if cell ID is invalid.
+    /// Only available for planets generated with
+    /// [`generate_with_rich_sampler`](Self::generate_with_rich_sampler);
+    /// returns `None` otherwise or if the cell ID is out of bounds.
+    #[inline]
+    pub fn cell_data(&self, id: usize) -> Option<&TerrainData> {
+        self.terrain_data.get(id)
+    }
+
+    /// Get the auxiliary terrain data for all cells, indexed by cell ID
     ///
-    /// # Arguments
+    /// Empty unless the planet was generated with a rich sampler.
+    #[inline]
+    pub fn terrain_data(&self) -> &[TerrainData] {
+        &self.terrain_data
+    }
+
+    /// Re-run terrain sampling for a subset of cells
     ///
-    /// * `cell_id` - Cell ID to query
+    /// Only the listed cells get new terrain; all others are left untouched.
+    /// Combined with region detection this allows localized re-rolls, e.g.
+    /// sampling one continent again with a different seed. Invalid IDs are
+    /// ignored. Baked colors and auxiliary terrain data are not updated; call
+    /// [`bake_colors`](Self::bake_colors) again if you use them.
     ///
     /// # Example
     ///
     /// ```
     /// # use rust_voronoi_planet::*;
-    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
-    /// let neighbors = planet.get_neighbors(0);
-    /// println!("Cell 0 has {} neighbors", neighbors.len());
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let continent = planet.find_cells_within_radius(0, 5);
+    /// planet.resample_region(&continent, &PerlinTerrainSampler::new(7));
     /// ```
-    pub fn get_neighbors(&self, cell_id: usize) -> &[usize] {
-        self.cells
-            .get(cell_id)
-            .map(|c| c.neighbors.as_slice())
-            .unwrap_or(&[])
+    pub fn resample_region<S>(&mut self, cells: &[usize], sampler: &S)
+    where
+        S: TerrainSampler<Output = T>,
+    {
+        let radius = self.radius;
+        for &id in cells {
+            if let Some(cell) = self.cells.get_mut(id) {
+                cell.terrain = sampler.sample(cell.center, radius);
+            }
+        }
+    }
+
+    /// Set the terrain of a single cell
+    ///
+    /// Baked colors are not updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VoronoiError::CellNotFound` if the ID is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// planet.set_terrain(0, BasicTerrainType::Mountain).unwrap();
+    /// assert_eq!(planet.get_cell(0).unwrap().terrain, BasicTerrainType::Mountain);
+    /// ```
+    pub fn set_terrain(&mut self, id: usize, terrain: T) -> Result<()> {
+        let cell = self.cells.get_mut(id).ok_or(VoronoiError::CellNotFound(id))?;
+        cell.terrain = terrain;
+        Ok(())
+    }
+
+    /// Copy the terrain of every cell, indexed by cell ID
+    ///
+    /// Much cheaper than cloning the planet, so an editor can push one
+    /// snapshot per edit onto an undo stack and roll back with
+    /// [`restore_terrain`](Self::restore_terrain).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let mut undo = vec![planet.terrain_snapshot()];
+    /// planet.set_terrain(0, BasicTerrainType::Ice).unwrap();
+    ///
+    /// planet.restore_terrain(&undo.pop().unwrap()).unwrap();
+    /// ```
+    pub fn terrain_snapshot(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.cells.iter().map(|c| c.terrain.clone()).collect()
+    }
+
+    /// Restore the terrain of every cell from a [`terrain_snapshot`](Self::terrain_snapshot)
+    ///
+    /// Baked colors are not updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VoronoiError::InvalidConfig` if the snapshot length differs
+    /// from the cell count; the planet is left unchanged.
+    pub fn restore_terrain(&mut self, snapshot: &[T]) -> Result<()>
+    where
+        T: Clone,
+    {
+        if snapshot.len() != self.cells.len() {
+            return Err(VoronoiError::InvalidConfig(format!(
+                "terrain snapshot has {} entries, planet has {} cells",
+                snapshot.len(),
+                self.cells.len()
+            )));
+        }
+
+        for (cell, terrain) in self.cells.iter_mut().zip(snapshot) {
+            cell.terrain = terrain.clone();
+        }
+        Ok(())
+    }
+
+    /// Remove single-cell terrain specks with a majority filter
+    ///
+    /// Each pass replaces every cell's terrain, in place, with the most common
+    /// terrain among the cell and its neighbors. Ties keep the current terrain,
+    /// so coastlines and large features stay put while isolated one-cell
+    /// islands and lakes disappear. All cells in a pass read the terrain from
+    /// before that pass. Baked colors are not updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// planet.smooth_terrain(2);
+    /// ```
+    pub fn smooth_terrain(&mut self, passes: usize)
+    where
+        T: Eq,
+    {
+        for _ in 0..passes {
+            let smoothed: Vec<T> = self
+                .cells
+                .iter()
+                .map(|cell| {
+                    let votes = |terrain: &T| {
+                        std::iter::once(cell.id)
+                            .chain(cell.neighbors.iter().copied())
+                            .filter(|&id| self.cells[id].terrain == *terrain)
+                            .count()
+                    };
+
+                    let mut best = &cell.terrain;
+                    let mut best_votes = votes(best);
+                    for &n in &cell.neighbors {
+                        let candidate = &self.cells[n].terrain;
+                        let candidate_votes = votes(candidate);
+                        if candidate_votes > best_votes {
+                            best = candidate;
+                            best_votes = candidate_votes;
+                        }
+                    }
+                    best.clone()
+                })
+                .collect();
+
+            for (cell, terrain) in self.cells.iter_mut().zip(smoothed) {
+                cell.terrain = terrain;
+            }
+        }
+    }
+
+    /// Nudge every cell center by a seeded random tangential offset
+    ///
+    /// Each center moves up to `strength` times the average cell radius
+    /// (the radius of a disc with the mean cell area) in a random direction,
+    /// is projected back onto the sphere, and the spatial index is rebuilt.
+    /// Edge costs from [`precompute_edge_costs`](Self::precompute_edge_costs)
+    /// are recomputed for the new center distances. `strength` 0 leaves the
+    /// planet unchanged.
+    ///
+    /// This is a cheap cosmetic tweak for where units and buildings sit: cell
+    /// boundaries, neighbors and terrain are NOT recomputed. Keep `strength`
+    /// well below 1, or centers may end up outside their own cell and
+    /// [`find_cell_at`](Self::find_cell_at) near the boundary will disagree
+    /// with the drawn polygons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// planet.jitter_centers(0.2, 7);
+    /// ```
+    pub fn jitter_centers(&mut self, strength: f32, seed: u32) {
+        if strength == 0.0 || self.cells.is_empty() {
+            return;
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+        let cell_radius = 2.0 * self.radius / (self.cells.len() as f32).sqrt();
+        let max_offset = strength * cell_radius;
+
+        for cell in &mut self.cells {
+            let normal = cell.center.normalize();
+            let (u, v) = tangent_basis(normal);
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let distance = rng.gen::<f32>() * max_offset;
+            let offset = (u * angle.cos() + v * angle.sin()) * distance;
+            cell.center = (cell.center + offset).normalize() * self.radius;
+        }
+
+        #[cfg(feature = "spatial-index")]
+        {
+            let centers: Vec<Vec3> = self.cells.iter().map(|c| c.center).collect();
+            self.spatial_index = SpatialIndex::new(&centers);
+        }
+
+        if !self.edge_costs.is_empty() {
+            self.precompute_edge_costs();
+        }
+    }
+
+    /// Rotate the whole planet about its center
+    ///
+    /// Applies `rotation` to every cell center and boundary vertex and rebuilds
+    /// the spatial index, so [`find_cell_at`](Self::find_cell_at) and the other
+    /// position queries answer in the rotated frame. Cell IDs, neighbors and
+    /// terrain are unchanged. Useful to turn a chosen cell toward the camera
+    /// without moving the camera.
+    ///
+    /// This is O(n) in the number of cells (plus the index rebuild), and any
+    /// world positions cached from the planet before the call, including
+    /// previously generated meshes, are stale afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// // Bring cell 0 to face +Z
+    /// let center = planet.cells()[0].center.normalize();
+    /// planet.rotate(glam::Quat::from_rotation_arc(center, Vec3::Z));
+    /// assert!(planet.cells()[0].center.normalize().abs_diff_eq(Vec3::Z, 1e-5));
+    /// ```
+    pub fn rotate(&mut self, rotation: Quat) {
+        let rotation = rotation.normalize();
+        for cell in &mut self.cells {
+            cell.center = rotation * cell.center;
+            for vertex in &mut cell.vertices {
+                *vertex = rotation * *vertex;
+            }
+        }
+
+        #[cfg(feature = "spatial-index")]
+        {
+            let centers: Vec<Vec3> = self.cells.iter().map(|c| c.center).collect();
+            self.spatial_index = SpatialIndex::new(&centers);
+        }
+    }
+
+    /// Pick a stable art variant for a cell
+    ///
+    /// Returns an index in `0..variant_count` (e.g. which of four grass
+    /// textures to use) drawn from a per-cell RNG seeded with the planet seed
+    /// and the cell ID. The same configuration always gives the same index for
+    /// a cell, and indices are uniformly distributed over cells, so materials
+    /// can vary per cell without storing anything. Returns 0 if
+    /// `variant_count` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let grass_textures = ["grass_a.png", "grass_b.png", "grass_c.png", "grass_d.png"];
+    /// let texture = grass_textures[planet.variant_index(0, grass_textures.len())];
+    /// ```
+    pub fn variant_index(&self, cell_id: usize, variant_count: usize) -> usize {
+        if variant_count == 0 {
+            return 0;
+        }
+        self.cell_rng(cell_id, SEED_STREAM_VARIANT).gen_range(0..variant_count)
+    }
+
+    /// Draw uniformly distributed random points on the planet surface
+    ///
+    /// Points are area-uniform, with no clustering at the poles: the height
+    /// along Y and the longitude are drawn uniformly,
+    /// which by Archimedes' hat-box theorem covers equal areas with equal
+    /// probability. Every point lies at [`radius`](Self::radius), and the same
+    /// `seed` always gives the same points. Unlike picking cells, this ignores
+    /// cell sizes entirely, which suits particles and decoration scatter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let sparks = planet.random_surface_points(500, 7);
+    /// assert_eq!(sparks.len(), 500);
+    /// ```
+    pub fn random_surface_points(&self, count: usize, seed: u32) -> Vec<Vec3> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+
+        (0..count)
+            .map(|_| {
+                let y: f32 = rng.gen_range(-1.0..=1.0);
+                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                let ring = (1.0 - y * y).max(0.0).sqrt();
+                Vec3::new(ring * angle.cos(), y, ring * angle.sin()) * self.radius
+            })
+            .collect()
+    }
+
+    /// Draw random surface points together with the cell containing each
+    ///
+    /// Same points as [`random_surface_points`](Self::random_surface_points)
+    /// for the same `seed`, each paired with its nearest cell (through the
+    /// spatial index when available). Returns an empty list for a planet
+    /// without cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// for (position, cell_id) in planet.random_surface_points_with_cells(20, 7) {
+    ///     println!("{:?} lies in cell {}", position, cell_id);
+    /// }
+    /// ```
+    pub fn random_surface_points_with_cells(&self, count: usize, seed: u32) -> Vec<(Vec3, usize)> {
+        if self.cells.is_empty() {
+            return Vec::new();
+        }
+        self.random_surface_points(count, seed)
+            .into_iter()
+            .filter_map(|point| Some((point, self.nearest_cell(point)?)))
+            .collect()
+    }
+
+    /// Pick well-separated starting cells on habitable terrain
+    ///
+    /// Greedy farthest-point dispersion: the first cell is drawn at random
+    /// from the habitable cells (seeded by `seed`), and every further pick is
+    /// the habitable cell whose great-circle distance to the nearest
+    /// already-picked cell is largest (ties go to the lowest ID). The smallest
+    /// pairwise distance of the result is at least half of the best possible
+    /// for `count` cells. Costs O(count × habitable cells).
+    ///
+    /// Returns cell IDs in pick order: `count` of them, or every habitable
+    /// cell if there are fewer. The same planet, predicate and seed always
+    /// give the same result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let habitable = |cell: &VoronoiCell<BasicTerrainType>| cell.terrain == BasicTerrainType::Land;
+    /// let capitals = planet.pick_spawn_points(4, habitable, 7);
+    /// assert!(capitals.iter().all(|&id| planet.cells()[id].terrain == BasicTerrainType::Land));
+    /// ```
+    pub fn pick_spawn_points(&self, count: usize, habitable: impl Fn(&VoronoiCell<T>) -> bool, seed: u32) -> Vec<usize> {
+        let candidates: Vec<(usize, Vec3)> = self
+            .cells
+            .iter()
+            .filter(|cell| habitable(cell))
+            .map(|cell| (cell.id, cell.center.normalize_or_zero()))
+            .collect();
+        if count == 0 || candidates.is_empty() {
+            return Vec::new();
+        }
+
+        // Separation is tracked as the largest dot product with any pick: the
+        // smaller it is, the larger the great-circle distance
+        let mut closest_dot = vec![f32::NEG_INFINITY; candidates.len()];
+        let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+        let mut next = rng.gen_range(0..candidates.len());
+        let mut picks = Vec::with_capacity(count.min(candidates.len()));
+
+        while picks.len() < count {
+            let (id, direction) = candidates[next];
+            picks.push(id);
+            closest_dot[next] = f32::INFINITY;
+            for (dot, (_, other)) in closest_dot.iter_mut().zip(&candidates) {
+                *dot = dot.max(direction.dot(*other));
+            }
+
+            // Farthest remaining candidate; candidates are in ID order, so the first minimum wins ties
+            match closest_dot
+                .iter()
+                .enumerate()
+                .filter(|(_, dot)| **dot < f32::INFINITY)
+                .min_by(|a, b| a.1.total_cmp(b.1))
+            {
+                Some((index, _)) => next = index,
+                None => break,
+            }
+        }
+        picks
+    }
+
+    /// Deterministic RNG for one cell and purpose, see [`derive_seed`]
+    fn cell_rng(&self, cell_id: usize, stream: u32) -> ChaCha8Rng {
+        let base = derive_seed(self.config.seed, stream) as u64;
+        ChaCha8Rng::seed_from_u64(base << 32 ^ cell_id as u64)
+    }
+
+    /// Resolve and store the render color of every cell
+    ///
+    /// Runs the color mapper once per cell and keeps the result, so meshes
+    /// rebuilt later with [`generate_mesh_baked`](crate::mesh::generate_mesh_baked)
+    /// don't need the mapper. Call again after changing terrain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// planet.bake_colors(&BasicColorMapper);
+    /// println!("Cell 0 color: {:?}", planet.baked_color(0));
+    /// ```
+    pub fn bake_colors<C: ColorMapper<T>>(&mut self, mapper: &C) {
+        self.baked_colors = self.cells.iter().map(|c| mapper.map_color_cell(c)).collect();
+    }
+
+    /// Get the baked color of a cell
+    ///
+    /// Returns `None` if colors haven't been baked or the cell ID is out of bounds.
+    #[inline]
+    pub fn baked_color(&self, id: usize) -> Option<TerrainColor> {
+        self.baked_colors.get(id).copied()
+    }
+
+    /// Get all baked colors, indexed by cell ID (empty until baked)
+    #[inline]
+    pub fn baked_colors(&self) -> &[TerrainColor] {
+        &self.baked_colors
+    }
+
+    /// Precompute the great-circle distance from every cell to each neighbor
+    ///
+    /// Pathfinding visits the same neighbor pairs over and over; after this call
+    /// [`edge_cost`](Self::edge_cost) reads the stored value instead of
+    /// recomputing an `acos` per step. Costs a few floats per cell of memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// planet.precompute_edge_costs();
+    /// let neighbor = planet.get_neighbors(0)[0];
+    /// println!("Step cost: {:?}", planet.edge_cost(0, neighbor));
+    /// ```
+    pub fn precompute_edge_costs(&mut self) {
+        self.edge_costs = self
+            .cells
+            .iter()
+            .map(|cell| {
+                cell.neighbors
+                    .iter()
+                    .map(|&n| cell.distance_to(&self.cells[n], self.radius))
+                    .collect()
+            })
+            .collect();
+    }
+
+    /// Get the precomputed neighbor distances of a cell
+    ///
+    /// The slice is parallel to the cell's `neighbors`. Returns `None` if
+    /// [`precompute_edge_costs`](Self::precompute_edge_costs) hasn't been
+    /// called or the cell ID is out of bounds.
+    #[inline]
+    pub fn neighbor_costs(&self, id: usize) -> Option<&[f32]> {
+        self.edge_costs.get(id).map(Vec::as_slice)
+    }
+
+    /// Get the great-circle distance between two neighboring cells
+    ///
+    /// Uses the precomputed value when available and computes it otherwise.
+    /// Returns `None` if the cells are not neighbors.
+    pub fn edge_cost(&self, from: usize, to: usize) -> Option<f32> {
+        let cell = self.cells.get(from)?;
+        let slot = cell.neighbors.iter().position(|&n| n == to)?;
+
+        match self.edge_costs.get(from) {
+            Some(costs) => Some(costs[slot]),
+            None => Some(cell.distance_to(&self.cells[to], self.radius)),
+        }
+    }
+
+    /// Build a compact compressed-sparse-row copy of the neighbor graph
+    ///
+    /// Packs every neighbor list into one contiguous `u32` buffer, so graph
+    /// traversal on large planets touches two allocations instead of one per
+    /// cell. The per-cell `neighbors` vecs are kept as the canonical (and
+    /// serialized) form; read the packed lists via [`neighbors_csr`](Self::neighbors_csr).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// planet.build_neighbor_csr();
+    /// println!("Cell 0 has {} neighbors", planet.neighbors_csr(0).len());
+    /// ```
+    pub fn build_neighbor_csr(&mut self) {
+        let (offsets, data) = self.adjacency_buffers();
+        self.neighbor_offsets = offsets;
+        self.neighbor_data = data;
+    }
+
+    /// Export the whole neighbor graph as flat CSR buffers for GPU upload
+    ///
+    /// Returns `(offsets, neighbor_ids)`, both plain `u32` arrays that can be
+    /// copied straight into storage buffers:
+    ///
+    /// - `offsets` has `cell_count + 1` entries, starting at 0 and ending at
+    ///   `neighbor_ids.len()`
+    /// - the neighbors of cell `i` are `neighbor_ids[offsets[i]..offsets[i + 1]]`,
+    ///   in the same order as [`VoronoiCell::neighbors`]
+    ///
+    /// A shader can bound its per-cell loops with [`max_degree`](Self::max_degree).
+    /// Unlike [`build_neighbor_csr`](Self::build_neighbor_csr) this does not
+    /// touch the planet and always reflects the current neighbor lists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let (offsets, neighbor_ids) = planet.adjacency_buffers();
+    /// let first = &neighbor_ids[offsets[0] as usize..offsets[1] as usize];
+    /// assert_eq!(first.len(), planet.get_neighbors(0).len());
+    /// ```
+    pub fn adjacency_buffers(&self) -> (Vec<u32>, Vec<u32>) {
+        let total: usize = self.cells.iter().map(|c| c.neighbors.len()).sum();
+        let mut offsets = Vec::with_capacity(self.cells.len() + 1);
+        let mut neighbor_ids = Vec::with_capacity(total);
+
+        offsets.push(0);
+        for cell in &self.cells {
+            neighbor_ids.extend(cell.neighbors.iter().map(|&n| n as u32));
+            offsets.push(neighbor_ids.len() as u32);
+        }
+
+        (offsets, neighbor_ids)
+    }
+
+    /// Largest number of neighbors of any cell (0 for an empty planet)
+    pub fn max_degree(&self) -> usize {
+        self.cells.iter().map(|c| c.neighbors.len()).max().unwrap_or(0)
+    }
+
+    /// Get neighbor IDs for a cell from the packed CSR layout
+    ///
+    /// Returns an empty slice if the cell ID is invalid or
+    /// [`build_neighbor_csr`](Self::build_neighbor_csr) hasn't been called.
+    #[inline]
+    pub fn neighbors_csr(&self, cell_id: usize) -> &[u32] {
+        match (self.neighbor_offsets.get(cell_id), self.neighbor_offsets.get(cell_id + 1)) {
+            (Some(&start), Some(&end)) => &self.neighbor_data[start as usize..end as usize],
+            _ => &[],
+        }
+    }
+
+    /// Create an attribute side-table with one default value per cell
+    ///
+    /// Use this for per-cell gameplay data that doesn't belong in the terrain
+    /// type (owner, resources, temperature, ...). The table is indexed by cell ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let mut population = planet.with_attributes::<u32>();
+    /// population[0] = 1_000;
+    /// ```
+    pub fn with_attributes<A: Default + Clone>(&self) -> CellAttributes<A> {
+        CellAttributes::new(self.cells.len())
+    }
+
+    /// Get neighbor IDs for a cell
+    ///
+    /// Returns a slice of cell IDs that are adjacent to the given cell.
+    /// Returns empty slice if cell ID is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell_id` - Cell ID to query
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let neighbors = planet.get_neighbors(0);
+    /// println!("Cell 0 has {} neighbors", neighbors.len());
+    /// ```
+    pub fn get_neighbors(&self, cell_id: usize) -> &[usize] {
+        self.cells
+            .get(cell_id)
+            .map(|c| c.neighbors.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Predict the triangle count of a default fan mesh without building it
+    ///
+    /// Each cell's fan produces one triangle per polygon vertex; cells with
+    /// fewer than 3 vertices are skipped by the mesher and not counted. Useful
+    /// for LOD planning, e.g. deciding whether a planet needs subdividing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// println!("Mesh will have {} triangles", planet.estimated_triangle_count());
+    /// ```
+    pub fn estimated_triangle_count(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|c| c.vertex_count())
+            .filter(|&n| n >= 3)
+            .sum()
+    }
+
+    /// Predict the vertex count of a default fan mesh without building it
+    ///
+    /// Each cell contributes its polygon vertices plus the center.
+    pub fn estimated_vertex_count(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|c| c.vertex_count())
+            .filter(|&n| n >= 3)
+            .map(|n| n + 1)
+            .sum()
+    }
+
+    /// Break down mesh triangles and surface area by terrain type
+    ///
+    /// Groups cells by terrain and sums their triangle count in the default
+    /// fan mesh (as in [`estimated_triangle_count`](Self::estimated_triangle_count))
+    /// and their spherical area. Shows where rendering cost goes, e.g. whether
+    /// a large, flat ocean deserves its own LOD. Terrains without cells are
+    /// absent from the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// for (terrain, stats) in planet.render_stats() {
+    ///     println!("{:?}: {} triangles, area {:.1}", terrain, stats.triangle_count, stats.spherical_area);
+    /// }
+    /// ```
+    pub fn render_stats(&self) -> HashMap<T, RenderStats>
+    where
+        T: Eq + Hash,
+    {
+        let mut stats: HashMap<T, RenderStats> = HashMap::new();
+        for cell in &self.cells {
+            let entry = stats.entry(cell.terrain.clone()).or_default();
+            entry.cell_count += 1;
+            if cell.vertex_count() >= 3 {
+                entry.triangle_count += cell.vertex_count();
+            }
+            entry.spherical_area += cell.spherical_area(self.radius);
+        }
+        stats
+    }
+
+    /// List cells with fewer than `min_neighbors` neighbors
+    ///
+    /// Cells with very few neighbors (typically 3, near poles or with little
+    /// Lloyd relaxation) are thin slivers that cause rendering and pathfinding
+    /// artifacts. Use this diagnostic to detect problem areas; if it reports
+    /// cells, increase `lloyd_iterations` or switch to `PointDistribution::Fibonacci`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_neighbors` - Minimum acceptable neighbor count (4 flags triangular cells)
+    ///
+    /// # Returns
+    ///
+    /// IDs of cells below the threshold, in ascending order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let slivers = planet.degenerate_cells(4);
+    /// println!("{} cells have only 3 neighbors", slivers.len());
+    /// ```
+    pub fn degenerate_cells(&self, min_neighbors: usize) -> Vec<usize> {
+        self.cells
+            .iter()
+            .filter(|c| c.neighbor_count() < min_neighbors)
+            .map(|c| c.id)
+            .collect()
+    }
+
+    /// Compute cell area statistics for judging distribution uniformity
+    ///
+    /// Uses exact spherical cell areas. See [`UniformityReport`] for the fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let report = planet.uniformity_report();
+    /// println!("Areas range {:.3}..{:.3}", report.min_area, report.max_area);
+    /// ```
+    pub fn uniformity_report(&self) -> UniformityReport {
+        let areas: Vec<f32> = self
+            .cells
+            .iter()
+            .map(|c| c.spherical_area(self.radius))
+            .collect();
+        UniformityReport::from_areas(&areas)
+    }
+
+    /// Get the coefficient of variation of cell areas (lower is more uniform)
+    ///
+    /// Shorthand for `uniformity_report().coefficient_of_variation`. Useful for
+    /// empirically choosing the number of Lloyd iterations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// println!("Area CV: {:.3}", planet.uniformity());
+    /// ```
+    pub fn uniformity(&self) -> f32 {
+        self.uniformity_report().coefficient_of_variation
+    }
+
+    /// Get the length of the mean cell center direction
+    ///
+    /// Averages the unit vectors from the origin to every cell center. A
+    /// balanced distribution gives a value near 0; points clustered on one
+    /// side push it towards 1. A cheap sanity check for generation tuning.
+    /// Returns 0.0 for a planet without cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// assert!(planet.center_of_mass_offset() < 0.05);
+    /// ```
+    pub fn center_of_mass_offset(&self) -> f32 {
+        if self.cells.is_empty() {
+            return 0.0;
+        }
+        let sum: Vec3 = self.cells.iter().map(|c| c.center.normalize_or_zero()).sum();
+        (sum / self.cells.len() as f32).length()
+    }
+
+    /// Determine which cells face the sun
+    ///
+    /// A cell is lit when its center points towards `sun_dir`, i.e.
+    /// `center.normalize().dot(sun_dir) > 0`. `sun_dir` does not need to be
+    /// normalized.
+    ///
+    /// # Returns
+    ///
+    /// One entry per cell, indexed by cell ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use glam::Vec3;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let lit = planet.lit_cells(Vec3::X);
+    /// let day_side: Vec<usize> = (0..lit.len()).filter(|&id| lit[id]).collect();
+    ///
+    /// // Dim the night side
+    /// let night: TerrainColor = [0.05, 0.05, 0.1, 1.0];
+    /// let mesh = generate_mesh_with_visibility(&planet, &BasicColorMapper, Some(&day_side), night);
+    /// ```
+    pub fn lit_cells(&self, sun_dir: Vec3) -> Vec<bool> {
+        self.cells
+            .iter()
+            .map(|c| c.center.normalize().dot(sun_dir) > 0.0)
+            .collect()
+    }
+
+    /// Find the cells along the day/night boundary
+    ///
+    /// Returns the IDs of lit cells (see [`lit_cells`](Self::lit_cells)) that
+    /// have at least one unlit neighbor, in ascending order.
+    pub fn terminator_cells(&self, sun_dir: Vec3) -> Vec<usize> {
+        let lit = self.lit_cells(sun_dir);
+
+        self.cells
+            .iter()
+            .filter(|c| lit[c.id] && c.neighbors.iter().any(|&n| !lit[n]))
+            .map(|c| c.id)
+            .collect()
+    }
+
+    /// Find cells whose centers project into a screen-space rectangle
+    ///
+    /// Each cell center is transformed by `view_proj`; cells behind the camera
+    /// (clip `w <= 0`) are skipped, the rest are kept if their normalized device
+    /// coordinates fall inside `rect = [min_x, min_y, max_x, max_y]`. Use
+    /// `[-1.0, -1.0, 1.0, 1.0]` for the whole screen. Cells on the far side of
+    /// the planet are not occluded; filter with the camera direction if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use glam::{Mat4, Vec3};
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let eye = Vec3::new(0.0, 0.0, planet.radius() * 3.0);
+    /// let view = Mat4::look_at_rh(eye, Vec3::ZERO, Vec3::Y);
+    /// let proj = Mat4::perspective_rh(1.0, 16.0 / 9.0, 0.1, 1000.0);
+    ///
+    /// // Box selection over the center of the screen
+    /// let selected = planet.cells_in_frustum(proj * view, [-0.2, -0.2, 0.2, 0.2]);
+    /// ```
+    pub fn cells_in_frustum(&self, view_proj: Mat4, rect: [f32; 4]) -> Vec<usize> {
+        let [min_x, min_y, max_x, max_y] = rect;
+
+        self.cells
+            .iter()
+            .filter(|cell| {
+                let clip = view_proj * cell.center.extend(1.0);
+                if clip.w <= 0.0 {
+                    return false;
+                }
+                let (x, y) = (clip.x / clip.w, clip.y / clip.w);
+                (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y)
+            })
+            .map(|cell| cell.id)
+            .collect()
+    }
+
+    /// Get the axis-aligned bounding box of the planet as `(min, max)`
+    ///
+    /// Computed from the actual cell centers and vertices, so it also covers
+    /// displaced geometry (see [`generate_displaced`](Self::generate_displaced)).
+    /// For a plain planet it is close to `±radius` on every axis, slightly
+    /// inside where no vertex sits exactly on an axis. Returns zeros for a
+    /// planet without cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let (min, max) = planet.aabb();
+    /// let camera_distance = (max - min).length() * 1.5;
+    /// ```
+    pub fn aabb(&self) -> (Vec3, Vec3) {
+        let mut points = self.cells.iter().flat_map(|c| std::iter::once(c.center).chain(c.vertices.iter().copied()));
+        let Some(first) = points.next() else {
+            return (Vec3::ZERO, Vec3::ZERO);
+        };
+        points.fold((first, first), |(min, max), p| (min.min(p), max.max(p)))
+    }
+
+    /// Interpolate along the great circle between two surface positions
+    ///
+    /// Returns the point at fraction `t` of the shorter arc from `a` to `b`,
+    /// scaled to the planet radius, for flight paths, arc queries and camera
+    /// tours. `a` and `b` only need the right direction; their lengths are
+    /// ignored. Values of `t` outside `[0, 1]` continue along the same circle.
+    ///
+    /// For (nearly) antipodal points every great circle through them is
+    /// equally short, so a fixed perpendicular one is picked; the result stays
+    /// on the sphere and moves smoothly with `t`. A zero vector is treated as
+    /// pointing along +X.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let start = planet.get_cell(0).unwrap().center;
+    /// let end = planet.get_cell(1).unwrap().center;
+    /// let path: Vec<Vec3> = (0..=10).map(|i| planet.slerp_surface(start, end, i as f32 / 10.0)).collect();
+    /// ```
+    pub fn slerp_surface(&self, a: Vec3, b: Vec3, t: f32) -> Vec3 {
+        let from = a.try_normalize().unwrap_or(Vec3::X);
+        let to = b.try_normalize().unwrap_or(Vec3::X);
+
+        let cross = from.cross(to);
+        let angle = cross.length().atan2(from.dot(to));
+        let axis = match cross.try_normalize() {
+            Some(axis) if angle < std::f32::consts::PI - 1e-4 => axis,
+            // Antipodal (or identical, where the axis does not matter)
+            _ => tangent_basis(from).0,
+        };
+
+        let (sin, cos) = (angle * t).sin_cos();
+        (from * cos + axis.cross(from) * sin).normalize() * self.radius
+    }
+
+    /// Check whether a point lies on the planet surface
+    ///
+    /// The tolerance is relative to the radius: the point passes when
+    /// `|p.length() - radius| < rel_eps * radius`, so the same `rel_eps` works
+    /// for tiny and huge planets alike. `1e-4` suits `f32` geometry.
+    #[inline]
+    pub fn on_surface(&self, p: Vec3, rel_eps: f32) -> bool {
+        (p.length() - self.radius).abs() < rel_eps * self.radius
+    }
+
+    /// Check that all cell centers and vertices lie on the planet surface
+    ///
+    /// Uses [`on_surface`](Self::on_surface) with the given relative tolerance.
+    /// Useful after building a planet from external data or custom cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VoronoiError::GenerationFailed` naming the first offending cell.
+    pub fn validate(&self, rel_eps: f32) -> Result<()> {
+        for cell in &self.cells {
+            let off_surface = std::iter::once(&cell.center)
+                .chain(&cell.vertices)
+                .find(|p| !self.on_surface(**p, rel_eps));
+
+            if let Some(p) = off_surface {
+                return Err(VoronoiError::GenerationFailed(format!(
+                    "cell {} has point {:?} off the sphere of radius {}",
+                    cell.id, p, self.radius
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Make the neighbor graph symmetric
+    ///
+    /// Generated planets always have symmetric neighbors, but cells imported
+    /// through [`from_raw_cells`](Self::from_raw_cells) or edited by hand may
+    /// list B as a neighbor of A without the reverse, which silently breaks
+    /// pathfinding and flood fills. For every such one-sided link A→B this
+    /// either appends A to B's neighbors or removes B from A's, depending on
+    /// `mode`. Entries that point at the cell itself or at an invalid ID are
+    /// always removed.
+    ///
+    /// Returns the number of links added or removed (0 if the graph was
+    /// already symmetric). When anything changed, precomputed edge costs and
+    /// the packed CSR neighbor layout are cleared; call
+    /// [`precompute_edge_costs`](Self::precompute_edge_costs) or
+    /// [`build_neighbor_csr`](Self::build_neighbor_csr) again if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let mut planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let fixed = planet.repair_neighbor_symmetry(NeighborRepair::AddMissing);
+    /// assert_eq!(fixed, 0);
+    /// ```
+    pub fn repair_neighbor_symmetry(&mut self, mode: NeighborRepair) -> usize {
+        let cell_count = self.cells.len();
+        let mut fixed = 0;
+
+        for cell in &mut self.cells {
+            let id = cell.id;
+            let before = cell.neighbors.len();
+            cell.neighbors.retain(|&n| n != id && n < cell_count);
+            fixed += before - cell.neighbors.len();
+        }
+
+        // A link listed twice on one side is still repaired once
+        let mut one_sided: Vec<(usize, usize)> = self
+            .cells
+            .iter()
+            .flat_map(|cell| cell.neighbors.iter().map(move |&n| (cell.id, n)))
+            .filter(|&(a, b)| !self.cells[b].neighbors.contains(&a))
+            .collect();
+        one_sided.sort_unstable();
+        one_sided.dedup();
+
+        for &(a, b) in &one_sided {
+            match mode {
+                NeighborRepair::AddMissing => self.cells[b].neighbors.push(a),
+                NeighborRepair::RemoveDangling => self.cells[a].neighbors.retain(|&n| n != b),
+            }
+        }
+        fixed += one_sided.len();
+
+        if fixed > 0 {
+            self.edge_costs.clear();
+            self.neighbor_offsets.clear();
+            self.neighbor_data.clear();
+        }
+        fixed
+    }
+
+    /// Get the boundary edge shared by two neighboring cells
+    ///
+    /// # Returns
+    ///
+    /// The two Voronoi vertices at the ends of the shared edge, or `None` if the
+    /// cells are not neighbors (or either ID is invalid).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let neighbor = planet.get_neighbors(0)[0];
+    /// if let Some((a, b)) = planet.shared_edge(0, neighbor) {
+    ///     println!("Border runs from {:?} to {:?}", a, b);
+    /// }
+    /// ```
+    pub fn shared_edge(&self, a: usize, b: usize) -> Option<(Vec3, Vec3)> {
+        let cell_a = self.cells.get(a)?;
+        let cell_b = self.cells.get(b)?;
+        if !cell_a.is_neighbor_of(b) {
+            return None;
+        }
+
+        let mut shared = cell_a
+            .vertices
+            .iter()
+            .filter(|&&va| cell_b.vertices.iter().any(|&vb| self.same_corner(va, vb)));
+
+        match (shared.next(), shared.next()) {
+            (Some(&v0), Some(&v1)) => Some((v0, v1)),
+            _ => None,
+        }
+    }
+
+    /// Get the cells meeting at a Voronoi corner
+    ///
+    /// Snaps `vertex` to the closest cell corner within `radius * 1e-4` and
+    /// returns the IDs, sorted, of every cell that has that exact corner;
+    /// normally three, the dual of a Delaunay triangle. Handy for river routing
+    /// and physics along borders. Returns an empty list if `vertex` is not a
+    /// cell corner.
+    ///
+    /// Corners are matched by identity rather than by distance, so two distinct
+    /// corners lying close together (common with near-cocircular seeds) never
+    /// pull in cells that don't actually touch. Only the cell nearest to
+    /// `vertex` and the two rings of cells around it are checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let corner = planet.get_cell(0).unwrap().vertices[0];
+    /// let cells = planet.cells_at_vertex(corner);
+    /// assert!(cells.contains(&0));
+    /// ```
+    pub fn cells_at_vertex(&self, vertex: Vec3) -> Vec<usize> {
+        let Some(nearest) = self.nearest_cell(vertex) else {
+            return Vec::new();
+        };
+
+        // The nearest center may belong to a cell just past the corner when
+        // seeds are near-cocircular, so look one ring further out
+        let mut candidates: Vec<usize> = std::iter::once(nearest)
+            .chain(self.cells[nearest].neighbors.iter().flat_map(|&nb| {
+                std::iter::once(nb).chain(self.cells[nb].neighbors.iter().copied())
+            }))
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let corner = candidates
+            .iter()
+            .flat_map(|&id| self.cells[id].vertices.iter().copied())
+            .min_by(|a, b| a.distance_squared(vertex).total_cmp(&b.distance_squared(vertex)));
+        let Some(corner) = corner.filter(|c| c.distance(vertex) < self.radius * 1e-4) else {
+            return Vec::new();
+        };
+
+        candidates.retain(|&id| self.cells[id].vertices.iter().any(|&v| self.same_corner(v, corner)));
+        candidates
+    }
+
+    /// Get the midpoint of every shared cell edge
+    ///
+    /// Returns one `(a, b, midpoint)` entry per unique neighbor pair with
+    /// `a < b`, where `midpoint` is the middle of the shared edge projected onto
+    /// the sphere. Connecting these points gives a road or river network that
+    /// runs along cell borders instead of through cell centers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// for (a, b, midpoint) in planet.edge_midpoints().iter().take(3) {
+    ///     println!("Border node between {} and {} at {:?}", a, b, midpoint);
+    /// }
+    /// ```
+    pub fn edge_midpoints(&self) -> Vec<(usize, usize, Vec3)> {
+        let mut midpoints = Vec::new();
+
+        for cell in &self.cells {
+            for &neighbor in cell.neighbors.iter().filter(|&&n| n > cell.id) {
+                if let Some((v0, v1)) = self.shared_edge(cell.id, neighbor) {
+                    let midpoint = (v0 + v1).normalize_or_zero() * self.radius;
+                    midpoints.push((cell.id, neighbor, midpoint));
+                }
+            }
+        }
+
+        midpoints
+    }
+
+    /// Export the neighbor graph in Graphviz DOT format
+    ///
+    /// Writes one node per cell, labeled with `label_fn`, and each undirected
+    /// neighbor edge once. The output grows with the whole planet (tens of
+    /// thousands of nodes for `Large`), so it is mainly useful for `Tiny`
+    /// planets; use [`to_dot_subset`](Self::to_dot_subset) for a region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let dot = planet.to_dot(|cell| format!("{:?}", cell.terrain));
+    /// // std::fs::write("planet.dot", dot).unwrap();
+    /// ```
+    pub fn to_dot(&self, label_fn: impl Fn(&VoronoiCell<T>) -> String) -> String {
+        let all: Vec<usize> = (0..self.cells.len()).collect();
+        self.to_dot_subset(&all, label_fn)
+    }
+
+    /// Export the neighbor graph of a subset of cells in Graphviz DOT format
+    ///
+    /// Only edges between two listed cells are written. Invalid IDs are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let region = planet.find_cells_within_radius(0, 3);
+    /// let dot = planet.to_dot_subset(&region, |cell| cell.id.to_string());
+    /// ```
+    pub fn to_dot_subset(&self, cells: &[usize], label_fn: impl Fn(&VoronoiCell<T>) -> String) -> String {
+        use std::fmt::Write;
+
+        let mut included: Vec<usize> = cells.iter().copied().filter(|&id| id < self.cells.len()).collect();
+        included.sort_unstable();
+        included.dedup();
+        let selected: HashSet<usize> = included.iter().copied().collect();
+
+        let mut dot = String::from("graph planet {\n");
+        for &id in &included {
+            let label = label_fn(&self.cells[id]).replace('\\', "\\\\").replace('"', "\\\"");
+            // Writing to a String cannot fail
+            let _ = writeln!(dot, "    {} [label=\"{}\"];", id, label);
+        }
+        for &id in &included {
+            for &neighbor in &self.cells[id].neighbors {
+                if id < neighbor && selected.contains(&neighbor) {
+                    let _ = writeln!(dot, "    {} -- {};", id, neighbor);
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Find the spherical centroid of all cells matching a predicate
+    ///
+    /// Averages the unit directions of the matching cell centers and
+    /// normalizes the result, giving a point to aim a camera at. Returns
+    /// `None` if no cell matches or the directions cancel out (e.g. two
+    /// antipodal cells), where no meaningful center exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// if let Some(dir) = planet.centroid_of(|c| c.terrain == BasicTerrainType::Mountain) {
+    ///     let camera_target = dir * planet.radius();
+    ///     println!("Look at {:?}", camera_target);
+    /// }
+    /// ```
+    pub fn centroid_of(&self, pred: impl Fn(&VoronoiCell<T>) -> bool) -> Option<Vec3> {
+        let (sum, count) = self
+            .cells
+            .iter()
+            .filter(|c| pred(c))
+            .fold((Vec3::ZERO, 0usize), |(sum, count), c| (sum + c.center.normalize(), count + 1));
+
+        // Mean length near zero: the directions are spread around the sphere
+        if count == 0 || sum.length() < 1e-4 * count as f32 {
+            return None;
+        }
+        Some(sum.normalize())
+    }
+
+    /// Get a spherical cap enclosing the centers of a set of cells
+    ///
+    /// Returns `(axis, half_angle)`: the axis is the normalized mean direction
+    /// of the cell centers and `half_angle` (radians) the largest angle between
+    /// it and any of them. This is close to, but not always exactly, the
+    /// smallest enclosing cap. Only centers are covered; add about one cell
+    /// radius to include the cell boundaries. Invalid IDs are ignored, and
+    /// `None` is returned if no valid cell is given. If the directions cancel
+    /// out, the first cell's direction is used as the axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let selection = planet.find_cells_within_radius(0, 3);
+    /// let (axis, half_angle) = planet.bounding_cap(&selection).unwrap();
+    /// let arc_radius = half_angle * planet.radius();
+    /// println!("Frame {:?} with {:.1} units of surface radius", axis, arc_radius);
+    /// ```
+    pub fn bounding_cap(&self, cells: &[usize]) -> Option<(Vec3, f32)> {
+        let directions: Vec<Vec3> = cells
+            .iter()
+            .filter_map(|&id| self.cells.get(id))
+            .map(|c| c.center.normalize())
+            .collect();
+        let first = *directions.first()?;
+
+        let sum: Vec3 = directions.iter().sum();
+        let axis = if sum.length() < 1e-4 * directions.len() as f32 {
+            first
+        } else {
+            sum.normalize()
+        };
+
+        let half_angle = directions
+            .iter()
+            .map(|&d| arc_length(axis, d, 1.0))
+            .fold(0.0, f32::max);
+        Some((axis, half_angle))
+    }
+
+    /// Calculate the total spherical area of a group of cells
+    ///
+    /// Useful for territories and regions. Invalid IDs are ignored; duplicate
+    /// IDs are counted once per occurrence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let territory = planet.find_cells_within_radius(0, 2);
+    /// println!("Territory area: {:.2}", planet.region_area(&territory));
+    /// ```
+    pub fn region_area(&self, cells: &[usize]) -> f32 {
+        cells
+            .iter()
+            .filter_map(|&id| self.cells.get(id))
+            .map(|c| c.spherical_area(self.radius))
+            .sum()
+    }
+
+    /// Total surface area of all cells
+    ///
+    /// Sums the spherical cell areas, which tile the sphere exactly, so this
+    /// is `4πr²` up to floating-point error. Meant for gameplay scaling (e.g.
+    /// gravity by planet size), not for scientific use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let r = planet.radius();
+    /// let area = planet.surface_area();
+    /// assert!((area - 4.0 * std::f32::consts::PI * r * r).abs() / area < 1e-3);
+    /// ```
+    pub fn surface_area(&self) -> f32 {
+        self.cells.iter().map(|c| c.spherical_area(self.radius)).sum()
+    }
+
+    /// Approximate volume enclosed by the surface raised by an elevation field
+    ///
+    /// Treats each cell as a cone from the planet center with the cell's solid
+    /// angle, extended to `radius + elevation`, contributing
+    /// `area / r² * (r + elevation)³ / 3`. With zero elevation everywhere this
+    /// is the sphere volume `4πr³/3`. Slopes between cells are ignored, so this
+    /// is an approximation for gameplay (mass, gravity), not science.
+    ///
+    /// # Arguments
+    ///
+    /// * `elevation` - Offset from the base radius per cell, indexed by cell ID
+    ///   (missing entries count as 0). Values below `-radius` count as 0 volume.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let flat = planet.estimated_volume(&[]);
+    /// let raised = planet.estimated_volume(&vec![0.5; planet.cell_count()]);
+    /// assert!(raised > flat);
+    /// ```
+    pub fn estimated_volume(&self, elevation: &[f32]) -> f32 {
+        let r2 = self.radius * self.radius;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(id, c)| {
+                let height = (self.radius + elevation.get(id).copied().unwrap_or(0.0)).max(0.0);
+                c.spherical_area(self.radius) / r2 * height.powi(3) / 3.0
+            })
+            .sum()
+    }
+
+    /// Get the latitude/longitude extent of a group of cells (degrees)
+    ///
+    /// Combines the vertices of all member cells, handling antimeridian
+    /// wrapping and poles like [`VoronoiCell::lat_lon_bounds`]. Invalid IDs are
+    /// ignored; returns `None` if no valid cell is given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let territory = planet.find_cells_within_radius(0, 3);
+    /// if let Some(bounds) = planet.region_lat_lon_bounds(&territory) {
+    ///     println!("Label at lat {:.1}", (bounds.min_lat + bounds.max_lat) / 2.0);
+    /// }
+    /// ```
+    pub fn region_lat_lon_bounds(&self, cells: &[usize]) -> Option<LatLonBounds> {
+        let members: Vec<&VoronoiCell<T>> = cells.iter().filter_map(|&id| self.cells.get(id)).collect();
+        let north = members.iter().any(|c| c.contains_direction(Vec3::Y));
+        let south = members.iter().any(|c| c.contains_direction(Vec3::NEG_Y));
+        let points = members.iter().flat_map(|c| c.vertices.iter().copied());
+
+        LatLonBounds::from_points(points, north, south)
+    }
+
+    /// Find explored cells that border unexplored ones
+    ///
+    /// Returns every cell in `explored` with at least one neighbor outside
+    /// it, sorted by the number of unexplored neighbors (most first, ties by
+    /// cell ID). These are the targets of frontier-based exploration: the
+    /// first entries open up the most unknown territory. Invalid IDs are
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use std::collections::HashSet;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let explored: HashSet<usize> = planet.find_cells_within_radius(0, 2).into_iter().collect();
+    /// if let Some(&next) = planet.frontier_cells(&explored).first() {
+    ///     println!("Scout from cell {}", next);
+    /// }
+    /// ```
+    pub fn frontier_cells(&self, explored: &HashSet<usize>) -> Vec<usize> {
+        let mut frontier: Vec<(usize, usize)> = explored
+            .iter()
+            .filter_map(|&id| self.cells.get(id))
+            .map(|cell| {
+                let unexplored = cell.neighbors.iter().filter(|n| !explored.contains(n)).count();
+                (cell.id, unexplored)
+            })
+            .filter(|&(_, unexplored)| unexplored > 0)
+            .collect();
+
+        frontier.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        frontier.into_iter().map(|(id, _)| id).collect()
+    }
+
+    /// Calculate the border length of a group of cells
+    ///
+    /// Sums the great-circle lengths of all cell edges whose other side lies
+    /// outside the region. Interior edges between two member cells are not
+    /// counted. Invalid IDs are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use std::collections::HashSet;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let territory: HashSet<usize> = planet.find_cells_within_radius(0, 2).into_iter().collect();
+    /// println!("Border length: {:.2}", planet.region_perimeter(&territory));
+    /// ```
+    pub fn region_perimeter(&self, cells: &HashSet<usize>) -> f32 {
+        self.region_boundary_edges(cells)
+            .into_iter()
+            .map(|(v0, v1)| arc_length(v0, v1, self.radius))
+            .sum()
+    }
+
+    /// Trace the border of a group of cells as closed loops
+    ///
+    /// Every cell edge whose other side lies outside the region is stitched
+    /// with its successors into loops of boundary vertex positions, one per
+    /// contiguous border: a region with a hole yields an outer loop plus one
+    /// for the hole. Loops run counter-clockwise around the region seen from
+    /// outside the planet (the region is on the left), and the first vertex is
+    /// not repeated at the end. Invalid IDs are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use std::collections::HashSet;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let territory: HashSet<usize> = planet.find_cells_within_radius(0, 2).into_iter().collect();
+    /// let outlines = planet.region_outlines(&territory);
+    /// assert_eq!(outlines.len(), 1);
+    /// ```
+    pub fn region_outlines(&self, cells: &HashSet<usize>) -> Vec<Vec<Vec3>> {
+        let edges = self.region_boundary_edges(cells);
+
+        // Match endpoints on a grid the size of the corner tolerance and check
+        // the neighboring grid cells too
+        let tolerance = self.corner_tolerance();
+        let key = |v: Vec3| (v / tolerance).round().as_ivec3();
+        let mut by_start: HashMap<IVec3, Vec<usize>> = HashMap::new();
+        for (i, (start, _)) in edges.iter().enumerate() {
+            by_start.entry(key(*start)).or_default().push(i);
+        }
+
+        let mut used = vec![false; edges.len()];
+        let next_edge = |end: Vec3, used: &[bool]| {
+            let center = key(end);
+            (-1..=1)
+                .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| center + IVec3::new(x, y, z))))
+                .filter_map(|k| by_start.get(&k))
+                .flatten()
+                .copied()
+                .find(|&i| !used[i] && self.same_corner(edges[i].0, end))
+        };
+
+        let mut loops = Vec::new();
+        for first in 0..edges.len() {
+            if used[first] {
+                continue;
+            }
+            used[first] = true;
+
+            let mut outline = vec![edges[first].0];
+            let mut current = first;
+            loop {
+                let end = edges[current].1;
+                if self.same_corner(end, edges[first].0) {
+                    break;
+                }
+                outline.push(end);
+                match next_edge(end, &used) {
+                    Some(next) => {
+                        used[next] = true;
+                        current = next;
+                    }
+                    None => break,
+                }
+            }
+            loops.push(outline);
+        }
+
+        loops
+    }
+
+    /// Collect the region's border edges, oriented counter-clockwise around it
+    ///
+    /// Cells are visited in ID order so the output is deterministic.
+    fn region_boundary_edges(&self, cells: &HashSet<usize>) -> Vec<(Vec3, Vec3)> {
+        let mut ids: Vec<usize> = cells.iter().copied().filter(|&id| id < self.cells.len()).collect();
+        ids.sort_unstable();
+
+        let mut edges = Vec::new();
+        for cell in ids.into_iter().map(|id| &self.cells[id]) {
+            let n = cell.vertices.len();
+            for i in 0..n {
+                let v0 = cell.vertices[i];
+                let v1 = cell.vertices[(i + 1) % n];
+
+                // The neighbor across this edge shares both of its endpoints
+                let across = cell.neighbors.iter().copied().find(|&nb| {
+                    let other = &self.cells[nb].vertices;
+                    other.iter().any(|&v| self.same_corner(v, v0))
+                        && other.iter().any(|&v| self.same_corner(v, v1))
+                });
+
+                if across.is_none_or(|nb| !cells.contains(&nb)) {
+                    edges.push((v0, v1));
+                }
+            }
+        }
+
+        edges
+    }
+
+    /// Whether two cell corners are the same Voronoi vertex
+    ///
+    /// Each corner is computed once per Delaunay triangle and copied into every
+    /// cell around it, so shared corners are bit-identical, and whole-planet
+    /// transforms like [`rotate`](Self::rotate) keep them that way. The
+    /// tolerance only absorbs last-bit rounding and sits far below the
+    /// shortest edge, so distinct corners that lie close together never merge.
+    fn same_corner(&self, a: Vec3, b: Vec3) -> bool {
+        a.distance(b) <= self.corner_tolerance()
+    }
+
+    /// Distance below which [`same_corner`](Self::same_corner) treats corners as equal
+    fn corner_tolerance(&self) -> f32 {
+        self.radius * 1e-6
+    }
+
+    /// Find the cell containing a position (requires spatial-index feature)
+    ///
+    /// Uses KD-tree spatial index for O(log n) nearest-neighbor lookup.
+    /// This is essential for converting 3D positions (from raycasting, clicks, etc.)
+    /// into cell IDs. The lookup doesn't allocate, so it is safe to call many
+    /// times per frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - 3D position on sphere surface
+    ///
+    /// # Returns
+    ///
+    /// Cell ID of the nearest cell
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use glam::Vec3;
+    /// # #[cfg(feature = "spatial-index")]
+    /// # {
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let position = Vec3::new(planet.radius(), 0.0, 0.0);
+    /// let cell_id = planet.find_cell_at(position);
+    /// println!("Position is in cell {}", cell_id);
+    /// # }
+    /// ```
+    #[cfg(feature = "spatial-index")]
+    pub fn find_cell_at(&self, position: Vec3) -> usize {
+        self.spatial_index.find_nearest(position)
+    }
+
+    /// Find the cell at a position, or `None` if the position is off the surface
+    ///
+    /// Unlike [`find_cell_at`](Self::find_cell_at), which always returns the
+    /// nearest cell, this rejects queries whose nearest cell center is farther
+    /// than `max_distance` away. Use it to ignore clicks or rays that missed the
+    /// planet. A tolerance of about one cell diameter works well for surface hits.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - 3D position to query
+    /// * `max_distance` - Maximum allowed distance to the nearest cell center
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use glam::Vec3;
+    /// # #[cfg(feature = "spatial-index")]
+    /// # {
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let far_away = Vec3::new(planet.radius() * 10.0, 0.0, 0.0);
+    /// assert_eq!(planet.try_find_cell_at(far_away, 1.0), None);
+    /// # }
+    /// ```
+    #[cfg(feature = "spatial-index")]
+    pub fn try_find_cell_at(&self, position: Vec3, max_distance: f32) -> Option<usize> {
+        let (cell_id, distance) = self.spatial_index.find_nearest_with_distance(position);
+        (distance <= max_distance).then_some(cell_id)
+    }
+
+    /// Get the spatial index behind [`find_cell_at`](Self::find_cell_at)
+    ///
+    /// Gives direct access to the KD-tree over cell centers for custom
+    /// queries such as [`SpatialIndex::find_k_nearest`],
+    /// [`SpatialIndex::find_within`] or batched lookups, without building a
+    /// second tree from [`cells`](Self::cells). Item IDs are cell IDs. The
+    /// index follows geometry edits like [`rotate`](Self::rotate). Returns
+    /// `None` for a planet without cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let index = planet.spatial_index().unwrap();
+    /// let around = index.find_k_nearest(planet.cells()[0].center, 7);
+    /// assert_eq!(around[0].0, 0);
+    /// ```
+    #[cfg(feature = "spatial-index")]
+    pub fn spatial_index(&self) -> Option<&SpatialIndex> {
+        if self.cells.is_empty() {
+            None
+        } else {
+            Some(&self.spatial_index)
+        }
+    }
+
+    /// Find the nearest cell, using the spatial index when available
+    fn nearest_cell(&self, position: Vec3) -> Option<usize> {
+        if self.cells.is_empty() {
+            return None;
+        }
+
+        #[cfg(feature = "spatial-index")]
+        {
+            Some(self.spatial_index.find_nearest(position))
+        }
+
+        #[cfg(not(feature = "spatial-index"))]
+        {
+            self.cells
+                .iter()
+                .min_by(|a, b| {
+                    a.center
+                        .distance_squared(position)
+                        .total_cmp(&b.center.distance_squared(position))
+                })
+                .map(|c| c.id)
+        }
+    }
+
+    /// Classify every cell as a peak, pit, slope or flat relative to its neighbors
+    ///
+    /// Comparisons are strict: a cell is a [`Relief::Peak`] only if it is
+    /// higher than all of its neighbors, so a level plateau is not a peak.
+    /// Cells that are level with some neighbors and higher (or lower) than the
+    /// rest count as [`Relief::Slope`].
+    ///
+    /// # Arguments
+    ///
+    /// * `elevation` - One value per cell, indexed by cell ID (missing entries count as 0)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let heights: Vec<f32> = planet.cells().iter().map(|c| c.center.y).collect();
+    /// let relief = planet.relative_relief(&heights);
+    /// let peaks = relief.iter().filter(|&&r| r == Relief::Peak).count();
+    /// println!("{} local maxima", peaks);
+    /// ```
+    pub fn relative_relief(&self, elevation: &[f32]) -> Vec<Relief> {
+        let value = |id: usize| elevation.get(id).copied().unwrap_or(0.0);
+
+        self.cells
+            .iter()
+            .map(|cell| {
+                let own = value(cell.id);
+                let (mut higher, mut lower) = (false, false);
+                for &n in &cell.neighbors {
+                    let other = value(n);
+                    higher |= own > other;
+                    lower |= own < other;
+                }
+                let level = cell.neighbors.iter().any(|&n| value(n) == own);
+
+                match (higher, lower, level) {
+                    (true, false, false) => Relief::Peak,
+                    (false, true, false) => Relief::Pit,
+                    (false, false, _) => Relief::Flat,
+                    _ => Relief::Slope,
+                }
+            })
+            .collect()
+    }
+
+    /// Measure how much each cell faces into or away from the wind
+    ///
+    /// Returns the terrain slope along the wind direction at every cell:
+    /// positive where the ground rises downwind, so the cell faces into the
+    /// wind on an upslope (windward), and negative where it falls away, so the
+    /// cell lies in the lee of higher terrain upwind. The slope is the
+    /// elevation change per unit of arc distance to each neighbor, averaged
+    /// with weights by how well the neighbor lines up with the wind.
+    ///
+    /// The wind is projected onto each cell's tangent plane, so one global
+    /// direction sweeps around the sphere; the result scales with the length
+    /// of that projection and is 0 where the wind points straight up or down.
+    ///
+    /// # Arguments
+    ///
+    /// * `wind_dir` - Direction the wind blows towards (its length scales the result)
+    /// * `elevation` - One value per cell, indexed by cell ID (missing entries count as 0)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let heights: Vec<f32> = planet.cells().iter().map(|c| c.center.x).collect();
+    /// let exposure = planet.exposure(Vec3::Z, &heights);
+    /// let windward = exposure.iter().filter(|&&e| e > 0.0).count();
+    /// println!("{} windward cells", windward);
+    /// ```
+    pub fn exposure(&self, wind_dir: Vec3, elevation: &[f32]) -> Vec<f32> {
+        let value = |id: usize| elevation.get(id).copied().unwrap_or(0.0);
+
+        self.cells
+            .iter()
+            .map(|cell| {
+                let normal = cell.center.normalize_or_zero();
+                let wind = wind_dir - normal * wind_dir.dot(normal);
+                let Some(along) = wind.try_normalize() else {
+                    return 0.0;
+                };
+
+                let own = value(cell.id);
+                let (mut slope, mut weight) = (0.0, 0.0);
+                for &n in &cell.neighbors {
+                    let other = self.cells[n].center;
+                    let Some(direction) = (other - normal * other.dot(normal)).try_normalize() else {
+                        continue;
+                    };
+                    let distance = arc_length(cell.center, other, self.radius);
+                    if distance <= 0.0 {
+                        continue;
+                    }
+                    let alignment = direction.dot(along);
+                    slope += alignment * (value(n) - own) / distance;
+                    weight += alignment.abs();
+                }
+
+                if weight > 0.0 {
+                    slope / weight * wind.length()
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Interpolate per-cell values at a position using natural neighbor (Sibson) weights
+    ///
+    /// Each surrounding cell is weighted by the area the query point would
+    /// "steal" from it if inserted as a new cell. Unlike
+    /// [`barycentric_interpolate`](Self::barycentric_interpolate), the result is
+    /// smooth (C1) away from cell centers, which avoids faceted gradients when
+    /// visualizing scientific data. Reproduces the cell value exactly at a center.
+    ///
+    /// Weights are computed in the tangent plane at the query point, which is
+    /// accurate while cells are small compared to the radius.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Query position on the sphere surface
+    /// * `values` - One value per cell, indexed by cell ID (missing entries count as 0)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use glam::Vec3;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let heights: Vec<f32> = planet.cells().iter().map(|c| c.center.y).collect();
+    /// let h = planet.natural_neighbor_interpolate(Vec3::new(0.0, 0.0, planet.radius()), &heights);
+    /// ```
+    pub fn natural_neighbor_interpolate(&self, position: Vec3, values: &[f32]) -> f32 {
+        let Some(nearest) = self.nearest_cell(position) else {
+            return 0.0;
+        };
+
+        // The 2-ring of the nearest cell contains every natural neighbor
+        let mut candidates = self.find_cells_within_radius(nearest, 2);
+        candidates.sort_unstable();
+
+        let sites: Vec<Vec3> = candidates.iter().map(|&id| self.cells[id].center).collect();
+        let weights = sibson_weights(position, &sites);
+
+        candidates
+            .iter()
+            .zip(weights)
+            .map(|(&id, w)| w * values.get(id).copied().unwrap_or(0.0))
+            .sum()
+    }
+
+    /// Interpolate per-cell values at a position using barycentric weights
+    ///
+    /// Finds the Delaunay triangle of cell centers containing the position and
+    /// blends the three cell values linearly. Cheap, but only C0: gradients are
+    /// faceted along triangle edges. Falls back to the nearest cell's value if
+    /// no containing triangle is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Query position on the sphere surface
+    /// * `values` - One value per cell, indexed by cell ID (missing entries count as 0)
+    pub fn barycentric_interpolate(&self, position: Vec3, values: &[f32]) -> f32 {
+        let Some(nearest) = self.nearest_cell(position) else {
+            return 0.0;
+        };
+        let value = |id: usize| values.get(id).copied().unwrap_or(0.0);
+
+        let neighbors = self.get_neighbors(nearest);
+        for (i, &a) in neighbors.iter().enumerate() {
+            for &b in &neighbors[i + 1..] {
+                if !self.cells[a].is_neighbor_of(b) {
+                    continue;
+                }
+                let weights = barycentric_weights(
+                    position,
+                    self.cells[nearest].center,
+                    self.cells[a].center,
+                    self.cells[b].center,
+                );
+                if let Some([w0, w1, w2]) = weights {
+                    return w0 * value(nearest) + w1 * value(a) + w2 * value(b);
+                }
+            }
+        }
+
+        value(nearest)
+    }
+
+    /// Find cells within a given hop count from a center cell (BFS)
+    ///
+    /// Uses breadth-first search to find all cells reachable within the
+    /// specified number of hops from the center cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `center_id` - Starting cell ID
+    /// * `hops` - Maximum number of cell hops (0 = just the center cell)
+    ///
+    /// # Returns
+    ///
+    /// Vector of cell IDs within radius, including the center cell.
+    /// Returns empty vec if center_id is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// // Get all cells within 3 hops of cell 0
+    /// let nearby_cells = planet.find_cells_within_radius(0, 3);
+    /// println!("Found {} cells within 3 hops", nearby_cells.len());
+    /// ```
+    pub fn find_cells_within_radius(&self, center_id: usize, hops: usize) -> Vec<usize> {
+        // Neighbor lists are already unique, so the common small cases need no visited set
+        match (self.cells.get(center_id), hops) {
+            (None, _) => return Vec::new(),
+            (Some(_), 0) => return vec![center_id],
+            (Some(cell), 1) => {
+                let mut cells = Vec::with_capacity(cell.neighbors.len() + 1);
+                cells.push(center_id);
+                cells.extend_from_slice(&cell.neighbors);
+                return cells;
+            }
+            _ => {}
+        }
+
+        let mut cells = Vec::new();
+        let mut visited = HashSet::new();
+        self.find_cells_within_radius_into(center_id, hops, &mut cells, &mut visited);
+        cells
+    }
+
+    /// Find cells within a hop count, reusing caller-provided buffers
+    ///
+    /// Same result as [`find_cells_within_radius`](Self::find_cells_within_radius),
+    /// but writes into `out` (cleared first) and uses `scratch` as the visited set.
+    /// Once the buffers have grown to fit, repeated queries don't allocate.
+    /// Cells are listed in BFS order, nearest hops first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use std::collections::HashSet;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// let mut cells = Vec::new();
+    /// let mut scratch = HashSet::new();
+    /// for center in 0..10 {
+    ///     planet.find_cells_within_radius_into(center, 2, &mut cells, &mut scratch);
+    ///     println!("{} cells around {}", cells.len(), center);
+    /// }
+    /// ```
+    pub fn find_cells_within_radius_into(
+        &self,
+        center_id: usize,
+        hops: usize,
+        out: &mut Vec<usize>,
+        scratch: &mut HashSet<usize>,
+    ) {
+        out.clear();
+        scratch.clear();
+        if center_id >= self.cells.len() {
+            return;
+        }
+
+        out.push(center_id);
+        scratch.insert(center_id);
+
+        // BFS with hop limit; `out` doubles as the queue, one level per range
+        let mut level_start = 0;
+        for _ in 0..hops {
+            let level_end = out.len();
+            for i in level_start..level_end {
+                for &neighbor in self.get_neighbors(out[i]) {
+                    if scratch.insert(neighbor) {
+                        out.push(neighbor);
+                    }
+                }
+            }
+            if out.len() == level_end {
+                break;
+            }
+            level_start = level_end;
+        }
+    }
+
+    /// Group cells by hop distance from a center cell
+    ///
+    /// Index `h` of the result holds every cell exactly `h` hops from `center`,
+    /// so ring 0 is `[center]` and ring 1 its neighbors. Useful for reveal
+    /// animations that expand outward ring by ring. Stops early once the whole
+    /// planet is covered, so no trailing empty rings are returned.
+    ///
+    /// Returns an empty vec if `center` is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// for (hop, ring) in planet.rings_from(0, 5).iter().enumerate() {
+    ///     println!("Reveal {} cells at step {}", ring.len(), hop);
+    /// }
+    /// ```
+    pub fn rings_from(&self, center: usize, max_hops: usize) -> Vec<Vec<usize>> {
+        if center >= self.cells.len() {
+            return Vec::new();
+        }
+
+        let mut visited = HashSet::new();
+        visited.insert(center);
+        let mut rings = vec![vec![center]];
+
+        for _ in 0..max_hops {
+            let next: Vec<usize> = rings[rings.len() - 1]
+                .iter()
+                .flat_map(|&id| self.get_neighbors(id))
+                .filter(|&&neighbor| visited.insert(neighbor))
+                .copied()
+                .collect();
+            if next.is_empty() {
+                break;
+            }
+            rings.push(next);
+        }
+        rings
+    }
+
+    /// Find the closest cell whose terrain satisfies a predicate
+    ///
+    /// Searches breadth-first outward from `from`, ring by ring, and returns a
+    /// match from the first ring that contains any, so the result is always
+    /// among the fewest hops away. Ties within that ring go to the cell with
+    /// the shortest great-circle distance (then the lowest ID). `from` itself
+    /// is returned if it matches. Returns `None` if `from` is invalid or no
+    /// reachable cell matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// if let Some(mountain) = planet.nearest_cell_matching(0, |t| *t == BasicTerrainType::Mountain) {
+    ///     println!("Closest mountain: cell {}", mountain);
+    /// }
+    /// ```
+    pub fn nearest_cell_matching(&self, from: usize, pred: impl Fn(&T) -> bool) -> Option<usize> {
+        let start = self.cells.get(from)?;
+        let mut visited = vec![false; self.cells.len()];
+        visited[from] = true;
+        let mut ring = vec![from];
+
+        while !ring.is_empty() {
+            let best = ring
+                .iter()
+                .map(|&id| &self.cells[id])
+                .filter(|cell| pred(&cell.terrain))
+                .map(|cell| (start.distance_to(cell, self.radius), cell.id))
+                .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+            if let Some((_, id)) = best {
+                return Some(id);
+            }
+
+            let mut next = Vec::new();
+            for &id in &ring {
+                for &neighbor in &self.cells[id].neighbors {
+                    if let Some(seen) = visited.get_mut(neighbor) {
+                        if !*seen {
+                            *seen = true;
+                            next.push(neighbor);
+                        }
+                    }
+                }
+            }
+            ring = next;
+        }
+        None
+    }
+
+    /// Find the smallest cycle of matching cells passing through a cell
+    ///
+    /// Only cells for which `same(&start.terrain, &other.terrain)` holds are
+    /// traversed. Useful for detecting rings, e.g. land enclosing an inland sea
+    /// or lakes forming a loop. Uses BFS girth detection from the start cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell_id` - Cell the cycle must pass through
+    /// * `same` - Predicate deciding whether a cell belongs with the start cell
+    ///
+    /// # Returns
+    ///
+    /// Cell IDs along the cycle, starting with `cell_id` (the closing edge back
+    /// to `cell_id` is implied), or `None` if no cycle exists or the ID is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    /// if let Some(ring) = planet.smallest_cycle_through(0, |a, b| a == b) {
+    ///     println!("Cell 0 lies on a ring of {} cells", ring.len());
+    /// }
+    /// ```
+    pub fn smallest_cycle_through(
+        &self,
+        cell_id: usize,
+        same: impl Fn(&T, &T) -> bool,
+    ) -> Option<Vec<usize>> {
+        let start = self.cells.get(cell_id)?;
+
+        // Per visited cell: (distance, parent, first hop out of the start cell)
+        let mut info: HashMap<usize, (usize, usize, usize)> = HashMap::new();
+        let mut queue = std::collections::VecDeque::new();
+        info.insert(cell_id, (0, cell_id, cell_id));
+        queue.push_back(cell_id);
+
+        // Best closing edge found so far: (cycle length, u, w)
+        let mut best: Option<(usize, usize, usize)> = None;
+
+        while let Some(u) = queue.pop_front() {
+            let (dist_u, parent_u, branch_u) = info[&u];
+
+            // No shorter cycle can be closed from this depth on
+            if let Some((len, _, _)) = best {
+                if 2 * dist_u + 1 >= len {
+                    break;
+                }
+            }
+
+            for &w in self.get_neighbors(u) {
+                if w == parent_u || !same(&start.terrain, &self.cells[w].terrain) {
+                    continue;
+                }
+
+                match info.get(&w) {
+                    None => {
+                        let branch = if u == cell_id { w } else { branch_u };
+                        info.insert(w, (dist_u + 1, u, branch));
+                        queue.push_back(w);
+                    }
+                    Some(&(dist_w, _, branch_w)) => {
+                        // Edge between two different branches closes a cycle through the start
+                        if w != cell_id && u != cell_id && branch_w != branch_u {
+                            let len = dist_u + dist_w + 1;
+                            if best.is_none_or(|(best_len, _, _)| len < best_len) {
+                                best = Some((len, u, w));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let (_, u, w) = best?;
+        let path_to_start = |mut node: usize| {
+            let mut path = Vec::new();
+            while node != cell_id {
+                path.push(node);
+                node = info[&node].1;
+            }
+            path
+        };
+
+        let mut cycle = vec![cell_id];
+        cycle.extend(path_to_start(u).into_iter().rev());
+        cycle.extend(path_to_start(w));
+        Some(cycle)
+    }
+}
+
+/// Get the IDs of cells whose terrain differs between two planets
+///
+/// Both planets must share the same geometry, e.g. a planet and a copy that
+/// was re-sampled with [`resample_region`](VoronoiPlanet::resample_region)
+/// or terraformed. Only terrain is compared; IDs are returned in ascending
+/// order.
+///
+/// # Errors
+///
+/// Returns `VoronoiError::InvalidConfig` if the planets have different cell
+/// counts.
+///
+/// # Example
+///
+/// ```
+/// # use rust_voronoi_planet::*;
+/// # let before = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+/// let mut after = before.clone();
+/// after.resample_region(&[0, 1, 2], &PerlinTerrainSampler::new(7));
+/// let changed = terrain_diff(&before, &after).unwrap();
+/// assert!(changed.iter().all(|&id| id <= 2));
+/// ```
+pub fn terrain_diff<T: PartialEq>(a: &VoronoiPlanet<T>, b: &VoronoiPlanet<T>) -> Result<Vec<usize>> {
+    if a.cells.len() != b.cells.len() {
+        return Err(VoronoiError::InvalidConfig(format!(
+            "cannot diff planets with different cell counts ({} vs {})",
+            a.cells.len(),
+            b.cells.len()
+        )));
+    }
+
+    Ok(a.cells
+        .iter()
+        .zip(&b.cells)
+        .filter(|(x, y)| x.terrain != y.terrain)
+        .map(|(x, _)| x.id)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContinentStyle, PlanetSize, PlanetConfigBuilder};
+
+    #[test]
+    fn test_planet_generation() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .lloyd_iterations(2)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        assert!(planet.cell_count() > 0);
+        assert_eq!(planet.radius(), config.radius());
+    }
+
+    #[test]
+    fn test_get_cell() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        assert!(planet.get_cell(0).is_some());
+        assert!(planet.get_cell(planet.cell_count()).is_none());
+    }
+
+    #[test]
+    fn test_get_neighbors() {
+        let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+
+        let neighbors = planet.get_neighbors(0);
+        assert!(!neighbors.is_empty());
+        assert!(neighbors.len() >= 3); // Should have at least 3 neighbors
+        assert!(neighbors.len() <= 10); // Shouldn't have more than ~10
+    }
+
+    #[cfg(feature = "spatial-index")]
+    #[test]
+    fn test_find_cell_at() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        // Get a cell's center and verify we find that cell
+        let cell_center = planet.get_cell(0).unwrap().center;
+        let found_cell_id = planet.find_cell_at(cell_center);
+
+        assert_eq!(found_cell_id, 0);
+    }
+
+    #[cfg(feature = "spatial-index")]
+    #[test]
+    fn test_try_find_cell_at() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let cell = planet.get_cell(10).unwrap();
+
+        // Point on the surface, slightly off the center
+        let on_surface = (cell.center + Vec3::new(0.05, 0.05, 0.0)).normalize() * planet.radius();
+        assert_eq!(planet.try_find_cell_at(on_surface, 1.0), Some(10));
+
+        // Point far outside the sphere
+        let far_away = cell.center * 5.0;
+        assert_eq!(planet.try_find_cell_at(far_away, 1.0), None);
+    }
+
+    #[test]
+    fn test_find_cells_within_radius() {
+        let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+
+        // Radius 0 should return just the center cell
+        let cells_r0 = planet.find_cells_within_radius(0, 0);
+        assert_eq!(cells_r0.len(), 1);
+        assert!(cells_r0.contains(&0));
+
+        // Radius 1 should return center + neighbors
+        let cells_r1 = planet.find_cells_within_radius(0, 1);
+        let neighbors = planet.get_neighbors(0);
+        assert_eq!(cells_r1.len(), 1 + neighbors.len());
+
+        // Radius 2 should be larger
+        let cells_r2 = planet.find_cells_within_radius(0, 2);
+        assert!(cells_r2.len() > cells_r1.len());
+    }
+
+    #[test]
+    fn test_rings_from() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let rings = planet.rings_from(0, 4);
+        assert_eq!(rings.len(), 5);
+        assert_eq!(rings[0], vec![0]);
+
+        let ring1: HashSet<usize> = rings[1].iter().copied().collect();
+        let neighbors: HashSet<usize> = planet.get_neighbors(0).iter().copied().collect();
+        assert_eq!(ring1, neighbors);
+
+        // Disjoint, and together the same set as the hop-limited BFS
+        let all: Vec<usize> = rings.iter().flatten().copied().collect();
+        let unique: HashSet<usize> = all.iter().copied().collect();
+        assert_eq!(all.len(), unique.len());
+        let within: HashSet<usize> = planet.find_cells_within_radius(0, 4).into_iter().collect();
+        assert_eq!(unique, within);
+
+        // Covering the whole planet stops without empty rings
+        let full = planet.rings_from(0, usize::MAX);
+        assert!(full.iter().all(|ring| !ring.is_empty()));
+        assert_eq!(full.iter().map(Vec::len).sum::<usize>(), planet.cell_count());
+
+        assert!(planet.rings_from(planet.cell_count(), 2).is_empty());
+    }
+
+    struct ConstSampler;
+
+    impl TerrainSampler for ConstSampler {
+        type Output = u8;
+
+        fn sample(&self, _position: glam::Vec3, _radius: f32) -> u8 {
+            0
+        }
+    }
+
+    #[test]
+    fn test_smallest_cycle_through() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let mut planet = VoronoiPlanet::generate_with_sampler(config, &ConstSampler).unwrap();
+
+        // Pick two neighbors of cell 0 that are also adjacent to each other
+        let neighbors = planet.get_neighbors(0).to_vec();
+        let (a, b) = neighbors
+            .iter()
+            .flat_map(|&a| neighbors.iter().map(move |&b| (a, b)))
+            .find(|&(a, b)| a < b && planet.cells[a].is_neighbor_of(b))
+            .unwrap();
+
+        for id in [0, a, b] {
+            planet.cells[id].terrain = 1;
+        }
+
+        let cycle = planet.smallest_cycle_through(0, |x, y| x == y).unwrap();
+        assert_eq!(cycle.len(), 3);
+        assert_eq!(cycle[0], 0);
+        assert!(cycle.contains(&a) && cycle.contains(&b));
+
+        // A cell surrounded by different terrain has no cycle
+        let isolated = (0..planet.cell_count())
+            .find(|&id| {
+                planet.cells[id].terrain == 0
+                    && planet.get_neighbors(id).iter().all(|&n| planet.cells[n].terrain == 0)
+            })
+            .unwrap();
+        planet.cells[isolated].terrain = 2;
+        assert!(planet.smallest_cycle_through(isolated, |x, y| x == y).is_none());
+
+        // Uniform terrain always has a triangle through any cell
+        let cycle = planet.smallest_cycle_through(isolated, |_, _| true).unwrap();
+        assert_eq!(cycle.len(), 3);
+
+        assert!(planet.smallest_cycle_through(999_999, |x, y| x == y).is_none());
+    }
+
+    #[test]
+    fn test_natural_neighbor_interpolation() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        // Rough per-cell data (deterministic pseudo-random values)
+        let values: Vec<f32> = (0..planet.cell_count())
+            .map(|id| ((id * 7919) % 101) as f32 / 100.0)
+            .collect();
+
+        // Reproduces cell values at centers
+        for id in [0, 100, 2000] {
+            let center = planet.get_cell(id).unwrap().center;
+            let sibson = planet.natural_neighbor_interpolate(center, &values);
+            assert!((sibson - values[id]).abs() < 1e-3, "cell {}: {} vs {}", id, sibson, values[id]);
+        }
+
+        // Walk across the Delaunay edge at the midpoint between two neighbors.
+        // Barycentric interpolation has a kink there, Sibson does not, so the
+        // largest second difference along the walk should be smaller.
+        let max_kink = |f: &dyn Fn(Vec3) -> f32, a: Vec3, b: Vec3| {
+            let midpoint = (a + b).normalize() * planet.radius();
+            let across = (b - a).cross(midpoint).normalize() * (b - a).length() * 0.2;
+            let samples: Vec<f32> = (0..=100)
+                .map(|i| {
+                    let t = i as f32 / 50.0 - 1.0;
+                    f((midpoint + across * t).normalize() * planet.radius())
+                })
+                .collect();
+            samples
+                .windows(3)
+                .map(|w| (w[0] - 2.0 * w[1] + w[2]).abs())
+                .fold(0.0, f32::max)
+        };
+
+        let mut sibson_total = 0.0;
+        let mut barycentric_total = 0.0;
+        for id in [0, 500, 1000, 1500, 2500] {
+            let cell = planet.get_cell(id).unwrap();
+            for &n in &cell.neighbors {
+                let other = planet.get_cell(n).unwrap().center;
+                sibson_total += max_kink(&|p| planet.natural_neighbor_interpolate(p, &values), cell.center, other);
+                barycentric_total += max_kink(&|p| planet.barycentric_interpolate(p, &values), cell.center, other);
+            }
+        }
+        assert!(
+            sibson_total < barycentric_total,
+            "Sibson roughness {} should be below barycentric {}",
+            sibson_total,
+            barycentric_total
+        );
+    }
+
+    #[test]
+    fn test_find_cells_within_radius_into_matches() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let mut out = vec![usize::MAX; 3];
+        let mut scratch = HashSet::new();
+
+        for (center, hops) in [(0, 0), (0, 3), (17, 1), (4999, 5)] {
+            planet.find_cells_within_radius_into(center, hops, &mut out, &mut scratch);
+            let mut reused = out.clone();
+            let mut allocated = planet.find_cells_within_radius(center, hops);
+            assert_eq!(out[0], center);
+            reused.sort_unstable();
+            allocated.sort_unstable();
+            assert_eq!(reused, allocated);
+        }
+
+        planet.find_cells_within_radius_into(usize::MAX, 2, &mut out, &mut scratch);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_precomputed_edge_costs() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let mut planet = VoronoiPlanet::generate(config).unwrap();
+        let neighbor = planet.get_neighbors(0)[0];
+        let on_the_fly = planet.edge_cost(0, neighbor).unwrap();
+        assert!(planet.neighbor_costs(0).is_none());
+
+        planet.precompute_edge_costs();
+        assert_eq!(planet.edge_cost(0, neighbor), Some(on_the_fly));
+
+        for cell in planet.cells() {
+            let costs = planet.neighbor_costs(cell.id).unwrap();
+            assert_eq!(costs.len(), cell.neighbors.len());
+            for (&n, &cost) in cell.neighbors.iter().zip(costs) {
+                let expected = cell.distance_to(planet.get_cell(n).unwrap(), planet.radius());
+                assert!((cost - expected).abs() < 1e-5);
+            }
+        }
+
+        assert!(planet.edge_cost(0, 0).is_none());
+        assert!(planet.edge_cost(usize::MAX, 0).is_none());
+    }
+
+    #[test]
+    fn test_neighbor_csr_matches_cells() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let mut planet = VoronoiPlanet::generate(config).unwrap();
+        assert!(planet.neighbors_csr(0).is_empty());
+
+        planet.build_neighbor_csr();
+        for id in 0..planet.cell_count() {
+            let packed: Vec<usize> = planet.neighbors_csr(id).iter().map(|&n| n as usize).collect();
+            assert_eq!(packed, planet.get_neighbors(id), "cell {}", id);
+        }
+        assert!(planet.neighbors_csr(planet.cell_count()).is_empty());
+    }
+
+    #[test]
+    fn test_smooth_terrain_removes_speck() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let mut planet = VoronoiPlanet::generate_with_sampler(config, &ConstSampler).unwrap();
+
+        // One isolated "ocean" cell in a planet of land
+        planet.cells[10].terrain = 1;
+        planet.smooth_terrain(1);
+        assert_eq!(planet.cells[10].terrain, 0);
+
+        // A large feature survives: the 2-hop disc keeps its interior
+        let disc = planet.find_cells_within_radius(10, 2);
+        for &id in &disc {
+            planet.cells[id].terrain = 1;
+        }
+        planet.smooth_terrain(1);
+        assert_eq!(planet.cells[10].terrain, 1);
+        for &n in planet.get_neighbors(10) {
+            assert_eq!(planet.cells[n].terrain, 1);
+        }
+    }
+
+    #[test]
+    fn test_resample_region() {
+        struct OneSampler;
+
+        impl TerrainSampler for OneSampler {
+            type Output = u8;
+
+            fn sample(&self, _position: glam::Vec3, _radius: f32) -> u8 {
+                1
+            }
+        }
+
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Custom { cell_count: 500, radius: 5.0 })
+            .build()
+            .unwrap();
+        let mut planet = VoronoiPlanet::generate_with_sampler(config, &ConstSampler).unwrap();
+
+        // Empty slice is a no-op
+        planet.resample_region(&[], &OneSampler);
+        assert!(planet.cells().iter().all(|c| c.terrain == 0));
+
+        let region = planet.find_cells_within_radius(10, 2);
+        planet.resample_region(&region, &OneSampler);
+        planet.resample_region(&[usize::MAX], &OneSampler);
+        for cell in planet.cells() {
+            let expected = u8::from(region.contains(&cell.id));
+            assert_eq!(cell.terrain, expected, "cell {}", cell.id);
+        }
+    }
+
+    #[test]
+    fn test_on_surface_relative_tolerance() {
+        for radius in [1.0, 1000.0] {
+            let config = PlanetConfigBuilder::new()
+                .seed(42)
+                .planet_size(PlanetSize::Custom { cell_count: 200, radius })
+                .build()
+                .unwrap();
+            let mut planet = VoronoiPlanet::generate_with_sampler(config, &ConstSampler).unwrap();
+            assert!(planet.validate(1e-4).is_ok(), "radius {}", radius);
+
+            let surface = planet.get_cell(0).unwrap().center;
+            assert!(planet.on_surface(surface * (1.0 + 5e-5), 1e-4));
+            assert!(!planet.on_surface(surface * (1.0 + 2e-4), 1e-4));
+            assert!(!planet.on_surface(surface * (1.0 - 2e-4), 1e-4));
+
+            planet.cells[3].vertices[0] *= 1.01;
+            assert!(planet.validate(1e-4).is_err());
+        }
+    }
+
+    /// Sizes of the connected land regions, largest first
+    fn land_components(planet: &VoronoiPlanet<BasicTerrainType>) -> Vec<usize> {
+        let mut seen = vec![false; planet.cell_count()];
+        let mut sizes = Vec::new();
+        for start in 0..planet.cell_count() {
+            if seen[start] || planet.cells[start].terrain.is_water() {
+                continue;
+            }
+            seen[start] = true;
+            let mut stack = vec![start];
+            let mut size = 0;
+            while let Some(id) = stack.pop() {
+                size += 1;
+                for &n in planet.get_neighbors(id) {
+                    if !seen[n] && !planet.cells[n].terrain.is_water() {
+                        seen[n] = true;
+                        stack.push(n);
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    #[test]
+    fn test_continent_styles() {
+        let build = |style| {
+            let config = PlanetConfigBuilder::new()
+                .seed(42)
+                .planet_size(PlanetSize::Tiny)
+                .continent_style(style)
+                .build()
+                .unwrap();
+            land_components(&VoronoiPlanet::generate(config).unwrap())
+        };
+
+        let pangaea = build(ContinentStyle::Pangaea);
+        let fragmented = build(ContinentStyle::Fragmented);
+        assert!(pangaea.len() < fragmented.len());
+        let mean = |sizes: &[usize]| sizes.iter().sum::<usize>() as f32 / sizes.len() as f32;
+        assert!(mean(&pangaea) > mean(&fragmented));
+        assert!(pangaea[0] > fragmented[0]);
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_send_sync_bounds() {
+        assert_send::<VoronoiPlanet<BasicTerrainType>>();
+        assert_sync::<VoronoiPlanet<BasicTerrainType>>();
+        assert_send::<PlanetConfig>();
+        assert_send::<PerlinTerrainSampler>();
+        assert_sync::<PerlinTerrainSampler>();
+        assert_send::<crate::PlanetGenerator>();
+        assert_send::<crate::LazyTerrainPlanet<BasicTerrainType, PerlinTerrainSampler>>();
+        assert_sync::<crate::LazyTerrainPlanet<BasicTerrainType, PerlinTerrainSampler>>();
+        assert_send::<crate::MeshData>();
+        #[cfg(feature = "spatial-index")]
+        {
+            assert_send::<crate::SpatialIndex>();
+            assert_sync::<crate::SpatialIndex>();
+        }
+    }
+
+    #[test]
+    fn test_generate_on_thread() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let sampler = PerlinTerrainSampler::from_planet_config(&config);
+
+        let handle = std::thread::spawn(move || VoronoiPlanet::generate_with_sampler(config, &sampler));
+        let planet = handle.join().unwrap().unwrap();
+
+        let local = VoronoiPlanet::generate(config).unwrap();
+        assert_eq!(planet.cell_count(), local.cell_count());
+        assert!(planet.cells().iter().zip(local.cells()).all(|(a, b)| a.center == b.center && a.terrain == b.terrain));
+
+        // A finished planet can be queried from several threads at once
+        let shared = std::sync::Arc::new(planet);
+        let workers: Vec<_> = (0..2)
+            .map(|_| {
+                let planet = std::sync::Arc::clone(&shared);
+                std::thread::spawn(move || planet.cells().iter().filter(|c| c.terrain.is_water()).count())
+            })
+            .collect();
+        let counts: Vec<usize> = workers.into_iter().map(|w| w.join().unwrap()).collect();
+        assert_eq!(counts[0], counts[1]);
+    }
+
+    #[test]
+    fn test_relative_relief() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let mut elevation = vec![0.0; planet.cell_count()];
+        elevation[10] = 1.0;
+        elevation[200] = -1.0;
+        let relief = planet.relative_relief(&elevation);
+
+        assert_eq!(relief[10], Relief::Peak);
+        assert_eq!(relief[200], Relief::Pit);
+        for &n in planet.get_neighbors(10) {
+            assert_eq!(relief[n], Relief::Slope);
+        }
+        let untouched = (0..planet.cell_count())
+            .find(|&id| id != 10 && id != 200 && !planet.cells()[id].neighbors.iter().any(|&n| n == 10 || n == 200))
+            .unwrap();
+        assert_eq!(relief[untouched], Relief::Flat);
+
+        // A plateau of two raised cells has no peak
+        elevation[planet.get_neighbors(10)[0]] = 1.0;
+        let relief = planet.relative_relief(&elevation);
+        assert_eq!(relief[10], Relief::Slope);
+    }
+
+    #[test]
+    fn test_exposure() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let center = |id: usize| planet.cells()[id].center;
+
+        // Wind blows from an upwind neighbor across a two-cell ridge
+        let ridge = 10;
+        let normal = center(ridge).normalize();
+        let tangent = |v: Vec3| (v - normal * v.dot(normal)).normalize();
+        let upwind = planet.get_neighbors(ridge)[0];
+        let wind = tangent(center(ridge) - center(upwind));
+        let alignment = |n: usize| tangent(center(n) - center(ridge)).dot(wind);
+        let neighbors = planet.get_neighbors(ridge);
+        let downwind = *neighbors.iter().max_by(|&&a, &&b| alignment(a).total_cmp(&alignment(b))).unwrap();
+        let side = *neighbors.iter().min_by(|&&a, &&b| alignment(a).abs().total_cmp(&alignment(b).abs())).unwrap();
+
+        let mut elevation = vec![0.0; planet.cell_count()];
+        elevation[ridge] = 1.0;
+        elevation[side] = 1.0;
+        let exposure = planet.exposure(wind, &elevation);
+
+        assert!(exposure[upwind] > 0.0, "upwind face {}", exposure[upwind]);
+        assert!(exposure[downwind] < 0.0, "downwind face {}", exposure[downwind]);
+
+        // Reversing the wind swaps the faces; flat terrain is neutral
+        let reversed = planet.exposure(-wind, &elevation);
+        assert!(reversed[upwind] < 0.0 && reversed[downwind] > 0.0);
+        assert!(planet.exposure(wind, &[]).iter().all(|&e| e == 0.0));
+    }
+
+    #[test]
+    fn test_generate_displaced() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let sampler = PerlinTerrainSampler::from_planet_config(&config);
+        let flat = VoronoiPlanet::generate(config).unwrap();
+
+        let planet = VoronoiPlanet::generate_displaced(config, &sampler, |cell| if cell.id == 10 { 5.0 } else { 0.0 }).unwrap();
+        assert_eq!(planet.cell_count(), flat.cell_count());
+        assert_eq!(planet.radius(), flat.radius());
+
+        let raised = planet.get_cell(10).unwrap();
+        assert!((raised.center.length() - (planet.radius() + 5.0)).abs() < 1e-3);
+        assert!(raised.vertices.iter().all(|v| (v.length() - (planet.radius() + 5.0)).abs() < 1e-3));
+        assert!(planet.cells()[11].center.distance(flat.cells()[11].center) < 1e-4);
+        assert!(raised.approximate_area() > flat.cells()[10].approximate_area());
+        #[cfg(feature = "spatial-index")]
+        assert_eq!(planet.find_cell_at(raised.center), 10);
+
+        let sinks = VoronoiPlanet::generate_displaced(config, &sampler, |_| -2.0 * flat.radius());
+        assert!(matches!(sinks, Err(VoronoiError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_terrain_diff() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        assert!(terrain_diff(&planet, &planet).unwrap().is_empty());
+
+        let mut resampled = planet.clone();
+        let all: Vec<usize> = (0..planet.cell_count()).collect();
+        resampled.resample_region(&all, &PerlinTerrainSampler::new(7));
+
+        let changed = terrain_diff(&planet, &resampled).unwrap();
+        assert!(!changed.is_empty());
+        assert!(changed.iter().all(|&id| id < planet.cell_count()));
+        assert!(changed.iter().all(|&id| planet.cells()[id].terrain != resampled.cells()[id].terrain));
+        assert!(changed.windows(2).all(|w| w[0] < w[1]));
+
+        let other = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Small).build().unwrap();
+        let other = VoronoiPlanet::generate(other).unwrap();
+        assert!(matches!(terrain_diff(&planet, &other), Err(VoronoiError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_within_radius_fast_paths_match_bfs() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let mut bfs = Vec::new();
+        let mut scratch = HashSet::new();
+        for center in (0..planet.cell_count()).step_by(37) {
+            for hops in 0..=1 {
+                planet.find_cells_within_radius_into(center, hops, &mut bfs, &mut scratch);
+                assert_eq!(planet.find_cells_within_radius(center, hops), bfs);
+            }
+        }
+        assert!(planet.find_cells_within_radius(usize::MAX, 0).is_empty());
+        assert!(planet.find_cells_within_radius(usize::MAX, 1).is_empty());
+    }
+
+    #[test]
+    fn test_jitter_centers() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let original = VoronoiPlanet::generate(config).unwrap();
+
+        let mut planet = original.clone();
+        planet.jitter_centers(0.0, 7);
+        assert!(planet.cells().iter().zip(original.cells()).all(|(a, b)| a.center == b.center));
+
+        planet.jitter_centers(0.3, 7);
+        let moved = planet.cells().iter().zip(original.cells()).filter(|(a, b)| a.center != b.center).count();
+        assert!(moved > planet.cell_count() / 2);
+        assert!(planet.cells().iter().all(|c| planet.on_surface(c.center, 1e-4)));
+
+        // Offsets stay below strength times the average cell radius
+        let max_offset = 0.3 * 2.0 * planet.radius() / (planet.cell_count() as f32).sqrt();
+        assert!(planet.cells().iter().zip(original.cells()).all(|(a, b)| a.center.distance(b.center) <= max_offset * 1.01));
+
+        // Boundaries are untouched and the jitter is deterministic
+        assert!(planet.cells().iter().zip(original.cells()).all(|(a, b)| a.vertices == b.vertices));
+        let mut again = original.clone();
+        again.jitter_centers(0.3, 7);
+        assert!(planet.cells().iter().zip(again.cells()).all(|(a, b)| a.center == b.center));
+        #[cfg(feature = "spatial-index")]
+        assert_eq!(planet.find_cell_at(planet.cells()[5].center), 5);
+
+        // Precomputed edge costs follow the moved centers
+        let mut costed = original.clone();
+        costed.precompute_edge_costs();
+        costed.jitter_centers(0.3, 7);
+        for cell in costed.cells() {
+            let costs = costed.neighbor_costs(cell.id).unwrap();
+            for (&n, &cost) in cell.neighbors.iter().zip(costs) {
+                assert_eq!(cost, cell.distance_to(&costed.cells()[n], costed.radius()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounding_cap() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        assert!(planet.bounding_cap(&[]).is_none());
+        assert!(planet.bounding_cap(&[usize::MAX]).is_none());
+
+        let (axis, half_angle) = planet.bounding_cap(&[7]).unwrap();
+        assert!(axis.distance(planet.cells()[7].center.normalize()) < 1e-5);
+        assert!(half_angle < 1e-3);
+
+        // Every center lies within the cap
+        let selection = planet.find_cells_within_radius(7, 3);
+        let (axis, half_angle) = planet.bounding_cap(&selection).unwrap();
+        assert!(half_angle > 0.0 && half_angle < 1.0);
+        for &id in &selection {
+            assert!(arc_length(axis, planet.cells()[id].center, 1.0) <= half_angle + 1e-5);
+        }
+
+        let northern: Vec<usize> = planet.cells().iter().filter(|c| c.center.y >= 0.0).map(|c| c.id).collect();
+        let (axis, half_angle) = planet.bounding_cap(&northern).unwrap();
+        assert!(axis.dot(Vec3::Y) > 0.99);
+        assert!((half_angle - std::f32::consts::FRAC_PI_2).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_coastline_loops() {
+        struct UniformSampler(BasicTerrainType);
+
+        impl TerrainSampler for UniformSampler {
+            type Output = BasicTerrainType;
+
+            fn sample(&self, _position: Vec3, _radius: f32) -> BasicTerrainType {
+                self.0
+            }
+        }
+
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        for terrain in [BasicTerrainType::Land, BasicTerrainType::Ocean] {
+            let uniform = VoronoiPlanet::generate_with_sampler(config, &UniformSampler(terrain)).unwrap();
+            assert!(uniform.coastline_loops().is_empty());
+        }
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let loops = planet.coastline_loops();
+        assert!(!loops.is_empty());
+
+        // One vertex per land/water edge, and consecutive vertices (wrapping
+        // around) are the ends of a land cell edge
+        let coast_edges: usize = planet
+            .cells()
+            .iter()
+            .filter(|c| !c.terrain.is_water())
+            .map(|c| c.neighbors.iter().filter(|&&n| planet.cells()[n].terrain.is_water()).count())
+            .sum();
+        assert_eq!(loops.iter().map(Vec::len).sum::<usize>(), coast_edges);
+
+        let longest_edge = planet
+            .cells()
+            .iter()
+            .flat_map(|c| (0..c.vertices.len()).map(|i| c.vertices[i].distance(c.vertices[(i + 1) % c.vertices.len()])))
+            .fold(0.0, f32::max);
+        for coast in &loops {
+            assert!(coast.len() >= 3);
+            for (i, v) in coast.iter().enumerate() {
+                assert!(v.distance(coast[(i + 1) % coast.len()]) <= longest_edge);
+            }
+        }
+
+        let land: HashSet<usize> = planet.cells().iter().filter(|c| !c.terrain.is_water()).map(|c| c.id).collect();
+        let traced: f32 = loops
+            .iter()
+            .flat_map(|coast| (0..coast.len()).map(move |i| (coast[i], coast[(i + 1) % coast.len()])))
+            .map(|(a, b)| arc_length(a, b, planet.radius()))
+            .sum();
+        assert!((traced - planet.region_perimeter(&land)).abs() < 1e-2 * traced);
+    }
+
+    #[test]
+    fn test_custom_cell_count_is_exact() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Custom { cell_count: 1000, radius: 10.0 })
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        assert_eq!(planet.requested_cell_count(), 1000);
+        assert_eq!(planet.cell_count(), 1000);
+
+        // Merged input points are not replaced
+        let points = [Vec3::X, Vec3::X, Vec3::NEG_X, Vec3::Y, Vec3::NEG_Y, Vec3::Z, Vec3::NEG_Z];
+        let planet = VoronoiPlanet::from_points(&points, 5.0, &PerlinTerrainSampler::new(1)).unwrap();
+        assert_eq!((planet.requested_cell_count(), planet.cell_count()), (7, 6));
+    }
+
+    #[test]
+    fn test_aabb() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let (min, max) = planet.aabb();
+        let r = planet.radius();
+        let tolerance = 0.02 * r;
+        assert!((max - Vec3::splat(r)).abs().max_element() < tolerance);
+        assert!((min + Vec3::splat(r)).abs().max_element() < tolerance);
+        assert!(max.max_element() <= r * (1.0 + 1e-5));
+
+        // Displaced geometry grows the box
+        let sampler = PerlinTerrainSampler::from_planet_config(&config);
+        let raised = VoronoiPlanet::generate_displaced(config, &sampler, |_| 1.0).unwrap();
+        assert!(raised.aabb().1.x > max.x + 0.9);
+    }
+
+    #[test]
+    fn test_variant_index() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Small).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let again = VoronoiPlanet::generate(config).unwrap();
+
+        let variants: Vec<usize> = (0..planet.cell_count()).map(|id| planet.variant_index(id, 4)).collect();
+        assert!((0..planet.cell_count()).all(|id| again.variant_index(id, 4) == variants[id]));
+        assert_eq!(planet.variant_index(0, 0), 0);
+        assert_eq!(planet.variant_index(0, 1), 0);
+
+        // Roughly a quarter of the cells per variant
+        let expected = planet.cell_count() as f32 / 4.0;
+        for variant in 0..4 {
+            let count = variants.iter().filter(|&&v| v == variant).count() as f32;
+            assert!((count - expected).abs() < 0.1 * expected, "variant {} used {} times", variant, count);
+        }
+
+        // A different seed reshuffles the variants
+        let other = PlanetConfigBuilder::new().seed(43).planet_size(PlanetSize::Small).build().unwrap();
+        let other = VoronoiPlanet::generate(other).unwrap();
+        let same = (0..planet.cell_count().min(other.cell_count()))
+            .filter(|&id| other.variant_index(id, 4) == variants[id])
+            .count();
+        assert!(same < planet.cell_count() / 2);
+    }
+
+    #[test]
+    fn test_surface_area_and_volume() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let r = planet.radius();
+        let pi = std::f32::consts::PI;
+
+        let area = planet.surface_area();
+        assert!((area - 4.0 * pi * r * r).abs() / area < 1e-3, "area {}", area);
+
+        let flat = planet.estimated_volume(&[]);
+        assert!((flat - 4.0 / 3.0 * pi * r.powi(3)).abs() / flat < 1e-3, "volume {}", flat);
+        assert_eq!(planet.estimated_volume(&vec![0.0; planet.cell_count()]), flat);
+
+        // Raising every cell by h grows the volume like a sphere of radius r + h
+        let raised = planet.estimated_volume(&vec![1.0; planet.cell_count()]);
+        assert!(raised > flat);
+        assert!((raised - 4.0 / 3.0 * pi * (r + 1.0).powi(3)).abs() / raised < 1e-3);
+
+        // Raising a single cell still adds volume
+        let mut bump = vec![0.0; planet.cell_count()];
+        bump[0] = 2.0;
+        assert!(planet.estimated_volume(&bump) > flat);
+    }
+
+    #[test]
+    fn test_terrain_snapshot_restore() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let mut planet = VoronoiPlanet::generate(config).unwrap();
+        let original = planet.terrain_snapshot();
+        assert_eq!(original.len(), planet.cell_count());
+
+        // Edit, then undo
+        let mut undo = vec![planet.terrain_snapshot()];
+        planet.set_terrain(0, BasicTerrainType::Ice).unwrap();
+        undo.push(planet.terrain_snapshot());
+        planet.set_terrain(1, BasicTerrainType::Mountain).unwrap();
+        assert_eq!(planet.get_cell(1).unwrap().terrain, BasicTerrainType::Mountain);
+
+        planet.restore_terrain(&undo.pop().unwrap()).unwrap();
+        assert_eq!(planet.get_cell(0).unwrap().terrain, BasicTerrainType::Ice);
+        assert_eq!(planet.get_cell(1).unwrap().terrain, original[1]);
+        planet.restore_terrain(&undo.pop().unwrap()).unwrap();
+        assert_eq!(planet.terrain_snapshot(), original);
+
+        // Invalid input leaves the planet untouched
+        assert!(matches!(
+            planet.set_terrain(planet.cell_count(), BasicTerrainType::Ice),
+            Err(VoronoiError::CellNotFound(_))
+        ));
+        let short = vec![BasicTerrainType::Ice; planet.cell_count() - 1];
+        assert!(matches!(planet.restore_terrain(&short), Err(VoronoiError::InvalidConfig(_))));
+        assert_eq!(planet.terrain_snapshot(), original);
+    }
+
+    #[test]
+    fn test_slerp_surface() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let r = planet.radius();
+        let a = planet.get_cell(0).unwrap().center;
+        let b = planet.get_cell(planet.cell_count() / 2).unwrap().center;
+
+        assert!(planet.slerp_surface(a, b, 0.0).distance(a) < 1e-4 * r);
+        assert!(planet.slerp_surface(a, b, 1.0).distance(b) < 1e-3 * r);
+        let mid = planet.slerp_surface(a, b, 0.5);
+        assert!(planet.on_surface(mid, 1e-4));
+        assert!((mid.distance(a) - mid.distance(b)).abs() < 1e-3 * r);
+
+        // Endpoints need not lie on the surface
+        assert!(planet.slerp_surface(a * 3.0, b * 0.5, 1.0).distance(b) < 1e-3 * r);
+
+        // Antipodal and identical endpoints stay finite and on the sphere
+        for t in [0.0, 0.25, 0.5, 1.0] {
+            let p = planet.slerp_surface(a, -a, t);
+            assert!(planet.on_surface(p, 1e-4), "antipodal t={} gave {}", t, p);
+            assert!((p.distance(a) - 2.0 * r * (t * std::f32::consts::FRAC_PI_2).sin()).abs() < 1e-3 * r);
+            assert!(planet.slerp_surface(a, a, t).distance(a) < 1e-4 * r);
+        }
+        assert!(planet.on_surface(planet.slerp_surface(Vec3::ZERO, b, 0.5), 1e-4));
+    }
+
+    #[test]
+    fn test_cells_at_vertex() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let mut histogram = HashMap::new();
+        for cell in planet.cells() {
+            for &corner in &cell.vertices {
+                let cells = planet.cells_at_vertex(corner);
+                assert!(cells.contains(&cell.id));
+                // Every pair of cells at a corner are neighbors
+                for &a in &cells {
+                    assert!(cells.iter().all(|&b| a == b || planet.cells()[a].is_neighbor_of(b)));
+                }
+                *histogram.entry(cells.len()).or_insert(0) += 1;
+            }
+        }
+
+        // Typical corners join exactly three cells
+        let total: usize = histogram.values().sum();
+        assert!(histogram[&3] * 100 > total * 99, "corner sizes {:?}", histogram);
+
+        // Points that are not corners
+        let center = planet.get_cell(0).unwrap().center;
+        assert!(planet.cells_at_vertex(center).is_empty());
+    }
+
+    #[test]
+    fn test_generate_with_report() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let (planet, report) = VoronoiPlanet::generate_with_report(config).unwrap();
+        let plain = VoronoiPlanet::generate(config).unwrap();
+        assert!(terrain_diff(&planet, &plain).unwrap().is_empty());
+        assert!(planet.cells().iter().zip(plain.cells()).all(|(a, b)| a.center == b.center));
+
+        assert_eq!(report.cell_count, planet.cell_count());
+        assert_eq!(report.requested_cell_count, config.cell_count());
+        assert!(report.lloyd.iterations >= 1 && report.lloyd.iterations <= config.lloyd_iterations);
+        assert!(report.lloyd.max_displacement > 0.0);
+        if report.lloyd.iterations < config.lloyd_iterations {
+            assert!(report.lloyd.converged);
+        }
+        assert_eq!(report.terrain_counts.values().sum::<usize>(), planet.cell_count());
+        assert_eq!(
+            report.terrain_counts.get(&BasicTerrainType::Ocean).copied().unwrap_or(0),
+            planet.cells().iter().filter(|c| c.terrain == BasicTerrainType::Ocean).count()
+        );
+        #[cfg(feature = "profiling")]
+        assert!(report.timings.total >= report.timings.lloyd + report.timings.terrain);
+
+        // No relaxation, no Lloyd stats
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .lloyd_iterations(0)
+            .unwrap()
+            .build()
+            .unwrap();
+        let (_, report) = VoronoiPlanet::generate_with_report(config).unwrap();
+        assert_eq!(report.lloyd, crate::generation::LloydReport::default());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let original = VoronoiPlanet::generate(config).unwrap();
+
+        let mut planet = original.clone();
+        planet.rotate(Quat::IDENTITY);
+        for (a, b) in planet.cells().iter().zip(original.cells()) {
+            assert_eq!(a.center, b.center);
+            assert_eq!(a.vertices, b.vertices);
+        }
+
+        let target = 17;
+        let center = original.cells()[target].center.normalize();
+        planet.rotate(Quat::from_rotation_arc(center, Vec3::X));
+        assert!(planet.cells()[target].center.normalize().abs_diff_eq(Vec3::X, 1e-5));
+        assert_eq!(planet.nearest_cell(Vec3::X * planet.radius()), Some(target));
+
+        // Geometry stays on the sphere and keeps its shape
+        for (a, b) in planet.cells().iter().zip(original.cells()) {
+            assert!((a.center.length() - b.center.length()).abs() < 1e-3);
+            assert_eq!(a.neighbors, b.neighbors);
+        }
+        let area = original.cells()[3].spherical_area(original.radius());
+        assert!((planet.cells()[3].spherical_area(planet.radius()) - area).abs() < 1e-4 * area);
+    }
+
+    #[test]
+    fn test_adjacency_buffers() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let (offsets, neighbor_ids) = planet.adjacency_buffers();
+
+        assert_eq!(offsets.len(), planet.cell_count() + 1);
+        assert_eq!(offsets[0], 0);
+        assert_eq!(*offsets.last().unwrap() as usize, neighbor_ids.len());
+
+        let mut max_degree = 0;
+        for cell in planet.cells() {
+            let decoded: Vec<usize> = neighbor_ids[offsets[cell.id] as usize..offsets[cell.id + 1] as usize]
+                .iter()
+                .map(|&n| n as usize)
+                .collect();
+            assert_eq!(decoded, cell.neighbors);
+            max_degree = max_degree.max(decoded.len());
+        }
+        assert_eq!(planet.max_degree(), max_degree);
+        assert!(max_degree >= 6);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_config_only_without_serializable_terrain() {
+        // Deliberately not Serialize
+        #[derive(Clone)]
+        struct Opaque(#[allow(dead_code)] std::rc::Rc<u8>);
+
+        struct OpaqueSampler;
+
+        impl TerrainSampler for OpaqueSampler {
+            type Output = Opaque;
+
+            fn sample(&self, _position: glam::Vec3, _radius: f32) -> Opaque {
+                Opaque(std::rc::Rc::new(1))
+            }
+        }
+
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate_with_sampler(config, &OpaqueSampler).unwrap();
+
+        let json = serde_json::to_string(&planet.to_config_only()).unwrap();
+        let restored: PlanetConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, config);
+
+        let regenerated = VoronoiPlanet::generate_with_sampler(restored, &OpaqueSampler).unwrap();
+        assert_eq!(regenerated.cell_count(), planet.cell_count());
+        assert_eq!(regenerated.cells()[5].center, planet.cells()[5].center);
+    }
+
+    #[test]
+    fn test_frontier_cells() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let everything: HashSet<usize> = (0..planet.cell_count()).collect();
+        assert!(planet.frontier_cells(&everything).is_empty());
+        assert!(planet.frontier_cells(&HashSet::new()).is_empty());
+
+        let explored: HashSet<usize> = planet.find_cells_within_radius(0, 2).into_iter().collect();
+        let frontier = planet.frontier_cells(&explored);
+        assert!(!frontier.is_empty());
+
+        // Exactly the explored cells touching unexplored ones, the start cell is interior
+        let expected: HashSet<usize> = explored
+            .iter()
+            .copied()
+            .filter(|&id| planet.get_neighbors(id).iter().any(|n| !explored.contains(n)))
+            .collect();
+        assert_eq!(frontier.iter().copied().collect::<HashSet<_>>(), expected);
+        assert_eq!(frontier.len(), expected.len());
+        assert!(!frontier.contains(&0));
+
+        let unexplored = |id: usize| planet.get_neighbors(id).iter().filter(|n| !explored.contains(n)).count();
+        assert!(frontier.windows(2).all(|w| unexplored(w[0]) >= unexplored(w[1])));
+    }
+
+    #[test]
+    fn test_generate_variants() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let a = PerlinTerrainSampler::new(1);
+        let b = PerlinTerrainSampler::new(2);
+        let planets = VoronoiPlanet::generate_variants(config, &[&a, &b]).unwrap();
+        assert_eq!(planets.len(), 2);
+
+        for (x, y) in planets[0].cells().iter().zip(planets[1].cells()) {
+            assert_eq!(x.center, y.center);
+            assert_eq!(x.vertices, y.vertices);
+            assert_eq!(x.neighbors, y.neighbors);
+        }
+        assert!(!terrain_diff(&planets[0], &planets[1]).unwrap().is_empty());
+
+        // Same result as generating each planet separately
+        let separate = VoronoiPlanet::generate_with_sampler(config, &b).unwrap();
+        assert!(terrain_diff(&planets[1], &separate).unwrap().is_empty());
+        assert_eq!(planets[1].nearest_cell(Vec3::X * 3.0), separate.nearest_cell(Vec3::X * 3.0));
+
+        assert!(VoronoiPlanet::<BasicTerrainType>::generate_variants(config, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_random_surface_points() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let r = planet.radius();
+
+        let points = planet.random_surface_points(20_000, 9);
+        assert_eq!(points.len(), 20_000);
+        assert!(points.iter().all(|p| (p.length() - r).abs() < 1e-4 * r));
+        assert_eq!(points, planet.random_surface_points(20_000, 9));
+        assert_ne!(points[..10], planet.random_surface_points(10, 10)[..]);
+
+        // cos(lat) density: the band within 30° of the equator holds sin(30°) = 1/2
+        // of the area (uniform-in-latitude would give 1/3), beyond 60° only 1 - sin(60°)
+        let fraction = |f: &dyn Fn(f32) -> bool| {
+            points.iter().filter(|p| f((p.y / r).clamp(-1.0, 1.0).asin().to_degrees().abs())).count() as f32
+                / points.len() as f32
+        };
+        assert!((fraction(&|lat| lat < 30.0) - 0.5).abs() < 0.02);
+        assert!((fraction(&|lat| lat > 60.0) - (1.0 - 60f32.to_radians().sin())).abs() < 0.02);
+
+        let paired = planet.random_surface_points_with_cells(200, 9);
+        assert_eq!(paired.len(), 200);
+        for (&(point, cell_id), &expected) in paired.iter().zip(&points) {
+            assert_eq!(point, expected);
+            let nearest = planet
+                .cells()
+                .iter()
+                .min_by(|a, b| a.center.distance_squared(point).total_cmp(&b.center.distance_squared(point)))
+                .unwrap();
+            assert_eq!(cell_id, nearest.id);
+        }
+    }
+
+    #[test]
+    fn test_repair_neighbor_symmetry() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let original = VoronoiPlanet::generate(config).unwrap();
+        let is_symmetric = |planet: &VoronoiPlanet<BasicTerrainType>| {
+            planet
+                .cells()
+                .iter()
+                .all(|c| c.neighbors.iter().all(|&n| planet.get_neighbors(n).contains(&c.id)))
+        };
+
+        let mut planet = original.clone();
+        assert_eq!(planet.repair_neighbor_symmetry(NeighborRepair::AddMissing), 0);
+        assert!(is_symmetric(&planet));
+
+        // Inject a one-sided link between two far-apart cells
+        let far = planet.cell_count() - 1;
+        assert!(!planet.cells[0].neighbors.contains(&far));
+        planet.cells[0].neighbors.push(far);
+        planet.precompute_edge_costs();
+        assert!(!is_symmetric(&planet));
+
+        let mut added = planet.clone();
+        assert_eq!(added.repair_neighbor_symmetry(NeighborRepair::AddMissing), 1);
+        assert!(is_symmetric(&added));
+        assert!(added.get_neighbors(far).contains(&0));
+        assert!(added.neighbor_costs(0).is_none());
+
+        let mut removed = planet.clone();
+        assert_eq!(removed.repair_neighbor_symmetry(NeighborRepair::RemoveDangling), 1);
+        assert!(is_symmetric(&removed));
+        assert_eq!(removed.get_neighbors(0), original.get_neighbors(0));
+
+        // A one-sided link listed twice is repaired once, without new duplicates
+        let mut doubled = original.clone();
+        doubled.cells[0].neighbors.extend([far, far]);
+        let mut added = doubled.clone();
+        assert_eq!(added.repair_neighbor_symmetry(NeighborRepair::AddMissing), 1);
+        assert_eq!(added.get_neighbors(far).iter().filter(|&&n| n == 0).count(), 1);
+        let mut removed = doubled.clone();
+        assert_eq!(removed.repair_neighbor_symmetry(NeighborRepair::RemoveDangling), 1);
+        assert_eq!(removed.get_neighbors(0), original.get_neighbors(0));
+
+        // Self links and invalid IDs are dropped in either mode
+        let mut broken = original.clone();
+        broken.cells[3].neighbors.extend([3, usize::MAX]);
+        assert_eq!(broken.repair_neighbor_symmetry(NeighborRepair::AddMissing), 2);
+        assert_eq!(broken.get_neighbors(3), original.get_neighbors(3));
+    }
+
+    #[test]
+    fn test_generate_with_anchors() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .lloyd_iterations(5)
+            .unwrap()
+            .lloyd_convergence(0.0)
+            .unwrap()
+            .build()
+            .unwrap();
+        let sampler = PerlinTerrainSampler::from_planet_config(&config);
+        let anchors = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(-4.0, 0.5, 1.0), Vec3::Y];
+        let planet = VoronoiPlanet::generate_with_anchors(config, &sampler, &anchors).unwrap();
+        assert_eq!(planet.cell_count(), config.cell_count());
+
+        let r = planet.radius();
+        for anchor in anchors {
+            let position = anchor.normalize() * r;
+            let cell_id = planet.nearest_cell(position).unwrap();
+            assert!(planet.cells()[cell_id].center.distance(position) < 1e-4 * r);
+        }
+
+        // Without anchors the relaxed points end up elsewhere
+        let free = VoronoiPlanet::generate_with_sampler(config, &sampler).unwrap();
+        let position = anchors[0].normalize() * r;
+        let nearest = free.cells()[free.nearest_cell(position).unwrap()].center;
+        assert!(nearest.distance(position) > 1e-4 * r);
+
+        assert!(VoronoiPlanet::generate_with_anchors(config, &sampler, &[Vec3::ZERO]).is_err());
+        assert!(VoronoiPlanet::generate_with_anchors(config, &sampler, &[Vec3::X, Vec3::X * 2.0]).is_err());
+    }
+
+    #[test]
+    fn test_render_stats() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let stats = planet.render_stats();
+        assert!(stats.len() > 1);
+
+        let mesh = crate::generate_mesh(&planet, &crate::BasicColorMapper);
+        assert_eq!(stats.values().map(|s| s.triangle_count).sum::<usize>(), mesh.triangle_count());
+        assert_eq!(stats.values().map(|s| s.cell_count).sum::<usize>(), planet.cell_count());
+
+        let area: f32 = stats.values().map(|s| s.spherical_area).sum();
+        assert!((area - planet.surface_area()).abs() < 1e-3 * area);
+        let sphere = 4.0 * std::f32::consts::PI * planet.radius().powi(2);
+        assert!((area - sphere).abs() < 1e-3 * sphere);
+
+        let ocean = planet.cells().iter().filter(|c| c.terrain == BasicTerrainType::Ocean).count();
+        assert_eq!(stats[&BasicTerrainType::Ocean].cell_count, ocean);
     }
 
-    /// Find the cell containing a position (requires spatial-index feature)
-    ///
-    /// Uses KD-tree spatial index for O(log n) nearest-neighbor lookup.
-    /// This is essential for converting 3D positions (from raycasting, clicks, etc.)
-    /// into cell IDs.
-    ///
-    /// # Arguments
-    ///
-    /// * `position` - 3D position on sphere surface
-    ///
-    /// # Returns
-    ///
-    /// Cell ID of the nearest cell
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use rust_voronoi_planet::*;
-    /// # use glam::Vec3;
-    /// # #[cfg(feature = "spatial-index")]
-    /// # {
-    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
-    /// let position = Vec3::new(planet.radius(), 0.0, 0.0);
-    /// let cell_id = planet.find_cell_at(position);
-    /// println!("Position is in cell {}", cell_id);
-    /// # }
-    /// ```
-    #[cfg(feature = "spatial-index")]
-    pub fn find_cell_at(&self, position: Vec3) -> usize {
-        self.spatial_index.find_nearest(position)
+    #[test]
+    fn test_nearest_cell_matching() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        // Hops from every cell to the nearest ocean, by BFS from all ocean cells
+        let mut hops_to_ocean = vec![usize::MAX; planet.cell_count()];
+        let mut queue: std::collections::VecDeque<usize> =
+            planet.cells().iter().filter(|c| c.terrain.is_water()).map(|c| c.id).collect();
+        for &id in &queue {
+            hops_to_ocean[id] = 0;
+        }
+        while let Some(id) = queue.pop_front() {
+            for &n in planet.get_neighbors(id) {
+                if hops_to_ocean[n] == usize::MAX {
+                    hops_to_ocean[n] = hops_to_ocean[id] + 1;
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        // The land cell farthest (in hops) from any ocean
+        let inland = (0..planet.cell_count()).max_by_key(|&id| hops_to_ocean[id]).unwrap();
+        let hops = hops_to_ocean[inland];
+        assert!(hops >= 2);
+
+        let ocean = planet.nearest_ocean(inland).unwrap();
+        assert!(planet.get_cell(ocean).unwrap().terrain.is_water());
+        assert!(planet.rings_from(inland, hops)[hops].contains(&ocean));
+        assert_eq!(planet.nearest_ocean(ocean), Some(ocean));
+        assert_eq!(planet.nearest_ocean(planet.cell_count()), None);
+
+        struct LandSampler;
+        impl TerrainSampler for LandSampler {
+            type Output = BasicTerrainType;
+
+            fn sample(&self, _position: glam::Vec3, _radius: f32) -> BasicTerrainType {
+                BasicTerrainType::Land
+            }
+        }
+        let dry = VoronoiPlanet::generate_with_sampler(config, &LandSampler).unwrap();
+        assert_eq!(dry.nearest_ocean(0), None);
+        assert_eq!(dry.nearest_cell_matching(5, |t| *t == BasicTerrainType::Land), Some(5));
     }
 
-    /// Find cells within a given hop count from a center cell (BFS)
-    ///
-    /// Uses breadth-first search to find all cells reachable within the
-    /// specified number of hops from the center cell.
-    ///
-    /// # Arguments
-    ///
-    /// * `center_id` - Starting cell ID
-    /// * `hops` - Maximum number of cell hops (0 = just the center cell)
-    ///
-    /// # Returns
-    ///
-    /// Vector of cell IDs within radius, including the center cell.
-    /// Returns empty vec if center_id is invalid.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use rust_voronoi_planet::*;
-    /// # let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
-    /// // Get all cells within 3 hops of cell 0
-    /// let nearby_cells = planet.find_cells_within_radius(0, 3);
-    /// println!("Found {} cells within 3 hops", nearby_cells.len());
-    /// ```
-    pub fn find_cells_within_radius(&self, center_id: usize, hops: usize) -> Vec<usize> {
-        if center_id >= self.cells.len() {
-            return vec![];
+    #[test]
+    fn test_pick_spawn_points() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let habitable = |cell: &VoronoiCell<BasicTerrainType>| cell.terrain == BasicTerrainType::Land;
+
+        let picks = planet.pick_spawn_points(6, habitable, 3);
+        assert_eq!(picks.len(), 6);
+        assert!(picks.iter().all(|&id| habitable(&planet.cells()[id])));
+        assert_eq!(picks.iter().collect::<HashSet<_>>().len(), 6);
+        assert_eq!(picks, planet.pick_spawn_points(6, habitable, 3));
+
+        // Spread out: far better than the closest pair of the first six land cells by ID
+        let min_separation = |ids: &[usize]| {
+            let mut min = f32::INFINITY;
+            for (i, &a) in ids.iter().enumerate() {
+                for &b in &ids[i + 1..] {
+                    min = min.min(planet.cells()[a].distance_to(&planet.cells()[b], planet.radius()));
+                }
+            }
+            min
+        };
+        let naive: Vec<usize> = planet.cells().iter().filter(|c| habitable(c)).map(|c| c.id).take(6).collect();
+        assert!(min_separation(&picks) > 2.0 * min_separation(&naive));
+        assert!(min_separation(&picks) > 0.5 * planet.radius());
+
+        // Not enough habitable cells: all of them, once each
+        let rare = |cell: &VoronoiCell<BasicTerrainType>| cell.id % 100 == 0;
+        let all_rare = planet.pick_spawn_points(1000, rare, 3);
+        assert_eq!(all_rare.len(), planet.cells().iter().filter(|c| rare(c)).count());
+        assert_eq!(all_rare.iter().collect::<HashSet<_>>().len(), all_rare.len());
+
+        assert!(planet.pick_spawn_points(0, habitable, 3).is_empty());
+        assert!(planet.pick_spawn_points(3, |_| false, 3).is_empty());
+    }
+
+    #[test]
+    fn test_generate_with_context() {
+        use crate::terrain::ContextSampler;
+
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let raw = generate_raw_cells(&config).unwrap();
+
+        // Ocean world with one single-cell island and one larger continent
+        struct TwoLands {
+            island: Vec3,
+            continent: Vec3,
         }
+        impl TerrainSampler for TwoLands {
+            type Output = BasicTerrainType;
 
-        let mut visited = std::collections::HashSet::new();
-        let mut current = vec![center_id];
-        visited.insert(center_id);
+            fn sample(&self, position: Vec3, _radius: f32) -> BasicTerrainType {
+                let dir = position.normalize();
+                if dir.distance(self.island) < 1e-5 || dir.dot(self.continent) > 0.9 {
+                    BasicTerrainType::Land
+                } else {
+                    BasicTerrainType::Ocean
+                }
+            }
+        }
 
-        // BFS with hop limit
-        for _ in 0..hops {
-            let mut next = Vec::new();
-            for &cell_id in &current {
-                for &neighbor in self.get_neighbors(cell_id) {
-                    if visited.insert(neighbor) {
-                        next.push(neighbor);
-                    }
+        // Land entirely surrounded by ocean becomes an island beach
+        struct Islands;
+        impl ContextSampler for Islands {
+            type Output = BasicTerrainType;
+
+            fn refine(&self, _cell: &RawCell, terrain: &BasicTerrainType, neighbors: &[BasicTerrainType]) -> BasicTerrainType {
+                if *terrain == BasicTerrainType::Land && neighbors.iter().all(|n| *n == BasicTerrainType::Ocean) {
+                    BasicTerrainType::Beach
+                } else {
+                    *terrain
                 }
             }
-            current = next;
         }
 
-        visited.into_iter().collect()
-    }
-}
+        let island = raw[10].center.normalize();
+        let continent = -island;
+        let sampler = TwoLands { island, continent };
+        let base = VoronoiPlanet::generate_with_sampler(config, &sampler).unwrap();
+        let planet = VoronoiPlanet::generate_with_context(config, &sampler, &Islands).unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{PlanetSize, PlanetConfigBuilder};
+        assert_eq!(planet.get_cell(10).unwrap().terrain, BasicTerrainType::Beach);
+        assert_eq!(terrain_diff(&base, &planet).unwrap(), vec![10]);
+
+        let continent_cells = base.cells().iter().filter(|c| c.terrain == BasicTerrainType::Land).count();
+        assert!(continent_cells > 10);
+        for (a, b) in planet.cells().iter().zip(base.cells()) {
+            assert_eq!(a.center, b.center);
+        }
+    }
 
+    #[cfg(feature = "spatial-index")]
     #[test]
-    fn test_planet_generation() {
+    fn test_spatial_index_accessor() {
         let config = PlanetConfigBuilder::new()
             .seed(42)
             .planet_size(PlanetSize::Tiny)
-            .lloyd_iterations(2)
-            .unwrap()
             .build()
             .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let index = planet.spatial_index().unwrap();
+
+        let query = Vec3::new(3.0, -7.0, 5.0);
+        let mut brute: Vec<(usize, f32)> = planet.cells().iter().map(|c| (c.id, c.center.distance(query))).collect();
+        brute.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
 
+        let nearest = index.find_k_nearest(query, 12);
+        assert_eq!(nearest.len(), 12);
+        for (found, expected) in nearest.iter().zip(&brute) {
+            assert_eq!(found.0, expected.0);
+            assert!((found.1 - expected.1).abs() < 1e-4);
+        }
+        assert_eq!(nearest[0].0, planet.find_cell_at(query));
+    }
+
+    #[test]
+    fn test_center_of_mass_offset() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
         let planet = VoronoiPlanet::generate(config).unwrap();
+        let balanced = planet.center_of_mass_offset();
+        assert!(balanced < 0.02, "relaxed planet offset {}", balanced);
 
-        assert!(planet.cell_count() > 0);
-        assert_eq!(planet.radius(), config.radius());
+        // Golden spiral, keeping the whole northern half but only every tenth southern point
+        let n = 400;
+        let points: Vec<Vec3> = (0..n)
+            .map(|i| {
+                let y = 1.0 - (i as f32 + 0.5) * 2.0 / n as f32;
+                let r = (1.0 - y * y).sqrt();
+                let theta = i as f32 * std::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+                Vec3::new(r * theta.cos(), y, r * theta.sin())
+            })
+            .enumerate()
+            .filter(|(i, p)| p.y > 0.0 || i % 10 == 0)
+            .map(|(_, p)| p)
+            .collect();
+        let clustered = VoronoiPlanet::from_points(&points, 5.0, &PerlinTerrainSampler::new(1)).unwrap();
+        let offset = clustered.center_of_mass_offset();
+        assert!(offset > 0.3, "clustered offset {}", offset);
     }
 
     #[test]
-    fn test_get_cell() {
+    fn test_to_dot() {
+        let config = PlanetConfigBuilder::new().seed(42).planet_size(PlanetSize::Tiny).build().unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+
+        let dot = planet.to_dot(|cell| format!("cell \"{}\"", cell.id));
+        assert!(dot.starts_with("graph planet {\n") && dot.ends_with("}\n"));
+        assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+
+        // Each undirected edge appears once
+        let total_neighbors: usize = planet.cells().iter().map(|c| c.neighbor_count()).sum();
+        let edges: Vec<&str> = dot.lines().filter(|l| l.contains(" -- ")).collect();
+        assert_eq!(edges.len(), total_neighbors / 2);
+
+        // Every edge references a declared node, and labels are escaped
+        let nodes: HashSet<&str> = dot
+            .lines()
+            .filter(|l| l.contains("[label="))
+            .map(|l| l.trim().split(' ').next().unwrap())
+            .collect();
+        assert_eq!(nodes.len(), planet.cell_count());
+        for edge in &edges {
+            let (a, b) = edge.trim().trim_end_matches(';').split_once(" -- ").unwrap();
+            assert!(nodes.contains(a) && nodes.contains(b), "dangling edge {}", edge);
+        }
+        assert!(dot.contains("[label=\"cell \\\"0\\\"\"];"));
+
+        // A subset only keeps edges inside it
+        let region = planet.find_cells_within_radius(0, 1);
+        let sub = planet.to_dot_subset(&region, |cell| cell.id.to_string());
+        let sub_edges = sub.lines().filter(|l| l.contains(" -- ")).count();
+        let expected = region
+            .iter()
+            .flat_map(|&a| planet.get_neighbors(a).iter().map(move |&b| (a, b)))
+            .filter(|&(a, b)| a < b && region.contains(&b))
+            .count();
+        assert_eq!(sub_edges, expected);
+        assert!(sub_edges >= planet.get_neighbors(0).len());
+    }
+
+    #[test]
+    fn test_centroid_of() {
+        let points = [
+            glam::Vec3::X,
+            glam::Vec3::NEG_X,
+            glam::Vec3::Y,
+            glam::Vec3::NEG_Y,
+            glam::Vec3::Z,
+            glam::Vec3::NEG_Z,
+        ];
+        let planet = VoronoiPlanet::from_points(&points, 3.0, &ConstSampler).unwrap();
+        let x = planet.cell_for_input_point(0).unwrap();
+        let neg_x = planet.cell_for_input_point(1).unwrap();
+        let y = planet.cell_for_input_point(2).unwrap();
+
+        // A single cell is its own direction
+        let single = planet.centroid_of(|c| c.id == x).unwrap();
+        assert!(single.distance(glam::Vec3::X) < 1e-5);
+
+        // Two orthogonal cells meet halfway
+        let pair = planet.centroid_of(|c| c.id == x || c.id == y).unwrap();
+        assert!(pair.distance(glam::Vec3::new(1.0, 1.0, 0.0).normalize()) < 1e-5);
+
+        // Antipodal cells and the whole planet cancel out
+        assert_eq!(planet.centroid_of(|c| c.id == x || c.id == neg_x), None);
+        assert_eq!(planet.centroid_of(|_| true), None);
+        assert_eq!(planet.centroid_of(|_| false), None);
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [
+            glam::Vec3::X,
+            glam::Vec3::NEG_X,
+            glam::Vec3::Y,
+            glam::Vec3::NEG_Y,
+            glam::Vec3::Z,
+            glam::Vec3::NEG_Z,
+            // Off the sphere and duplicated: projected and merged
+            glam::Vec3::Z * 3.0,
+        ];
+        let planet = VoronoiPlanet::from_points(&points, 2.0, &ConstSampler).unwrap();
+
+        assert_eq!(planet.cell_count(), 6);
+        assert_eq!(planet.radius(), 2.0);
+        for cell in planet.cells() {
+            assert!((cell.center.length() - 2.0).abs() < 1e-5);
+            assert_eq!(cell.neighbors.len(), 4);
+            assert_eq!(cell.terrain, 0);
+        }
+
+        // Every input maps back to the cell centered on it; the duplicate shares Z's cell
+        for (i, p) in points.iter().enumerate() {
+            let cell = planet.cell_for_input_point(i).unwrap();
+            assert!(planet.cells[cell].center.distance(p.normalize() * 2.0) < 1e-5, "input {}", i);
+        }
+        assert_eq!(planet.cell_for_input_point(6), planet.cell_for_input_point(4));
+        assert_eq!(planet.cell_for_input_point(points.len()), None);
+
+        let ids: HashSet<usize> = (0..6).filter_map(|i| planet.cell_for_input_point(i)).collect();
+        assert_eq!(ids.len(), 6);
+
+        assert!(VoronoiPlanet::from_points(&points[..3], 2.0, &ConstSampler).is_err());
+        assert!(VoronoiPlanet::from_points(&points, 0.0, &ConstSampler).is_err());
+        let mut bad = points;
+        bad[0] = glam::Vec3::ZERO;
+        assert!(VoronoiPlanet::from_points(&bad, 2.0, &ConstSampler).is_err());
+    }
+
+    #[test]
+    fn test_cells_in_frustum() {
         let config = PlanetConfigBuilder::new()
             .seed(42)
             .planet_size(PlanetSize::Tiny)
             .build()
             .unwrap();
-
         let planet = VoronoiPlanet::generate(config).unwrap();
 
-        assert!(planet.get_cell(0).is_some());
-        assert!(planet.get_cell(planet.cell_count()).is_none());
+        // Camera at the planet center looking down -Z
+        let view_proj = Mat4::perspective_rh(1.5, 1.0, 0.1, 100.0);
+        let nearest_to = |dir: Vec3| {
+            planet
+                .cells()
+                .iter()
+                .max_by(|a, b| a.center.dot(dir).total_cmp(&b.center.dot(dir)))
+                .unwrap()
+                .id
+        };
+        let ahead = nearest_to(Vec3::NEG_Z);
+        let behind = nearest_to(Vec3::Z);
+
+        let visible = planet.cells_in_frustum(view_proj, [-1.0, -1.0, 1.0, 1.0]);
+        assert!(visible.contains(&ahead));
+        assert!(!visible.contains(&behind));
+        assert!(visible.iter().all(|&id| planet.get_cell(id).unwrap().center.z < 0.0));
+
+        // A small box in the corner excludes the cell straight ahead
+        let corner = planet.cells_in_frustum(view_proj, [0.5, 0.5, 1.0, 1.0]);
+        assert!(!corner.is_empty() && !corner.contains(&ahead));
     }
 
     #[test]
-    fn test_get_neighbors() {
-        let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    fn test_lit_and_terminator_cells() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let sun = glam::Vec3::new(1.0, 2.0, -0.5);
 
-        let neighbors = planet.get_neighbors(0);
-        assert!(!neighbors.is_empty());
-        assert!(neighbors.len() >= 3); // Should have at least 3 neighbors
-        assert!(neighbors.len() <= 10); // Shouldn't have more than ~10
+        // Rotating the sun to the opposite side flips every cell
+        let day = planet.lit_cells(sun);
+        let night = planet.lit_cells(-sun);
+        assert_eq!(day.len(), planet.cell_count());
+        assert!(day.iter().zip(&night).all(|(a, b)| a != b));
+
+        // Roughly half the planet is lit
+        let lit_count = day.iter().filter(|&&l| l).count();
+        let fraction = lit_count as f32 / planet.cell_count() as f32;
+        assert!((fraction - 0.5).abs() < 0.05, "lit fraction {}", fraction);
+
+        // The terminator hugs the great circle perpendicular to the sun
+        let terminator = planet.terminator_cells(sun);
+        assert!(!terminator.is_empty());
+        let sun_dir = sun.normalize();
+        for &id in &terminator {
+            let d = planet.get_cell(id).unwrap().center.normalize().dot(sun_dir);
+            assert!(d > 0.0 && d < 0.1, "cell {} too far from terminator: {}", id, d);
+        }
     }
 
-    #[cfg(feature = "spatial-index")]
     #[test]
-    fn test_find_cell_at() {
+    fn test_edge_midpoints() {
         let config = PlanetConfigBuilder::new()
             .seed(42)
             .planet_size(PlanetSize::Tiny)
             .build()
             .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let radius = planet.radius();
+        let midpoints = planet.edge_midpoints();
+
+        // One entry per undirected neighbor pair
+        let pair_count: usize = planet.cells().iter().map(|c| c.neighbors.len()).sum::<usize>() / 2;
+        assert_eq!(midpoints.len(), pair_count);
+
+        for &(a, b, midpoint) in &midpoints {
+            assert!(a < b);
+            assert!((midpoint.length() - radius).abs() < 1e-3);
+
+            let da = midpoint.distance(planet.get_cell(a).unwrap().center);
+            let db = midpoint.distance(planet.get_cell(b).unwrap().center);
+            assert!((da - db).abs() < 1e-3 * radius, "edge {}-{}: {} vs {}", a, b, da, db);
+        }
+    }
 
+    #[test]
+    fn test_region_lat_lon_bounds() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
         let planet = VoronoiPlanet::generate(config).unwrap();
 
-        // Get a cell's center and verify we find that cell
-        let cell_center = planet.get_cell(0).unwrap().center;
-        let found_cell_id = planet.find_cell_at(cell_center);
+        // Region bounds enclose the bounds of each member
+        let region = planet.find_cells_within_radius(100, 2);
+        let bounds = planet.region_lat_lon_bounds(&region).unwrap();
+        for &id in &region {
+            let cell = planet.get_cell(id).unwrap().lat_lon_bounds(planet.radius());
+            assert!(cell.min_lat >= bounds.min_lat && cell.max_lat <= bounds.max_lat);
+        }
 
-        assert_eq!(found_cell_id, 0);
+        // Exactly one cell holds each pole
+        let polar: Vec<usize> = (0..planet.cell_count())
+            .filter(|&id| planet.get_cell(id).unwrap().lat_lon_bounds(planet.radius()).max_lat == 90.0)
+            .collect();
+        assert_eq!(polar.len(), 1);
+        let polar_region = planet.region_lat_lon_bounds(&[polar[0], region[0]]).unwrap();
+        assert_eq!(polar_region.lon_span(), 360.0);
+
+        assert!(planet.region_lat_lon_bounds(&[]).is_none());
     }
 
     #[test]
-    fn test_find_cells_within_radius() {
-        let planet = VoronoiPlanet::generate(PlanetConfig::default()).unwrap();
+    fn test_region_area_and_perimeter() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        let radius = planet.radius();
+        let cell = planet.get_cell(0).unwrap();
 
-        // Radius 0 should return just the center cell
-        let cells_r0 = planet.find_cells_within_radius(0, 0);
-        assert_eq!(cells_r0.len(), 1);
-        assert!(cells_r0.contains(&0));
+        // A single cell region matches the cell itself
+        assert!((planet.region_area(&[0]) - cell.spherical_area(radius)).abs() < 1e-5);
+        let single: HashSet<usize> = [0].into_iter().collect();
+        assert!((planet.region_perimeter(&single) - cell.perimeter(radius)).abs() < 1e-4);
 
-        // Radius 1 should return center + neighbors
-        let cells_r1 = planet.find_cells_within_radius(0, 1);
-        let neighbors = planet.get_neighbors(0);
-        assert_eq!(cells_r1.len(), 1 + neighbors.len());
+        // Merging a neighbor removes their shared edge from the border
+        let neighbor = cell.neighbors[0];
+        let (a, b) = planet.shared_edge(0, neighbor).unwrap();
+        let shared = crate::cell::arc_length(a, b, radius);
+        let pair: HashSet<usize> = [0, neighbor].into_iter().collect();
+        let expected = cell.perimeter(radius) + planet.get_cell(neighbor).unwrap().perimeter(radius)
+            - 2.0 * shared;
+        assert!((planet.region_perimeter(&pair) - expected).abs() < 1e-3);
 
-        // Radius 2 should be larger
-        let cells_r2 = planet.find_cells_within_radius(0, 2);
-        assert!(cells_r2.len() > cells_r1.len());
+        // Whole planet has no border
+        let all: HashSet<usize> = (0..planet.cell_count()).collect();
+        assert!(planet.region_perimeter(&all) < 1e-3);
+        assert!(planet.shared_edge(0, 0).is_none());
     }
 
     #[test]
@@ -473,4 +4692,79 @@ mod tests {
         assert!(water_count > 0, "Should have some ocean");
         assert!(land_count > 0, "Should have some land");
     }
+
+    #[test]
+    fn test_degenerate_cells() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+
+        let mut planet = VoronoiPlanet::generate(config).unwrap();
+        let baseline = planet.degenerate_cells(3);
+        assert!(baseline.is_empty(), "Triangulated cells always have >= 3 neighbors");
+
+        planet.cells[7].neighbors.truncate(2);
+        planet.cells[42].neighbors.clear();
+        assert_eq!(planet.degenerate_cells(3), vec![7, 42]);
+    }
+
+    #[test]
+    fn test_no_degenerate_cells_when_relaxed() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Large)
+            .point_distribution(crate::PointDistribution::Fibonacci)
+            .lloyd_iterations(1)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let planet = VoronoiPlanet::generate(config).unwrap();
+        assert!(planet.degenerate_cells(4).is_empty());
+    }
+
+    #[test]
+    fn test_uniformity_improves_with_lloyd() {
+        let build = |iterations| {
+            let config = PlanetConfigBuilder::new()
+                .seed(42)
+                .planet_size(PlanetSize::Tiny)
+                .lloyd_iterations(iterations)
+                .unwrap()
+                .lloyd_convergence(0.0)
+                .unwrap()
+                .build()
+                .unwrap();
+            VoronoiPlanet::generate(config).unwrap()
+        };
+
+        let raw = build(0);
+        let relaxed = build(5);
+        assert!(relaxed.uniformity() < raw.uniformity());
+
+        // Spherical areas tile the whole sphere
+        let report = relaxed.uniformity_report();
+        let total = report.mean_area * relaxed.cell_count() as f32;
+        let sphere = 4.0 * std::f32::consts::PI * relaxed.radius().powi(2);
+        assert!((total - sphere).abs() / sphere < 1e-3);
+    }
+
+    #[test]
+    fn test_from_icosphere_cells() {
+        use crate::generation::generate_icosphere_cells;
+
+        let config = PlanetConfigBuilder::new().seed(42).build().unwrap();
+        let raw_cells = generate_icosphere_cells(2, config.radius()).unwrap();
+        let planet = VoronoiPlanet::from_raw_cells(
+            config,
+            raw_cells,
+            &PerlinTerrainSampler::new(config.terrain_seed),
+        );
+
+        assert_eq!(planet.cell_count(), 162);
+        let mesh = crate::generate_mesh(&planet, &crate::BasicColorMapper);
+        assert_eq!(mesh.triangle_count(), 12 * 5 + 150 * 6);
+    }
 }
@@ -8,6 +8,8 @@ use kiddo::immutable::float::kdtree::ImmutableKdTree;
 use kiddo::SquaredEuclidean;
 #[cfg(feature = "spatial-index")]
 use glam::Vec3;
+#[cfg(feature = "spatial-index")]
+use std::num::NonZero;
 
 /// Wrapper around KD-tree for spatial queries
 ///
@@ -102,10 +104,116 @@ impl SpatialIndex {
     pub fn find_nearest(&self, position: Vec3) -> usize {
         let query = [position.x, position.y, position.z];
         let result = self.tree.nearest_one::<SquaredEuclidean>(&query);
-        result.item as usize
+        result.item
+    }
+
+    /// Find the nearest cell to a position along with its distance
+    ///
+    /// Like [`find_nearest`](Self::find_nearest), but also returns the
+    /// Euclidean distance from the query to the nearest cell center.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - 3D position to query
+    ///
+    /// # Returns
+    ///
+    /// `(cell_id, distance)` of the nearest cell
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use glam::Vec3;
+    /// # #[cfg(feature = "spatial-index")]
+    /// # {
+    /// # let centers = vec![Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)];
+    /// # let index = SpatialIndex::new(&centers);
+    /// let (cell_id, distance) = index.find_nearest_with_distance(Vec3::new(2.0, 0.0, 0.0));
+    /// assert_eq!(cell_id, 0);
+    /// assert!((distance - 1.0).abs() < 1e-6);
+    /// # }
+    /// ```
+    pub fn find_nearest_with_distance(&self, position: Vec3) -> (usize, f32) {
+        let query = [position.x, position.y, position.z];
+        let result = self.tree.nearest_one::<SquaredEuclidean>(&query);
+        (result.item, result.distance.sqrt())
+    }
+
+    /// Find the `k` cells nearest to a position
+    ///
+    /// Returns `(cell_id, distance)` pairs sorted by increasing Euclidean
+    /// distance (ties by cell ID), with fewer than `k` entries if the index
+    /// holds fewer cells. `k = 0` returns an empty list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use glam::Vec3;
+    /// # #[cfg(feature = "spatial-index")]
+    /// # {
+    /// # let centers = vec![Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(-1.0, 0.0, 0.0)];
+    /// # let index = SpatialIndex::new(&centers);
+    /// let nearest = index.find_k_nearest(Vec3::new(0.9, 0.2, 0.0), 2);
+    /// assert_eq!(nearest.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![0, 1]);
+    /// # }
+    /// ```
+    pub fn find_k_nearest(&self, position: Vec3, k: usize) -> Vec<(usize, f32)> {
+        let Some(k) = NonZero::new(k) else {
+            return Vec::new();
+        };
+        let query = [position.x, position.y, position.z];
+        let found = self.tree.nearest_n::<SquaredEuclidean>(&query, k);
+        sorted_by_distance(found.into_iter().map(|n| (n.item, n.distance)))
+    }
+
+    /// Find all cells whose center lies within `max_distance` of a position
+    ///
+    /// Returns `(cell_id, distance)` pairs sorted by increasing Euclidean
+    /// distance (ties by cell ID). The distance is the straight-line chord,
+    /// not the great-circle distance; the two agree closely for distances
+    /// well below the radius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_voronoi_planet::*;
+    /// # use glam::Vec3;
+    /// # #[cfg(feature = "spatial-index")]
+    /// # {
+    /// # let centers = vec![Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(-1.0, 0.0, 0.0)];
+    /// # let index = SpatialIndex::new(&centers);
+    /// let nearby = index.find_within(Vec3::new(1.0, 0.0, 0.0), 1.5);
+    /// assert_eq!(nearby.len(), 2);
+    /// # }
+    /// ```
+    pub fn find_within(&self, position: Vec3, max_distance: f32) -> Vec<(usize, f32)> {
+        if max_distance.is_nan() || max_distance < 0.0 {
+            return Vec::new();
+        }
+        let query = [position.x, position.y, position.z];
+        let found = self.tree.within::<SquaredEuclidean>(&query, max_distance * max_distance);
+        sorted_by_distance(found.into_iter().map(|n| (n.item, n.distance)))
+    }
+
+    /// Find the nearest cell for each of many positions
+    ///
+    /// Equivalent to calling [`find_nearest`](Self::find_nearest) per
+    /// position; the result is parallel to `positions`.
+    pub fn find_nearest_batch(&self, positions: &[Vec3]) -> Vec<usize> {
+        positions.iter().map(|&p| self.find_nearest(p)).collect()
     }
 }
 
+/// Convert squared distances and sort by distance, then cell ID
+#[cfg(feature = "spatial-index")]
+fn sorted_by_distance(found: impl Iterator<Item = (usize, f32)>) -> Vec<(usize, f32)> {
+    let mut result: Vec<(usize, f32)> = found.map(|(id, squared)| (id, squared.sqrt())).collect();
+    result.sort_unstable_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    result
+}
+
 #[cfg(test)]
 #[cfg(feature = "spatial-index")]
 mod tests {
@@ -155,4 +263,50 @@ mod tests {
         let result = index.find_nearest(centers[1]);
         assert_eq!(result, 1);
     }
+
+    #[test]
+    fn test_spatial_index_k_nearest_and_within() {
+        let centers: Vec<Vec3> = (0..50)
+            .map(|i| {
+                let t = i as f32 * 0.7;
+                Vec3::new(t.cos(), (i as f32 / 25.0) - 1.0, t.sin())
+            })
+            .collect();
+        let index = SpatialIndex::new(&centers);
+        let query = Vec3::new(0.3, 0.1, 0.8);
+
+        let mut brute: Vec<(usize, f32)> = centers.iter().enumerate().map(|(i, c)| (i, c.distance(query))).collect();
+        brute.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+
+        let nearest = index.find_k_nearest(query, 5);
+        assert_eq!(nearest.len(), 5);
+        for (found, expected) in nearest.iter().zip(&brute) {
+            assert_eq!(found.0, expected.0);
+            assert!((found.1 - expected.1).abs() < 1e-5);
+        }
+        assert_eq!(index.find_k_nearest(query, 100).len(), 50);
+        assert!(index.find_k_nearest(query, 0).is_empty());
+
+        let within = index.find_within(query, 0.6);
+        let expected: Vec<usize> = brute.iter().filter(|(_, d)| *d <= 0.6).map(|(i, _)| *i).collect();
+        assert_eq!(within.iter().map(|(i, _)| *i).collect::<Vec<_>>(), expected);
+        assert!(index.find_within(query, -1.0).is_empty());
+
+        let batch = index.find_nearest_batch(&centers[..10]);
+        assert_eq!(batch, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_spatial_index_with_distance() {
+        let centers = vec![
+            Vec3::new(10.0, 0.0, 0.0),
+            Vec3::new(0.0, 10.0, 0.0),
+        ];
+
+        let index = SpatialIndex::new(&centers);
+
+        let (id, distance) = index.find_nearest_with_distance(Vec3::new(0.0, 13.0, 0.0));
+        assert_eq!(id, 1);
+        assert!((distance - 3.0).abs() < 1e-5);
+    }
 }
@@ -0,0 +1,134 @@
+//! Planet quality metrics and reports
+
+use std::collections::HashMap;
+#[cfg(feature = "profiling")]
+use std::time::Duration;
+
+use crate::generation::LloydReport;
+use crate::terrain::BasicTerrainType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Cell area statistics for judging point distribution uniformity
+///
+/// Produced by [`VoronoiPlanet::uniformity_report`](crate::VoronoiPlanet::uniformity_report).
+/// Areas are spherical surface areas in world units squared. Compare the
+/// `coefficient_of_variation` across Lloyd iteration counts to pick the
+/// cheapest setting that is uniform enough.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct UniformityReport {
+    /// Smallest cell area
+    pub min_area: f32,
+    /// Largest cell area
+    pub max_area: f32,
+    /// Mean cell area (ideal area for a perfectly uniform tessellation)
+    pub mean_area: f32,
+    /// Standard deviation of cell areas
+    pub std_dev: f32,
+    /// `std_dev / mean_area` (0 = perfectly uniform, lower is better)
+    pub coefficient_of_variation: f32,
+}
+
+impl UniformityReport {
+    /// Compute statistics from a list of cell areas
+    ///
+    /// Returns a zeroed report for an empty list.
+    pub fn from_areas(areas: &[f32]) -> Self {
+        if areas.is_empty() {
+            return Self::default();
+        }
+
+        let count = areas.len() as f64;
+        let mean = areas.iter().map(|&a| a as f64).sum::<f64>() / count;
+        let variance = areas
+            .iter()
+            .map(|&a| (a as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let std_dev = variance.sqrt();
+
+        Self {
+            min_area: areas.iter().copied().fold(f32::INFINITY, f32::min),
+            max_area: areas.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+            mean_area: mean as f32,
+            std_dev: std_dev as f32,
+            coefficient_of_variation: if mean > 0.0 { (std_dev / mean) as f32 } else { 0.0 },
+        }
+    }
+}
+
+/// Rendering cost and surface share of one terrain type
+///
+/// Produced per terrain by [`VoronoiPlanet::render_stats`](crate::VoronoiPlanet::render_stats).
+/// Triangle counts are those of the default fan mesh from
+/// [`generate_mesh`](crate::generate_mesh).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RenderStats {
+    /// Number of cells with this terrain
+    pub cell_count: usize,
+    /// Triangles these cells contribute to the default mesh
+    pub triangle_count: usize,
+    /// Total spherical surface area of these cells, in world units squared
+    pub spherical_area: f32,
+}
+
+/// Statistics collected while generating a planet
+///
+/// Produced by [`VoronoiPlanet::generate_with_report`](crate::VoronoiPlanet::generate_with_report)
+/// as structured data instead of log output, for tools that chart generation
+/// cost or tune Lloyd settings.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GenerationReport {
+    /// Number of cells in the generated planet
+    pub cell_count: usize,
+    /// Number of cells the configuration asked for
+    pub requested_cell_count: usize,
+    /// Points merged as duplicates and replaced before triangulation
+    pub merged_points: usize,
+    /// Lloyd's relaxation outcome (all zero if relaxation was disabled)
+    pub lloyd: LloydReport,
+    /// Number of cells of each terrain type (types without cells are absent)
+    pub terrain_counts: HashMap<BasicTerrainType, usize>,
+    /// Wall-clock time spent in each generation stage
+    #[cfg(feature = "profiling")]
+    pub timings: StageTimings,
+}
+
+/// Wall-clock duration of each generation stage
+///
+/// Only available with the `profiling` feature.
+#[cfg(feature = "profiling")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StageTimings {
+    /// Initial point distribution
+    pub points: Duration,
+    /// Lloyd's relaxation
+    pub lloyd: Duration,
+    /// Deduplication, triangulation and Voronoi cell construction
+    pub cells: Duration,
+    /// Terrain sampling and spatial index construction
+    pub terrain: Duration,
+    /// Whole generation, including the stages above
+    pub total: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_from_areas() {
+        let report = UniformityReport::from_areas(&[1.0, 2.0, 3.0]);
+        assert_eq!(report.min_area, 1.0);
+        assert_eq!(report.max_area, 3.0);
+        assert!((report.mean_area - 2.0).abs() < 1e-6);
+        assert!((report.std_dev - (2.0f32 / 3.0).sqrt()).abs() < 1e-6);
+
+        let uniform = UniformityReport::from_areas(&[5.0; 4]);
+        assert_eq!(uniform.coefficient_of_variation, 0.0);
+
+        assert_eq!(UniformityReport::from_areas(&[]), UniformityReport::default());
+    }
+}
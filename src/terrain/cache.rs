@@ -0,0 +1,202 @@
+//! Memoizing wrapper for terrain samplers
+
+use glam::Vec3;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use super::TerrainSampler;
+use crate::error::{Result, VoronoiError};
+
+/// Grid key: quantized position plus the radius bit pattern
+///
+/// 64-bit indices so fine grids on large planets never saturate and alias.
+type CacheKey = (i64, i64, i64, u32);
+
+/// Terrain sampler wrapper that caches results on a quantized position grid
+///
+/// Positions are snapped to a grid with spacing `resolution` (world units) and
+/// the wrapped sampler is evaluated at the snapped point. Because the sample
+/// location only depends on the grid cell, results are deterministic and
+/// independent of query order. Useful when an expensive sampler is queried
+/// many times at the same or nearby points (generation, rivers, moisture, ...).
+///
+/// The cache is guarded by a mutex, so the wrapper can be shared across threads.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+/// use rust_voronoi_planet::terrain::CachingSampler;
+///
+/// let cached = CachingSampler::new(PerlinTerrainSampler::new(42), 0.01).unwrap();
+/// let a = cached.sample(Vec3::new(10.0, 0.0, 0.0), 10.0);
+/// let b = cached.sample(Vec3::new(10.0, 0.0, 0.0), 10.0);
+/// assert_eq!(a, b);
+/// assert_eq!(cached.hits(), 1);
+/// ```
+pub struct CachingSampler<S: TerrainSampler> {
+    inner: S,
+    resolution: f32,
+    cache: Mutex<HashMap<CacheKey, S::Output>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl<S: TerrainSampler> CachingSampler<S> {
+    /// Wrap a sampler with a cache of the given grid resolution
+    ///
+    /// Smaller resolutions are more accurate but hit the cache less often.
+    /// Returns `InvalidConfig` if `resolution` is not a positive, finite number.
+    pub fn new(inner: S, resolution: f32) -> Result<Self> {
+        if !resolution.is_finite() || resolution <= 0.0 {
+            return Err(VoronoiError::InvalidConfig(format!(
+                "cache resolution must be positive, got {}",
+                resolution
+            )));
+        }
+
+        Ok(Self {
+            inner,
+            resolution,
+            cache: Mutex::new(HashMap::new()),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        })
+    }
+
+    /// Get the wrapped sampler
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Get the grid resolution in world units
+    pub fn resolution(&self) -> f32 {
+        self.resolution
+    }
+
+    /// Snap a position to the cache grid (the point actually sampled)
+    pub fn snap(&self, position: Vec3) -> Vec3 {
+        (position / self.resolution).round() * self.resolution
+    }
+
+    /// Number of queries answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of queries that had to call the wrapped sampler
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Number of cached entries
+    pub fn len(&self) -> usize {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Check if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all cached entries and reset the hit/miss counters
+    pub fn clear(&self) {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<S> TerrainSampler for CachingSampler<S>
+where
+    S: TerrainSampler,
+    S::Output: Clone,
+{
+    type Output = S::Output;
+
+    fn sample(&self, position: Vec3, radius: f32) -> S::Output {
+        let grid = (position / self.resolution).round();
+        let key = (grid.x as i64, grid.y as i64, grid.z as i64, radius.to_bits());
+
+        if let Some(value) = self.cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return value.clone();
+        }
+
+        // Sample outside the lock so slow samplers don't serialize other threads
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let value = self.inner.sample(grid * self.resolution, radius);
+        self.cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::PerlinTerrainSampler;
+
+    #[test]
+    fn test_cached_matches_wrapped() {
+        let cached = CachingSampler::new(PerlinTerrainSampler::new(42), 0.001).unwrap();
+        let reference = PerlinTerrainSampler::new(42);
+        let radius = 10.0;
+
+        for i in 0..300 {
+            let t = i as f32 * 0.13;
+            let pos = Vec3::new(t.cos(), (t * 0.4).sin(), t.sin()).normalize() * radius;
+            let snapped = cached.snap(pos);
+            assert_eq!(cached.sample(pos, radius), reference.sample(snapped, radius));
+        }
+        assert_eq!(cached.misses(), 300);
+    }
+
+    #[test]
+    fn test_cache_hits() {
+        let cached = CachingSampler::new(PerlinTerrainSampler::new(42), 0.1).unwrap();
+        let pos = Vec3::new(3.0, 4.0, 8.66);
+
+        let first = cached.sample(pos, 10.0);
+        // A nearby point in the same grid cell is answered from the cache
+        let second = cached.sample(pos + Vec3::splat(0.01), 10.0);
+
+        assert_eq!(first, second);
+        assert_eq!(cached.misses(), 1);
+        assert_eq!(cached.hits(), 1);
+        assert_eq!(cached.len(), 1);
+
+        cached.clear();
+        assert!(cached.is_empty());
+        assert_eq!(cached.hits(), 0);
+    }
+
+    #[test]
+    fn test_far_positions_do_not_alias() {
+        struct PositionSampler;
+        impl TerrainSampler for PositionSampler {
+            type Output = f32;
+            fn sample(&self, position: Vec3, _radius: f32) -> f32 {
+                position.x
+            }
+        }
+
+        // Both grid indices lie far beyond i32::MAX
+        let cached = CachingSampler::new(PositionSampler, 1e-3).unwrap();
+        let radius = 5.0e6;
+        assert_eq!(cached.sample(Vec3::new(3.0e6, 0.0, 0.0), radius), 3.0e6);
+        assert_eq!(cached.sample(Vec3::new(4.0e6, 0.0, 0.0), radius), 4.0e6);
+        assert_eq!(cached.misses(), 2);
+        assert_eq!(cached.len(), 2);
+    }
+
+    #[test]
+    fn test_rejects_non_positive_resolution() {
+        for resolution in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(CachingSampler::new(PerlinTerrainSampler::new(42), resolution).is_err());
+        }
+    }
+}
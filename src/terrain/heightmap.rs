@@ -0,0 +1,191 @@
+//! Terrain from an equirectangular heightmap
+//!
+//! The image is passed in as a flat float buffer so the crate stays
+//! independent of any image library: decode the file yourself and hand over
+//! one height value per pixel.
+
+use glam::Vec3;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use super::{classify_basic, BasicTerrainType, TerrainSampler};
+use crate::error::{Result, VoronoiError};
+use crate::geometry::longitude;
+
+/// Terrain sampler reading heights from an equirectangular image
+///
+/// Pixels are stored row-major, top row first. Rows run from the north pole
+/// (+Y) at the top to the south pole at the bottom; columns cover longitude
+/// [-π, π) in the crate convention ([`geometry::longitude`](crate::geometry::longitude)),
+/// so the left edge of the image sits on the antimeridian and the center
+/// column faces +X. Heights are bilinearly interpolated between pixel centers,
+/// wrapping across the left/right edges and clamping at the poles.
+///
+/// The interpolated height is classified with the same rules as
+/// [`PerlinTerrainSampler`](super::PerlinTerrainSampler). Default thresholds
+/// assume heights normalized to [0, 1] with sea level a little below 0.5, and
+/// ice caps are disabled, since an imported map usually paints its own.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+/// use rust_voronoi_planet::terrain::ImageHeightSampler;
+///
+/// // 4x2 map: left half ocean, right half land
+/// let data = vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0];
+/// let sampler = ImageHeightSampler::new(data, 4, 2).unwrap();
+///
+/// let config = PlanetConfigBuilder::new()
+///     .seed(42)
+///     .planet_size(PlanetSize::Tiny)
+///     .build()
+///     .unwrap();
+/// let planet = VoronoiPlanet::generate_with_sampler(config, &sampler).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ImageHeightSampler {
+    /// Height per pixel, row-major, `width * height` entries
+    pub data: Vec<f32>,
+    /// Image width in pixels
+    pub width: usize,
+    /// Image height in pixels
+    pub height: usize,
+    /// Threshold below which terrain is ocean (default: 0.44)
+    pub ocean_threshold: f32,
+    /// Threshold above which terrain is mountain (default: 0.7)
+    pub mountain_threshold: f32,
+    /// Latitude above which terrain is ice (default: 1.0, disabled)
+    pub ice_cap_latitude: f32,
+    /// Width of beach band above ocean threshold (default: 0.025)
+    pub beach_band: f32,
+}
+
+impl ImageHeightSampler {
+    /// Create a sampler from a row-major height buffer
+    ///
+    /// Returns `InvalidConfig` if either dimension is 0 or `data` does not
+    /// hold exactly `width * height` values.
+    pub fn new(data: Vec<f32>, width: usize, height: usize) -> Result<Self> {
+        if width == 0 || height == 0 {
+            return Err(VoronoiError::InvalidConfig(format!(
+                "heightmap must not be empty, got {}x{}",
+                width, height
+            )));
+        }
+        if data.len() != width * height {
+            return Err(VoronoiError::InvalidConfig(format!(
+                "heightmap is {}x{} but has {} values",
+                width,
+                height,
+                data.len()
+            )));
+        }
+
+        Ok(Self {
+            data,
+            width,
+            height,
+            ocean_threshold: 0.44,
+            mountain_threshold: 0.7,
+            ice_cap_latitude: 1.0,
+            beach_band: 0.025,
+        })
+    }
+
+    /// Bilinearly interpolated height at a position (only the direction matters)
+    pub fn elevation(&self, position: Vec3) -> f32 {
+        let dir = position.normalize_or_zero();
+        let latitude = dir.y.clamp(-1.0, 1.0).asin();
+
+        // Continuous pixel coordinates with pixel centers at integer + 0.5
+        let u = (longitude(dir) + PI) / TAU * self.width as f32 - 0.5;
+        let v = (FRAC_PI_2 - latitude) / PI * self.height as f32 - 0.5;
+
+        let x0 = u.floor();
+        let y0 = v.floor();
+        let (fx, fy) = (u - x0, v - y0);
+
+        let column = |x: f32| (x as i64).rem_euclid(self.width as i64) as usize;
+        let row = |y: f32| (y as i64).clamp(0, self.height as i64 - 1) as usize;
+        let pixel = |x: usize, y: usize| self.data[y * self.width + x];
+
+        let (left, right) = (column(x0), column(x0 + 1.0));
+        let (top, bottom) = (row(y0), row(y0 + 1.0));
+        let upper = pixel(left, top) * (1.0 - fx) + pixel(right, top) * fx;
+        let lower = pixel(left, bottom) * (1.0 - fx) + pixel(right, bottom) * fx;
+        upper * (1.0 - fy) + lower * fy
+    }
+}
+
+impl TerrainSampler for ImageHeightSampler {
+    type Output = BasicTerrainType;
+
+    fn sample(&self, position: Vec3, radius: f32) -> BasicTerrainType {
+        let latitude = (position.y / radius).abs();
+        classify_basic(
+            latitude,
+            self.elevation(position),
+            self.ocean_threshold,
+            self.beach_band,
+            self.mountain_threshold,
+            self.ice_cap_latitude,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{PlanetConfigBuilder, PlanetSize};
+    use crate::VoronoiPlanet;
+
+    /// Direction at continuous pixel coordinates (pixel centers at integer + 0.5)
+    fn direction_at(sampler: &ImageHeightSampler, u: f32, v: f32) -> Vec3 {
+        let lon = u / sampler.width as f32 * TAU - PI;
+        let lat = FRAC_PI_2 - v / sampler.height as f32 * PI;
+        Vec3::new(lat.cos() * lon.cos(), lat.sin(), lat.cos() * lon.sin())
+    }
+
+    #[test]
+    fn test_new_validates_size() {
+        assert!(ImageHeightSampler::new(vec![0.0; 6], 3, 2).is_ok());
+        assert!(ImageHeightSampler::new(vec![0.0; 5], 3, 2).is_err());
+        assert!(ImageHeightSampler::new(Vec::new(), 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_all_high_is_land() {
+        let sampler = ImageHeightSampler::new(vec![0.6; 32 * 16], 32, 16).unwrap();
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Tiny)
+            .build()
+            .unwrap();
+        let planet = VoronoiPlanet::generate_with_sampler(config, &sampler).unwrap();
+        assert!(planet.cells().iter().all(|c| c.terrain == BasicTerrainType::Land));
+    }
+
+    #[test]
+    fn test_bilinear_interpolation() {
+        // Row 0: 0, 1, 2, 3; row 1: 10, 11, 12, 13
+        let data = vec![0.0, 1.0, 2.0, 3.0, 10.0, 11.0, 12.0, 13.0];
+        let sampler = ImageHeightSampler::new(data, 4, 2).unwrap();
+        let at = |u, v| sampler.elevation(direction_at(&sampler, u, v) * 5.0);
+
+        // Pixel centers return the pixel value
+        assert!((at(1.5, 0.5) - 1.0).abs() < 1e-4);
+        assert!((at(2.5, 1.5) - 12.0).abs() < 1e-4);
+
+        // Halfway between neighbors horizontally and vertically
+        assert!((at(2.0, 0.5) - 1.5).abs() < 1e-4);
+        assert!((at(1.5, 1.0) - 6.0).abs() < 1e-4);
+        assert!((at(1.75, 0.75) - 3.75).abs() < 1e-4);
+
+        // Wraps across the antimeridian between the last and first column
+        assert!((at(0.0, 0.5) - 1.5).abs() < 1e-4);
+
+        // Clamps to the top and bottom rows at the poles
+        assert!((sampler.elevation(Vec3::Y) - 1.5).abs() < 1e-4);
+        assert!((sampler.elevation(Vec3::NEG_Y) - 11.5).abs() < 1e-4);
+    }
+}
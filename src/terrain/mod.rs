@@ -2,12 +2,45 @@
 //!
 //! Provides traits and implementations for sampling terrain on sphere surfaces.
 
+mod cache;
+mod heightmap;
 mod perlin;
+pub mod spharm;
 
-pub use perlin::{PerlinConfig, sample_perlin_3d, sample_perlin_fbm};
+pub use cache::CachingSampler;
+pub use heightmap::ImageHeightSampler;
+pub use perlin::{PerlinConfig, perlin_3d_gradient, sample_perlin_3d, sample_perlin_fbm, sample_perlin_fbm_with_gradient};
+pub use spharm::SphericalHarmonicSampler;
 
 use glam::Vec3;
 
+use crate::config::{ContinentStyle, PlanetConfig};
+use crate::generation::RawCell;
+
+/// Rotate a sphere position into the orientation used for noise sampling
+///
+/// Samplers call this before looking up noise so terrain lands in the same
+/// place as in the reference implementation this crate was ported from. That
+/// renderer displays the planet rotated 180° about the vertical (Y) axis, so
+/// X and Z are negated while Y is kept; latitude-based rules (ice caps) are
+/// unaffected. Custom samplers should use it too if they want their features
+/// to line up with `PerlinTerrainSampler`.
+///
+/// Changing this transform moves every continent, so it is pinned by tests.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::{terrain::orient_sample_position, Vec3};
+///
+/// let oriented = orient_sample_position(Vec3::new(1.0, 2.0, 3.0));
+/// assert_eq!(oriented, Vec3::new(-1.0, 2.0, -3.0));
+/// ```
+#[inline]
+pub fn orient_sample_position(position: Vec3) -> Vec3 {
+    Vec3::new(-position.x, position.y, -position.z)
+}
+
 /// Basic terrain types for planet surfaces
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -46,6 +79,48 @@ pub trait TerrainSampler {
     fn sample(&self, position: Vec3, radius: f32) -> Self::Output;
 }
 
+/// Auxiliary per-cell values produced alongside the terrain classification
+///
+/// Stored in parallel with the cells when a planet is generated with a
+/// [`RichTerrainSampler`], so displacement, rivers, or biome logic can reuse
+/// the raw noise values instead of sampling again.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TerrainData {
+    /// Raw elevation value (sampler-specific range, roughly [-1, 1] for Perlin)
+    pub elevation: f32,
+    /// Temperature in [0, 1] (0 = coldest, 1 = hottest)
+    pub temperature: f32,
+    /// Moisture in [0, 1] (0 = driest, 1 = wettest)
+    pub moisture: f32,
+}
+
+/// Trait for samplers that return terrain plus auxiliary data in one pass
+///
+/// Implement this when the terrain classification is derived from values
+/// (elevation, temperature, moisture) that are also useful after generation.
+pub trait RichTerrainSampler: TerrainSampler {
+    /// Sample terrain and auxiliary data at a 3D position on the sphere surface
+    fn sample_rich(&self, position: Vec3, radius: f32) -> (Self::Output, TerrainData);
+}
+
+/// Second-pass terrain rule that sees each cell's neighborhood
+///
+/// Used by [`VoronoiPlanet::generate_with_context`](crate::VoronoiPlanet::generate_with_context):
+/// after every cell has base terrain from a [`TerrainSampler`], `refine` runs
+/// once per cell with the cell's geometry, its base terrain and the base
+/// terrain of its neighbors (in the order of `cell.neighbors`). All cells read
+/// the base terrain, never already refined values, so the result does not
+/// depend on cell order. Rules like "beaches only next to ocean" or "volcanoes
+/// only among mountains" fit here.
+pub trait ContextSampler {
+    /// The terrain type refined by this sampler
+    type Output;
+
+    /// Decide the final terrain of a cell from its base terrain and surroundings
+    fn refine(&self, cell: &RawCell, terrain: &Self::Output, neighbors_terrain: &[Self::Output]) -> Self::Output;
+}
+
 /// Default terrain sampler using 3D Perlin noise
 pub struct PerlinTerrainSampler {
     /// Seed for noise generation
@@ -58,6 +133,26 @@ pub struct PerlinTerrainSampler {
     pub ice_cap_latitude: f32,
     /// Width of beach band above ocean threshold (default: 0.05)
     pub beach_band: f32,
+    /// Axial tilt in degrees (default: 0.0)
+    ///
+    /// Tilts the climate pole from +Y towards +X before latitude is computed,
+    /// shifting ice caps and the temperature gradient off the geometric poles.
+    pub axial_tilt_deg: f32,
+    /// Half-width of the dithering band around each threshold (default: 0.0, off)
+    ///
+    /// Elevations within this distance of the ocean, beach or mountain
+    /// threshold are nudged by a stable per-position offset in
+    /// `[-threshold_dither, threshold_dither]` before classification. Coasts
+    /// become ragged instead of following a single contour line, and a cell's
+    /// side of the threshold no longer hinges on the last bits of its
+    /// elevation. Cells outside every band are unaffected.
+    pub threshold_dither: f32,
+    /// Frequency of the continent noise layer (default: 0.125)
+    ///
+    /// Lower values give fewer, larger landmasses.
+    pub continent_frequency: f32,
+    /// Strength of the domain warp bending coastlines (default: 1.75)
+    pub warp_strength: f32,
     /// Perlin noise configuration
     pub config: PerlinConfig,
 }
@@ -70,6 +165,10 @@ impl Default for PerlinTerrainSampler {
             mountain_threshold: 0.4,
             ice_cap_latitude: 0.85,
             beach_band: 0.05,
+            axial_tilt_deg: 0.0,
+            threshold_dither: 0.0,
+            continent_frequency: 0.125,
+            warp_strength: 1.75,
             config: PerlinConfig::default(),
         }
     }
@@ -92,43 +191,182 @@ impl PerlinTerrainSampler {
             ..Default::default()
         }
     }
-}
 
-impl TerrainSampler for PerlinTerrainSampler {
-    type Output = BasicTerrainType;
+    /// Create the sampler `VoronoiPlanet::generate` uses for a configuration
+    ///
+    /// Seeded with `terrain_seed` and shaped by `continent_style`.
+    pub fn from_planet_config(config: &PlanetConfig) -> Self {
+        Self::new(config.terrain_seed).with_continent_style(config.continent_style)
+    }
 
-    fn sample(&self, position: Vec3, radius: f32) -> BasicTerrainType {
-        // Check for polar ice caps
-        let latitude = (position.y / radius).abs();
-        if latitude > self.ice_cap_latitude {
-            return BasicTerrainType::Ice;
-        }
+    /// Shape the continents according to a [`ContinentStyle`]
+    ///
+    /// Sets `continent_frequency`, `warp_strength` and `ocean_threshold`.
+    /// `Fragmented` raises the sea level as well, since islands only separate
+    /// once ocean covers most of the surface.
+    pub fn with_continent_style(mut self, style: ContinentStyle) -> Self {
+        let (frequency, warp, ocean) = match style {
+            ContinentStyle::Pangaea => (0.06, 2.5, -0.12),
+            ContinentStyle::Fragmented => (0.35, 1.0, 0.1),
+            ContinentStyle::Balanced => (0.125, 1.75, -0.12),
+        };
+        self.continent_frequency = frequency;
+        self.warp_strength = warp;
+        self.ocean_threshold = ocean;
+        self
+    }
+}
+
+impl PerlinTerrainSampler {
+    /// Absolute latitude in [0, 1] relative to the (tilted) climate pole
+    fn latitude(&self, position: Vec3, radius: f32) -> f32 {
+        let (sin, cos) = self.axial_tilt_deg.to_radians().sin_cos();
+        let pole = Vec3::new(sin, cos, 0.0);
+        (position.dot(pole) / radius).abs()
+    }
 
-        // Apply coordinate transformation for consistency
-        let sampling_pos = Vec3::new(-position.x, position.y, -position.z);
+    /// Sample the raw continent elevation (approximately [-1, 1])
+    fn elevation(&self, position: Vec3) -> f32 {
+        let sampling_pos = orient_sample_position(position);
 
         // Domain warping for organic coastlines
         let warp_freq = 0.15;
-        let warp_strength = 1.75;
+        let warp_strength = self.warp_strength;
         let warp_x = sample_perlin_fbm(sampling_pos * warp_freq, self.seed.wrapping_add(1000), 3, 0.5, 2.0);
         let warp_y = sample_perlin_fbm(sampling_pos * warp_freq, self.seed.wrapping_add(2000), 3, 0.5, 2.0);
         let warp_z = sample_perlin_fbm(sampling_pos * warp_freq, self.seed.wrapping_add(3000), 3, 0.5, 2.0);
         let warped_pos = sampling_pos + Vec3::new(warp_x, warp_y, warp_z) * warp_strength;
 
         // Sample continent base
-        let continent_freq = 0.125;
-        let elevation = sample_perlin_fbm(warped_pos * continent_freq, self.seed, 1, 0.5, 2.0);
-
-        // Classify terrain
-        if elevation < self.ocean_threshold {
-            BasicTerrainType::Ocean
-        } else if elevation < self.ocean_threshold + self.beach_band {
-            BasicTerrainType::Beach
-        } else if elevation > self.mountain_threshold {
-            BasicTerrainType::Mountain
-        } else {
-            BasicTerrainType::Land
+        sample_perlin_fbm(warped_pos * self.continent_frequency, self.seed, 1, 0.5, 2.0)
+    }
+
+    /// Distance from the sampled elevation to the nearest classification threshold
+    ///
+    /// Small values mark "fragile" cells whose terrain type would flip with a
+    /// slight change of noise or thresholds, such as ragged coastlines. Uses the
+    /// raw elevation from [`RichTerrainSampler::sample_rich`] and the ocean,
+    /// beach and mountain thresholds; the latitude-based ice caps and
+    /// `threshold_dither` are not considered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_voronoi_planet::{terrain::PerlinTerrainSampler, Vec3};
+    ///
+    /// let sampler = PerlinTerrainSampler::new(42);
+    /// let margin = sampler.classification_margin(Vec3::new(10.0, 0.0, 0.0), 10.0);
+    /// assert!(margin >= 0.0);
+    /// ```
+    pub fn classification_margin(&self, position: Vec3, _radius: f32) -> f32 {
+        let elevation = self.elevation(position);
+        [
+            self.ocean_threshold,
+            self.ocean_threshold + self.beach_band,
+            self.mountain_threshold,
+        ]
+        .iter()
+        .map(|t| (elevation - t).abs())
+        .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Apply threshold dithering to an elevation (see `threshold_dither`)
+    fn dithered(&self, position: Vec3, elevation: f32) -> f32 {
+        let band = self.threshold_dither;
+        let thresholds = [
+            self.ocean_threshold,
+            self.ocean_threshold + self.beach_band,
+            self.mountain_threshold,
+        ];
+        if band <= 0.0 || thresholds.iter().all(|t| (elevation - t).abs() >= band) {
+            return elevation;
+        }
+        elevation + position_hash(position, self.seed) * band
+    }
+
+    /// Classify terrain from latitude and elevation
+    fn classify(&self, position: Vec3, latitude: f32, elevation: f32) -> BasicTerrainType {
+        classify_basic(
+            latitude,
+            self.dithered(position, elevation),
+            self.ocean_threshold,
+            self.beach_band,
+            self.mountain_threshold,
+            self.ice_cap_latitude,
+        )
+    }
+}
+
+/// Stable pseudo-random value in [-1, 1] for a sphere position
+///
+/// Depends only on the direction (quantized to ~1e-5) and the seed, so the
+/// same cell center always gets the same value at any radius.
+fn position_hash(position: Vec3, seed: u32) -> f32 {
+    let q = (position.normalize_or_zero() * 100_000.0).round();
+    let mut z = (q.x as i32 as u32 as u64)
+        ^ (q.y as i32 as u32 as u64) << 21
+        ^ (q.z as i32 as u32 as u64) << 42
+        ^ (seed as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
+
+/// Shared threshold classification for the built-in samplers
+fn classify_basic(
+    latitude: f32,
+    elevation: f32,
+    ocean_threshold: f32,
+    beach_band: f32,
+    mountain_threshold: f32,
+    ice_cap_latitude: f32,
+) -> BasicTerrainType {
+    // Check for polar ice caps
+    if latitude > ice_cap_latitude {
+        return BasicTerrainType::Ice;
+    }
+
+    if elevation < ocean_threshold {
+        BasicTerrainType::Ocean
+    } else if elevation < ocean_threshold + beach_band {
+        BasicTerrainType::Beach
+    } else if elevation > mountain_threshold {
+        BasicTerrainType::Mountain
+    } else {
+        BasicTerrainType::Land
+    }
+}
+
+impl TerrainSampler for PerlinTerrainSampler {
+    type Output = BasicTerrainType;
+
+    fn sample(&self, position: Vec3, radius: f32) -> BasicTerrainType {
+        // Skip the noise entirely inside the ice caps
+        let latitude = self.latitude(position, radius);
+        if latitude > self.ice_cap_latitude {
+            return BasicTerrainType::Ice;
         }
+
+        self.classify(position, latitude, self.elevation(position))
+    }
+}
+
+impl RichTerrainSampler for PerlinTerrainSampler {
+    fn sample_rich(&self, position: Vec3, radius: f32) -> (BasicTerrainType, TerrainData) {
+        let latitude = self.latitude(position, radius);
+        let elevation = self.elevation(position);
+
+        // Colder towards the poles and at altitude
+        let temperature = (1.0 - latitude - elevation.max(0.0) * 0.5).clamp(0.0, 1.0);
+
+        // Independent low-frequency noise layer for moisture
+        let sampling_pos = orient_sample_position(position);
+        let moisture = sample_perlin_fbm(sampling_pos * 0.1, self.seed.wrapping_add(4000), 3, 0.5, 2.0);
+        let moisture = ((moisture + 1.0) * 0.5).clamp(0.0, 1.0);
+
+        let terrain = self.classify(position, latitude, elevation);
+        (terrain, TerrainData { elevation, temperature, moisture })
     }
 }
 
@@ -180,6 +418,26 @@ mod tests {
         assert_eq!(terrain, BasicTerrainType::Ice);
     }
 
+    /// Test that axial tilt moves the ice caps off the geometric poles
+    #[test]
+    fn test_axial_tilt() {
+        let sampler = PerlinTerrainSampler {
+            axial_tilt_deg: 90.0,
+            ..PerlinTerrainSampler::new(42)
+        };
+        let radius = 1.0;
+
+        // Cold region now sits on the geometric equator
+        assert_eq!(sampler.sample(Vec3::X, radius), BasicTerrainType::Ice);
+        assert_eq!(sampler.sample(Vec3::NEG_X, radius), BasicTerrainType::Ice);
+        assert_ne!(sampler.sample(Vec3::Y, radius), BasicTerrainType::Ice);
+        assert_ne!(sampler.sample(Vec3::NEG_Y, radius), BasicTerrainType::Ice);
+
+        let (_, pole) = sampler.sample_rich(Vec3::Y, radius);
+        let (_, equator) = sampler.sample_rich(Vec3::X, radius);
+        assert!(equator.temperature < pole.temperature);
+    }
+
     /// Test determinism of terrain sampling
     #[test]
     fn test_terrain_determinism() {
@@ -227,4 +485,126 @@ mod tests {
         assert!(!BasicTerrainType::Ice.is_water());
         assert!(BasicTerrainType::Ice.is_land());
     }
+
+    /// Pin the sampling orientation (180° rotation about Y)
+    #[test]
+    fn test_orient_sample_position() {
+        assert_eq!(
+            orient_sample_position(Vec3::new(1.0, 2.0, 3.0)),
+            Vec3::new(-1.0, 2.0, -3.0)
+        );
+        assert_eq!(orient_sample_position(Vec3::Y), Vec3::Y);
+
+        let p = Vec3::new(0.3, -0.4, 0.5);
+        assert_eq!(orient_sample_position(orient_sample_position(p)), p);
+    }
+
+    /// Pin terrain output so refactors of the sampling path don't move continents
+    #[test]
+    fn test_terrain_golden_values() {
+        use BasicTerrainType::*;
+
+        let expected = [
+            Ocean, Mountain, Ocean, Ocean, Ocean, Land, Land, Land, Mountain, Ocean, Land, Land,
+            Beach, Land, Land, Ocean, Ocean, Beach, Ocean, Beach, Land, Mountain, Mountain, Land,
+        ];
+
+        let sampler = PerlinTerrainSampler::new(42);
+        let radius = 10.0;
+        for (i, &terrain) in expected.iter().enumerate() {
+            let t = i as f32 * 0.9;
+            let pos = Vec3::new(t.cos(), (t * 0.37).sin() * 0.8, t.sin()).normalize() * radius;
+            assert_eq!(sampler.sample(pos, radius), terrain, "sample {} changed", i);
+        }
+    }
+
+    /// Test that rich sampling classifies identically to plain sampling
+    #[test]
+    fn test_rich_sample_matches_sample() {
+        let sampler = PerlinTerrainSampler::new(42);
+        let radius = 10.0;
+
+        for i in 0..200 {
+            let t = i as f32 * 0.37;
+            let pos = Vec3::new(t.cos(), (t * 0.21).sin(), t.sin()).normalize() * radius;
+            let (terrain, data) = sampler.sample_rich(pos, radius);
+
+            assert_eq!(terrain, sampler.sample(pos, radius));
+            assert!((0.0..=1.0).contains(&data.temperature));
+            assert!((0.0..=1.0).contains(&data.moisture));
+        }
+    }
+
+    /// Test that the margin is large far from thresholds and small near them
+    #[test]
+    fn test_classification_margin() {
+        let sampler = PerlinTerrainSampler::new(42);
+        let radius = 10.0;
+
+        let samples: Vec<(Vec3, f32)> = (0..2000)
+            .map(|i| {
+                let t = i as f32 * 0.37;
+                let pos = Vec3::new(t.cos(), (t * 0.21).sin(), t.sin()).normalize() * radius;
+                (pos, sampler.sample_rich(pos, radius).1.elevation)
+            })
+            .collect();
+
+        let (deep, deep_elevation) = samples.iter().copied().min_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert_eq!(sampler.sample(deep, radius), BasicTerrainType::Ocean);
+        let deep_margin = sampler.classification_margin(deep, radius);
+        assert!((deep_margin - (sampler.ocean_threshold - deep_elevation)).abs() < 1e-6);
+        assert!(deep_margin > 0.2, "deep ocean margin {}", deep_margin);
+
+        let (coast, _) = samples
+            .iter()
+            .copied()
+            .min_by(|a, b| (a.1 - sampler.ocean_threshold).abs().total_cmp(&(b.1 - sampler.ocean_threshold).abs()))
+            .unwrap();
+        let coast_margin = sampler.classification_margin(coast, radius);
+        assert!(coast_margin < 0.01, "coastal margin {}", coast_margin);
+    }
+
+    /// Test that dithering only touches cells near a threshold
+    #[test]
+    fn test_threshold_dither() {
+        let plain = PerlinTerrainSampler::new(42);
+        let dithered = PerlinTerrainSampler {
+            threshold_dither: 0.03,
+            ..PerlinTerrainSampler::new(42)
+        };
+        let radius = 10.0;
+        let thresholds = [
+            plain.ocean_threshold,
+            plain.ocean_threshold + plain.beach_band,
+            plain.mountain_threshold,
+        ];
+
+        let mut changed = 0;
+        for i in 0..2000 {
+            let t = i as f32 * 0.37;
+            let pos = Vec3::new(t.cos(), (t * 0.21).sin(), t.sin()).normalize() * radius;
+            let (before, data) = plain.sample_rich(pos, radius);
+            let (after, after_data) = dithered.sample_rich(pos, radius);
+
+            // Auxiliary data keeps the raw elevation
+            assert_eq!(data, after_data);
+            assert_eq!(after, dithered.sample(pos, radius));
+
+            let near = thresholds.iter().any(|t| (data.elevation - t).abs() < 0.03);
+            if !near {
+                assert_eq!(before, after, "sample {} far from thresholds changed", i);
+            } else if before != after {
+                changed += 1;
+            }
+
+            // Deterministic per position
+            assert_eq!(after, dithered.sample(pos, radius));
+        }
+        assert!(changed > 0, "dithering never changed a near-threshold cell");
+
+        // The hash covers [-1, 1]
+        let hashes: Vec<f32> = (0..1000).map(|i| position_hash(Vec3::new(i as f32, 1.0, 2.0), 7)).collect();
+        assert!(hashes.iter().all(|h| (-1.0..=1.0).contains(h)));
+        assert!(hashes.iter().any(|&h| h < -0.9) && hashes.iter().any(|&h| h > 0.9));
+    }
 }
@@ -106,6 +106,33 @@ fn fade(t: f32) -> f32 {
     t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
 }
 
+/// Derivative of the fade function: 30t²(t - 1)²
+#[inline]
+fn fade_derivative(t: f32) -> f32 {
+    30.0 * t * t * (t - 1.0) * (t - 1.0)
+}
+
+/// Gradient vector selected by a hash value
+///
+/// Satisfies `gradient(h, x, y, z) == gradient_vector(h).dot(Vec3::new(x, y, z))`.
+#[inline]
+fn gradient_vector(hash_value: u32) -> Vec3 {
+    let h = hash_value & 15;
+    let u = if h < 8 { Vec3::X } else { Vec3::Y };
+    let v = if h < 4 {
+        Vec3::Y
+    } else if h == 12 || h == 14 {
+        Vec3::Z
+    } else {
+        Vec3::X
+    };
+
+    let sign_u = if (h & 1) == 0 { -u } else { u };
+    let sign_v = if (h & 2) == 0 { -v } else { v };
+
+    sign_u + sign_v
+}
+
 /// Linear interpolation
 #[inline]
 fn lerp(a: f32, b: f32, t: f32) -> f32 {
@@ -178,6 +205,89 @@ fn perlin_3d(pos: Vec3, seed: u32) -> f32 {
     lerp(y0_val, y1_val, w)
 }
 
+/// Sample 3D Perlin noise together with its analytic gradient
+///
+/// The value is bit-identical to `perlin_3d`. The gradient differentiates the
+/// trilinear blend: corner dot products change with the corner gradient
+/// vectors, and the blend weights change with the fade curve derivatives.
+fn perlin_3d_with_gradient(pos: Vec3, seed: u32) -> (f32, Vec3) {
+    let floor = pos.floor();
+    let (x0, y0, z0) = (floor.x as i32, floor.y as i32, floor.z as i32);
+    let (x1, y1, z1) = (x0 + 1, y0 + 1, z0 + 1);
+
+    let xf = pos.x - pos.x.floor();
+    let yf = pos.y - pos.y.floor();
+    let zf = pos.z - pos.z.floor();
+
+    let u = fade(xf);
+    let v = fade(yf);
+    let w = fade(zf);
+
+    // Corners in (x, y, z) bit order: aaa, baa, aba, bba, aab, bab, abb, bbb
+    let corners = [
+        (x0, y0, z0),
+        (x1, y0, z0),
+        (x0, y1, z0),
+        (x1, y1, z0),
+        (x0, y0, z1),
+        (x1, y0, z1),
+        (x0, y1, z1),
+        (x1, y1, z1),
+    ];
+    let mut dots = [0.0; 8];
+    let mut grads = [Vec3::ZERO; 8];
+    for (i, &(cx, cy, cz)) in corners.iter().enumerate() {
+        let h = hash(cx, cy, cz, seed);
+        let dx = if i & 1 == 0 { xf } else { xf - 1.0 };
+        let dy = if i & 2 == 0 { yf } else { yf - 1.0 };
+        let dz = if i & 4 == 0 { zf } else { zf - 1.0 };
+        dots[i] = gradient(h, dx, dy, dz);
+        grads[i] = gradient_vector(h);
+    }
+
+    // Same interpolation order as perlin_3d so the value matches exactly
+    let x00 = lerp(dots[0], dots[1], u);
+    let x10 = lerp(dots[2], dots[3], u);
+    let x01 = lerp(dots[4], dots[5], u);
+    let x11 = lerp(dots[6], dots[7], u);
+    let y0_val = lerp(x00, x10, v);
+    let y1_val = lerp(x01, x11, v);
+    let value = lerp(y0_val, y1_val, w);
+
+    // Partial derivatives of the blend with respect to the fade weights
+    let d_u = lerp(
+        lerp(dots[1] - dots[0], dots[3] - dots[2], v),
+        lerp(dots[5] - dots[4], dots[7] - dots[6], v),
+        w,
+    );
+    let d_v = lerp(x10 - x00, x11 - x01, w);
+    let d_w = y1_val - y0_val;
+
+    // Blend of the corner gradient vectors with the same weights
+    let g00 = grads[0].lerp(grads[1], u);
+    let g10 = grads[2].lerp(grads[3], u);
+    let g01 = grads[4].lerp(grads[5], u);
+    let g11 = grads[6].lerp(grads[7], u);
+    let blended = g00.lerp(g10, v).lerp(g01.lerp(g11, v), w);
+
+    let gradient = blended
+        + Vec3::new(
+            d_u * fade_derivative(xf),
+            d_v * fade_derivative(yf),
+            d_w * fade_derivative(zf),
+        );
+    (value, gradient)
+}
+
+/// Compute the analytic gradient of raw 3D Perlin noise
+///
+/// Returns the partial derivatives of the `[-1, 1]` Perlin function at `pos`.
+/// The noise is C1-continuous, so the gradient is continuous across lattice
+/// cells. Use it to shade displaced terrain without finite differences.
+pub fn perlin_3d_gradient(pos: Vec3, seed: u32) -> Vec3 {
+    perlin_3d_with_gradient(pos, seed).1
+}
+
 // ============================================================================
 // FRACTAL BROWNIAN MOTION (FBM)
 // ============================================================================
@@ -253,6 +363,41 @@ pub fn sample_perlin_fbm(
     total / max_value
 }
 
+/// Sample FBM together with its analytic gradient
+///
+/// Same parameters and value as [`sample_perlin_fbm`]; the gradient is the
+/// derivative of that value with respect to `position`. For a terrain height
+/// `h(p)` on a sphere, the surface normal follows from removing the radial
+/// part of the gradient.
+///
+/// # Returns
+/// `(value, gradient)` with value in range [-1, 1] (raw FBM output)
+pub fn sample_perlin_fbm_with_gradient(
+    position: Vec3,
+    seed: u32,
+    octaves: usize,
+    persistence: f32,
+    lacunarity: f32,
+) -> (f32, Vec3) {
+    let mut total = 0.0;
+    let mut gradient = Vec3::ZERO;
+    let mut amplitude = 1.0;
+    let mut frequency = 1.0;
+    let mut max_value = 0.0;
+
+    for _ in 0..octaves {
+        let (value, octave_gradient) = perlin_3d_with_gradient(position * frequency, seed);
+        total += value * amplitude;
+        // Chain rule: d/dp noise(p * f) = f * noise'(p * f)
+        gradient += octave_gradient * (amplitude * frequency);
+        max_value += amplitude;
+        amplitude *= persistence;
+        frequency *= lacunarity;
+    }
+
+    (total / max_value, gradient / max_value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,7 +436,7 @@ mod tests {
         for position in test_positions {
             let value = sample_perlin_3d(position, seed, &config);
             assert!(
-                value >= 0.0 && value <= 1.0,
+                (0.0..=1.0).contains(&value),
                 "Value {} at position {:?} is outside [0.0, 1.0] range",
                 value,
                 position
@@ -322,7 +467,7 @@ mod tests {
         // Test basic Perlin output range [-1, 1]
         let value = perlin_3d(Vec3::new(0.0, 0.0, 0.0), seed);
         assert!(
-            value >= -1.0 && value <= 1.0,
+            (-1.0..=1.0).contains(&value),
             "Raw Perlin should be in [-1, 1] range, got {}",
             value
         );
@@ -344,9 +489,56 @@ mod tests {
 
         // FBM output should be approximately in [-1, 1], though might slightly exceed
         assert!(
-            value >= -1.5 && value <= 1.5,
+            (-1.5..=1.5).contains(&value),
             "FBM value {} is outside reasonable range",
             value
         );
     }
+
+    /// Test the analytic gradient against central finite differences
+    #[test]
+    fn test_gradient_matches_finite_difference() {
+        let seed = 7;
+        let h = 1e-3;
+        let positions = [
+            Vec3::new(0.3, 1.7, -2.2),
+            Vec3::new(4.1, -0.6, 0.9),
+            Vec3::new(-3.45, 2.05, 5.5),
+        ];
+
+        for pos in positions {
+            let (value, analytic) = perlin_3d_with_gradient(pos, seed);
+            assert_eq!(value, perlin_3d(pos, seed));
+
+            let numeric = Vec3::new(
+                perlin_3d(pos + Vec3::X * h, seed) - perlin_3d(pos - Vec3::X * h, seed),
+                perlin_3d(pos + Vec3::Y * h, seed) - perlin_3d(pos - Vec3::Y * h, seed),
+                perlin_3d(pos + Vec3::Z * h, seed) - perlin_3d(pos - Vec3::Z * h, seed),
+            ) / (2.0 * h);
+            assert!(
+                (analytic - numeric).length() < 1e-2,
+                "at {:?}: analytic {:?} vs numeric {:?}",
+                pos,
+                analytic,
+                numeric
+            );
+            assert_eq!(perlin_3d_gradient(pos, seed), analytic);
+
+            let fbm = |p| sample_perlin_fbm(p, seed, 4, 0.5, 2.0);
+            let (fbm_value, fbm_analytic) = sample_perlin_fbm_with_gradient(pos, seed, 4, 0.5, 2.0);
+            assert_eq!(fbm_value, fbm(pos));
+            let fbm_numeric = Vec3::new(
+                fbm(pos + Vec3::X * h) - fbm(pos - Vec3::X * h),
+                fbm(pos + Vec3::Y * h) - fbm(pos - Vec3::Y * h),
+                fbm(pos + Vec3::Z * h) - fbm(pos - Vec3::Z * h),
+            ) / (2.0 * h);
+            assert!(
+                (fbm_analytic - fbm_numeric).length() < 5e-2,
+                "fbm at {:?}: analytic {:?} vs numeric {:?}",
+                pos,
+                fbm_analytic,
+                fbm_numeric
+            );
+        }
+    }
 }
@@ -0,0 +1,286 @@
+//! Spherical harmonic terrain
+//!
+//! Elevation is a random expansion in real spherical harmonics up to a chosen
+//! degree. Because the basis functions live on the sphere itself, the result
+//! has no seams, pole artifacts or tiling, and low degrees produce a few
+//! planet-scale continents. Higher degrees add more, smaller landmasses.
+
+use glam::Vec3;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::f64::consts::PI;
+
+use super::{classify_basic, BasicTerrainType, TerrainSampler};
+use crate::geometry::longitude;
+
+/// Highest supported expansion degree (keeps the Legendre recurrence in range)
+pub const MAX_DEGREE: u32 = 64;
+
+/// Target RMS of the raw elevation, comparable to the Perlin sampler's range
+const ELEVATION_RMS: f64 = 0.35;
+
+/// Terrain sampler evaluating a seeded spherical harmonic expansion
+///
+/// Elevation is normalized to an RMS of about 0.35 and clamped to [-1, 1],
+/// then classified with the same thresholds as
+/// [`PerlinTerrainSampler`](super::PerlinTerrainSampler). Latitude is measured
+/// along +Y.
+///
+/// # Example
+///
+/// ```
+/// use rust_voronoi_planet::*;
+/// use rust_voronoi_planet::terrain::SphericalHarmonicSampler;
+///
+/// let config = PlanetConfigBuilder::new()
+///     .seed(42)
+///     .planet_size(PlanetSize::Tiny)
+///     .build()
+///     .unwrap();
+/// let sampler = SphericalHarmonicSampler::new(6, config.terrain_seed);
+/// let planet = VoronoiPlanet::generate_with_sampler(config, &sampler).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SphericalHarmonicSampler {
+    degree: u32,
+    seed: u32,
+    coefficients: Vec<f32>,
+    /// Orthonormalization factor per `l * (l + 1) / 2 + m` for `m` in `0..=l`,
+    /// including the √2 of the real `m > 0` terms
+    norms: Vec<f64>,
+    /// Factor taking the raw expansion to an RMS of `ELEVATION_RMS` (0 if flat)
+    scale: f64,
+    /// Threshold below which terrain is ocean (default: -0.12)
+    pub ocean_threshold: f32,
+    /// Threshold above which terrain is mountain (default: 0.4)
+    pub mountain_threshold: f32,
+    /// Latitude above which terrain is ice (default: 0.85)
+    pub ice_cap_latitude: f32,
+    /// Width of beach band above ocean threshold (default: 0.05)
+    pub beach_band: f32,
+}
+
+impl SphericalHarmonicSampler {
+    /// Create a sampler with random coefficients up to `degree`
+    ///
+    /// Each degree contributes the same expected variance, so raising the
+    /// degree adds finer features without drowning out the large ones.
+    /// `degree` is clamped to [`MAX_DEGREE`]. The normalization table and RMS
+    /// scaling are computed here once, so each sample costs O(degree²) and the
+    /// expansion is read-only afterwards.
+    pub fn new(degree: u32, seed: u32) -> Self {
+        let degree = degree.min(MAX_DEGREE);
+        let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+
+        let coefficients = (0..=degree)
+            .flat_map(|l| (0..2 * l + 1).map(move |_| l))
+            .map(|l| {
+                let value: f32 = rng.gen_range(-1.0..1.0);
+                if l == 0 {
+                    0.0
+                } else {
+                    value / ((2 * l + 1) as f32).sqrt()
+                }
+            })
+            .collect::<Vec<f32>>();
+
+        let norms = (0..=degree)
+            .flat_map(|l| (0..=l).map(move |m| (l, m)))
+            .map(|(l, m)| {
+                // (l - m)! / (l + m)! as a running product to avoid overflow
+                let ratio: f64 = ((l - m + 1)..=(l + m)).map(|k| 1.0 / k as f64).product();
+                let norm = ((2 * l + 1) as f64 / (4.0 * PI) * ratio).sqrt();
+                if m == 0 {
+                    norm
+                } else {
+                    std::f64::consts::SQRT_2 * norm
+                }
+            })
+            .collect();
+
+        // Orthonormal basis: mean square over the sphere is sum(a²) / 4π
+        let power: f64 = coefficients.iter().map(|&c| (c as f64).powi(2)).sum();
+        let scale = if power > 0.0 { ELEVATION_RMS / (power / (4.0 * PI)).sqrt() } else { 0.0 };
+
+        Self {
+            degree,
+            seed,
+            coefficients,
+            norms,
+            scale,
+            ocean_threshold: -0.12,
+            mountain_threshold: 0.4,
+            ice_cap_latitude: 0.85,
+            beach_band: 0.05,
+        }
+    }
+
+    /// Highest harmonic degree in the expansion (at most [`MAX_DEGREE`])
+    pub fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    /// Seed the coefficients were drawn from
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Coefficients indexed by `l * l + l + m` for `m` in `-l..=l`
+    ///
+    /// `(degree + 1)²` entries. The degree-0 term is zero so land and ocean
+    /// balance out on average.
+    pub fn coefficients(&self) -> &[f32] {
+        &self.coefficients
+    }
+
+    /// Coefficient for degree `l` and order `m` (0 if missing)
+    fn coefficient(&self, l: u32, m: i32) -> f64 {
+        let index = (l * l + l) as i64 + m as i64;
+        self.coefficients.get(index as usize).copied().unwrap_or(0.0) as f64
+    }
+
+    /// Evaluate the normalized elevation in [-1, 1] at a direction
+    pub fn elevation(&self, position: Vec3) -> f32 {
+        let dir = position.as_dvec3().normalize_or_zero();
+        let degree = self.degree;
+
+        // Polar angle from +Y, azimuth in the XZ plane
+        let x = dir.y.clamp(-1.0, 1.0);
+        let sin_theta = (1.0 - x * x).max(0.0).sqrt();
+        let phi = longitude(position) as f64;
+
+        let mut value = 0.0;
+        let mut p_mm = 1.0; // P_m^m(x), built up incrementally
+        for m in 0..=degree {
+            if m > 0 {
+                p_mm *= -((2 * m - 1) as f64) * sin_theta;
+            }
+            let (sin_m, cos_m) = (m as f64 * phi).sin_cos();
+
+            // Upward recurrence in l for fixed m
+            let mut p_prev = 0.0;
+            let mut p_l = p_mm;
+            for l in m..=degree {
+                if l > m {
+                    let p_next = if l == m + 1 {
+                        x * (2 * m + 1) as f64 * p_mm
+                    } else {
+                        ((2 * l - 1) as f64 * x * p_l - (l + m - 1) as f64 * p_prev) / (l - m) as f64
+                    };
+                    p_prev = p_l;
+                    p_l = p_next;
+                }
+
+                let norm = self.norms[(l * (l + 1) / 2 + m) as usize];
+                value += if m == 0 {
+                    self.coefficient(l, 0) * norm * p_l
+                } else {
+                    norm * p_l * (self.coefficient(l, m as i32) * cos_m + self.coefficient(l, -(m as i32)) * sin_m)
+                };
+            }
+        }
+
+        (value * self.scale).clamp(-1.0, 1.0) as f32
+    }
+}
+
+impl TerrainSampler for SphericalHarmonicSampler {
+    type Output = BasicTerrainType;
+
+    fn sample(&self, position: Vec3, radius: f32) -> BasicTerrainType {
+        let latitude = (position.y / radius).abs();
+        classify_basic(
+            latitude,
+            self.elevation(position),
+            self.ocean_threshold,
+            self.beach_band,
+            self.mountain_threshold,
+            self.ice_cap_latitude,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{PlanetConfigBuilder, PlanetSize};
+    use crate::VoronoiPlanet;
+
+    fn sphere_samples() -> Vec<Vec3> {
+        crate::generation::generate_fibonacci_sphere_points(2000, 1.0, 3)
+            .into_iter()
+            .map(|p| Vec3::new(p.x, p.y, p.z))
+            .collect()
+    }
+
+    #[test]
+    fn test_determinism() {
+        let a = SphericalHarmonicSampler::new(8, 77);
+        let b = SphericalHarmonicSampler::new(8, 77);
+        assert_eq!(a.coefficients, b.coefficients);
+        assert_eq!(a.coefficients.len(), 81);
+
+        for p in sphere_samples().into_iter().take(100) {
+            assert_eq!(a.elevation(p), b.elevation(p));
+            assert_eq!(a.sample(p, 1.0), b.sample(p, 1.0));
+        }
+        assert_ne!(a.coefficients, SphericalHarmonicSampler::new(8, 78).coefficients);
+    }
+
+    #[test]
+    fn test_elevation_bounded_and_normalized() {
+        let sampler = SphericalHarmonicSampler::new(12, 5);
+        let samples = sphere_samples();
+        let elevations: Vec<f32> = samples.iter().map(|&p| sampler.elevation(p)).collect();
+
+        assert!(elevations.iter().all(|e| (-1.0..=1.0).contains(e)));
+        let mean_square = elevations.iter().map(|e| e * e).sum::<f32>() / elevations.len() as f32;
+        assert!((mean_square.sqrt() - 0.35).abs() < 0.08, "rms {}", mean_square.sqrt());
+
+        // Matches for any radius, only direction matters
+        assert!((sampler.elevation(samples[0]) - sampler.elevation(samples[0] * 40.0)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_higher_degree_more_continents() {
+        let config = PlanetConfigBuilder::new()
+            .seed(42)
+            .planet_size(PlanetSize::Custom { cell_count: 2000, radius: 10.0 })
+            .build()
+            .unwrap();
+
+        let continents = |degree| {
+            let sampler = SphericalHarmonicSampler::new(degree, 11);
+            let planet = VoronoiPlanet::generate_with_sampler(config, &sampler).unwrap();
+            let is_land = |id: usize| {
+                let t = planet.get_cell(id).unwrap().terrain;
+                t.is_land() && t != BasicTerrainType::Ice
+            };
+
+            // Count connected land components
+            let mut seen = vec![false; planet.cell_count()];
+            let mut count = 0;
+            for start in 0..planet.cell_count() {
+                if seen[start] || !is_land(start) {
+                    continue;
+                }
+                count += 1;
+                let mut stack = vec![start];
+                seen[start] = true;
+                while let Some(id) = stack.pop() {
+                    for &n in planet.get_neighbors(id) {
+                        if !seen[n] && is_land(n) {
+                            seen[n] = true;
+                            stack.push(n);
+                        }
+                    }
+                }
+            }
+            count
+        };
+
+        let coarse = continents(2);
+        let fine = continents(16);
+        assert!(fine > coarse, "degree 16: {} continents, degree 2: {}", fine, coarse);
+    }
+}